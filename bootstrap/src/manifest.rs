@@ -75,7 +75,8 @@ impl Manifest {
             .arg(format!("-Lall={}", self.out_dir.display()))
             .env("CFLAGS", "-Irust_runtime")
             .arg("-lc")
-            .arg("-lrust_runtime");
+            .arg("-lrust_runtime")
+            .arg("-lm");
         if self.debug {
             command.env("RUST_BACKTRACE", "full");
         }
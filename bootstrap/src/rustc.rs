@@ -23,6 +23,8 @@ impl RustcCommand {
             .args(["--crate-type", "bin"])
             .arg("--out-dir")
             .arg(&manifest.out_dir)
+            // name the C locals after the Rust variables they hold
+            .args(["-Z", "fewer-names=no"])
             .args(&self.slop);
         log::debug!("running {:?}", command);
         command.status().unwrap();
@@ -31,6 +31,8 @@ impl TestCommand {
                     cprint!("File checking {}...", testcase.name);
                     testcase.build(manifest);
                     filechecker.run(&testcase.source, &testcase.output);
+                    testcase.run();
+                    testcase.verify_unoptimized(manifest);
                 }
                 TestType::Compile => {
                     cprint!("Compiling {}...", testcase.name);
@@ -84,16 +86,73 @@ pub struct TestCase {
 impl TestCase {
     pub fn build(&self, manifest: &Manifest) {
         std::fs::create_dir_all(self.output.parent().unwrap()).unwrap();
+        let flags = self.compile_flags();
         let mut command = manifest.rustc();
+        command.args(["--crate-type", "bin"]);
+        // a test may be built at an optimization level of its own with `-C opt-level=N`
+        if !flags.iter().any(|flag| flag.starts_with("opt-level=")) {
+            command.arg("-O");
+        }
         command
-            .args(["--crate-type", "bin"])
-            .arg("-O")
+            .args(["-C", "llvm-args=verify=true"])
+            .args(flags)
             .arg(&self.source)
             .arg("-o")
             .arg(&self.output);
         log::debug!("running {:?}", command);
         command.status().unwrap();
     }
+
+    /// Run the built test, which has to exit successfully. A test whose `main` does not return
+    /// zero opts out with an `//@ ignore-run: <reason>` line.
+    pub fn run(&self) {
+        if !self.directives("ignore-run").is_empty() {
+            return;
+        }
+        let mut command = std::process::Command::new(&self.output);
+        log::debug!("running {:?}", command);
+        let output = command.output().unwrap();
+        assert!(output.status.success(), "{} exited with {}", self.name, output.status);
+    }
+
+    /// Build the test without optimizations into an object file, checking that the generated C
+    /// passes the verifier. A test whose unoptimized MIR needs unimplemented builder methods opts
+    /// out with an `//@ ignore-O0: <reason>` line.
+    pub fn verify_unoptimized(&self, manifest: &Manifest) {
+        if !self.directives("ignore-O0").is_empty() {
+            return;
+        }
+        let out_dir = manifest.out_dir.join("tests/codegen-O0");
+        std::fs::create_dir_all(&out_dir).unwrap();
+        let mut command = manifest.rustc();
+        command
+            .args(["--crate-type", "bin", "--emit", "obj"])
+            .args(["-C", "opt-level=0"])
+            .args(["-C", "llvm-args=verify=true"])
+            .args(self.compile_flags())
+            .arg(&self.source)
+            .arg("--out-dir")
+            .arg(&out_dir);
+        log::debug!("running {:?}", command);
+        let status = command.status().unwrap();
+        assert!(status.success(), "failed to build {} without optimizations", self.name);
+    }
+
+    /// Additional flags of the test, given by `//@ compile-flags: ...` lines.
+    fn compile_flags(&self) -> Vec<String> {
+        self.directives("compile-flags")
+            .iter()
+            .flat_map(|flags| flags.split_whitespace())
+            .map(String::from)
+            .collect()
+    }
+
+    /// The values of the `//@ <name>: ...` lines of the test.
+    fn directives(&self, name: &str) -> Vec<String> {
+        let source = std::fs::read_to_string(&self.source).unwrap();
+        let prefix = format!("//@ {name}:");
+        source.lines().filter_map(|line| line.strip_prefix(&prefix)).map(String::from).collect()
+    }
 }
 
 struct FileChecker {
@@ -119,21 +178,36 @@ impl FileChecker {
 
     fn run(&self, source: &Path, output: &Path) {
         let case = source.file_stem().unwrap().to_string_lossy();
-        let generated = std::fs::read_dir(output.parent().unwrap())
+        let generated = Self::find_generated(output, &case, ".c");
+        assert!(generated.is_some(), "could not find {case}'s generated file");
+        self.check(source, &generated.unwrap(), None);
+
+        // a header of the crate is only written with `-C llvm-args=header=true`
+        if let Some(header) = Self::find_generated(output, &case, ".h") {
+            self.check(source, &header, Some("HEADER"));
+        }
+    }
+
+    fn find_generated(output: &Path, case: &str, extension: &str) -> Option<PathBuf> {
+        std::fs::read_dir(output.parent().unwrap())
             .unwrap()
             .filter_map(|entry| entry.ok())
             .find(|entry| {
                 let filename = entry.file_name();
                 let filename = filename.to_string_lossy();
-                filename.ends_with(".c") && filename.starts_with(case.as_ref())
-            });
-
-        assert!(generated.is_some(), "could not find {case}'s generated file");
-        let generated = generated.unwrap();
+                filename.ends_with(extension) && filename.starts_with(case)
+            })
+            .map(|entry| entry.path())
+    }
 
-        let generated = File::open(generated.path()).unwrap();
+    fn check(&self, source: &Path, generated: &Path, prefix: Option<&str>) {
+        let case = source.file_stem().unwrap().to_string_lossy();
+        let generated = File::open(generated).unwrap();
         let mut command = std::process::Command::new(&self.filecheck);
         command.arg(source).stdin(generated);
+        if let Some(prefix) = prefix {
+            command.arg(format!("--check-prefix={prefix}"));
+        }
         log::debug!("running {:?}", command);
         let output = command.output().unwrap();
         assert!(output.status.success(), "failed to run FileCheck on {case}");
@@ -0,0 +1,32 @@
+/* file: example.4c2e2ea0d3ed8f8b-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+int32_t main();
+uint8_t abc(uint8_t _0);
+uint8_t bcd(bool _0, uint8_t _1);
+
+/* rustc_codegen_c: implementation */
+
+int32_t main() {
+  return 0;
+}
+
+uint8_t abc(uint8_t _0) {
+  return (uint8_t) (_0 * 2);
+}
+
+uint8_t bcd(bool _0, uint8_t _1) {
+  uint8_t _2;
+  if (_0) {
+    (*(uint8_t*) (&_2)) = (uint8_t) (_1 * 2);
+  } else {
+    (*(uint8_t*) (&_2)) = (uint8_t) (_1 * 3);
+  }
+  return _2;
+}
@@ -0,0 +1,173 @@
+/* file: tee.e057bd9b4129d250-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+intptr_t write(int32_t, void*, uintptr_t);
+int32_t open(int8_t*, int32_t, int32_t);
+intptr_t read(int32_t, void*, uintptr_t);
+int32_t close(int32_t);
+static const char _g0kvmqyqsabuj0_0[27] = "Failed to read from stdin.\n";
+static uint8_t (*const _g0kvmqyqsabuj0_1)[27] =
+  (uint8_t (*)[27]) _g0kvmqyqsabuj0_0;
+static const char _g0kvmqyqsabuj0_2[21] = "Failed to open file.\n";
+static uint8_t (*const _g0kvmqyqsabuj0_3)[21] =
+  (uint8_t (*)[21]) _g0kvmqyqsabuj0_2;
+static const char _g0kvmqyqsabuj0_4[18] = "Usage: tee <file>\n";
+static uint8_t (*const _g0kvmqyqsabuj0_5)[18] =
+  (uint8_t (*)[18]) _g0kvmqyqsabuj0_4;
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+static void _ZN3tee8eprintln17hba17c7fb583083a5E(void *_0, uint64_t _1);
+int main(int _0, char** _1);
+
+/* rustc_codegen_c: implementation */
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  abort();
+  abort();
+}
+
+static void _ZN3tee8eprintln17hba17c7fb583083a5E(void *_0, uint64_t _1) {
+  intptr_t _3 = write(2, _0, (uintptr_t) _1);
+  return;
+}
+
+int main(int _0, char** _1) {
+  int32_t _2;
+  char _3[4096];
+  if (_0 < 2) {
+    _ZN3tee8eprintln17hba17c7fb583083a5E((void*) _g0kvmqyqsabuj0_5, 18);
+    (*(int32_t*) (&_2)) = 1;
+  } else {
+    int32_t _9 =
+      open((int8_t*) (*(intptr_t*) (&((intptr_t*) _1)[1])), 66, 420);
+    if (_9 < 0) {
+      _ZN3tee8eprintln17hba17c7fb583083a5E((void*) _g0kvmqyqsabuj0_3, 21);
+      (*(int32_t*) (&_2)) = 1;
+    } else {
+      __rust_memset(&_3, 0, 4096);
+      while (1) {
+        intptr_t _12 = read(0, (void*) (&_3), 4096);
+        if (_12 < 0) break;
+        if (_12 == 0) {
+          int32_t _15 = close(_9);
+          (*(int32_t*) (&_2)) = 0;
+          goto bb18;
+        }
+        uintptr_t _16 = (uintptr_t) _12;
+        intptr_t _18 = write(_9, (void*) (&_3), _16);
+        intptr_t _20 = write(1, (void*) (&_3), _16);
+      }
+      _ZN3tee8eprintln17hba17c7fb583083a5E((void*) _g0kvmqyqsabuj0_1, 27);
+      (*(int32_t*) (&_2)) = 1;
+    }
+  }
+bb18:;
+  return _2;
+}
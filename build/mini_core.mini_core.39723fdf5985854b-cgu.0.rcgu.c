@@ -0,0 +1,253 @@
+/* file: mini_core.39723fdf5985854b-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+bool
+_ZN43_$LT$u8$u20$as$u20$mini_core__PartialEq$GT$2eq17hb63144b50f650dfdE(uint8_t *_0,
+                                                                       uint8_t *_1);
+bool
+_ZN43_$LT$u8$u20$as$u20$mini_core__PartialEq$GT$2ne17h94544aaaccc39590E(uint8_t *_0,
+                                                                       uint8_t *_1);
+bool
+_ZN44_$LT$u16$u20$as$u20$mini_core__PartialEq$GT$2eq17h8aa7e2b725743bbaE(uint16_t *_0,
+                                                                        uint16_t *_1);
+bool
+_ZN44_$LT$u16$u20$as$u20$mini_core__PartialEq$GT$2ne17hac03c7f40e234c72E(uint16_t *_0,
+                                                                        uint16_t *_1);
+bool
+_ZN44_$LT$u32$u20$as$u20$mini_core__PartialEq$GT$2eq17h6d28b16748474fd6E(uint32_t *_0,
+                                                                        uint32_t *_1);
+bool
+_ZN44_$LT$u32$u20$as$u20$mini_core__PartialEq$GT$2ne17h5972dcbcac211059E(uint32_t *_0,
+                                                                        uint32_t *_1);
+bool
+_ZN44_$LT$u64$u20$as$u20$mini_core__PartialEq$GT$2eq17h47aa07f91ef173e1E(uint64_t *_0,
+                                                                        uint64_t *_1);
+bool
+_ZN44_$LT$u64$u20$as$u20$mini_core__PartialEq$GT$2ne17hb8fb66dc1876f1d0E(uint64_t *_0,
+                                                                        uint64_t *_1);
+bool
+_ZN46_$LT$usize$u20$as$u20$mini_core__PartialEq$GT$2eq17h7bbfd92cd6faf0e1E(uintptr_t *_0,
+                                                                          uintptr_t *_1);
+bool
+_ZN46_$LT$usize$u20$as$u20$mini_core__PartialEq$GT$2ne17h0ed470bbf68e50ecE(uintptr_t *_0,
+                                                                          uintptr_t *_1);
+bool
+_ZN43_$LT$i8$u20$as$u20$mini_core__PartialEq$GT$2eq17h5319231f1a0645e2E(int8_t *_0,
+                                                                       int8_t *_1);
+bool
+_ZN43_$LT$i8$u20$as$u20$mini_core__PartialEq$GT$2ne17h3ef7d3f01399a555E(int8_t *_0,
+                                                                       int8_t *_1);
+bool
+_ZN44_$LT$i32$u20$as$u20$mini_core__PartialEq$GT$2eq17haba2b6766e76b164E(int32_t *_0,
+                                                                        int32_t *_1);
+bool
+_ZN44_$LT$i32$u20$as$u20$mini_core__PartialEq$GT$2ne17h16e487c3a62a80a5E(int32_t *_0,
+                                                                        int32_t *_1);
+bool
+_ZN46_$LT$isize$u20$as$u20$mini_core__PartialEq$GT$2eq17h03b564707d766702E(intptr_t *_0,
+                                                                          intptr_t *_1);
+bool
+_ZN46_$LT$isize$u20$as$u20$mini_core__PartialEq$GT$2ne17ha03d5cfd49c16425E(intptr_t *_0,
+                                                                          intptr_t *_1);
+bool
+_ZN45_$LT$char$u20$as$u20$mini_core__PartialEq$GT$2eq17hc96aa18246d3e801E(int32_t *_0,
+                                                                         int32_t *_1);
+bool
+_ZN45_$LT$char$u20$as$u20$mini_core__PartialEq$GT$2ne17h241efb656cca63eeE(int32_t *_0,
+                                                                         int32_t *_1);
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3pao17hce30cf60fd1ecdbcE(void *_0);
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3pso17h8841d6315f7666a0E(void *_0);
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3pmo17h61824d7883f8290cE(void *_0);
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3pdo17h7b39692c9f92fba3E(void *_0);
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3pro17h78d93095538e98fbE(void *_0);
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3pno17he7852900627d5fdbE(void *_0);
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3pho17h6094f364f4b493adE(void *_0);
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3plo17h4c621624466de1daE(void *_0);
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3pdz17h51ce5b5c2d5c2502E(void *_0);
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3prz17he1fd10b09fdae774E(void *_0);
+
+/* rustc_codegen_c: implementation */
+
+bool
+_ZN43_$LT$u8$u20$as$u20$mini_core__PartialEq$GT$2eq17hb63144b50f650dfdE(uint8_t *_0,
+                                                                       uint8_t *_1) {
+  return (*_0) == (*_1);
+}
+
+bool
+_ZN43_$LT$u8$u20$as$u20$mini_core__PartialEq$GT$2ne17h94544aaaccc39590E(uint8_t *_0,
+                                                                       uint8_t *_1) {
+  return (*_0) != (*_1);
+}
+
+bool
+_ZN44_$LT$u16$u20$as$u20$mini_core__PartialEq$GT$2eq17h8aa7e2b725743bbaE(uint16_t *_0,
+                                                                        uint16_t *_1) {
+  return (*_0) == (*_1);
+}
+
+bool
+_ZN44_$LT$u16$u20$as$u20$mini_core__PartialEq$GT$2ne17hac03c7f40e234c72E(uint16_t *_0,
+                                                                        uint16_t *_1) {
+  return (*_0) != (*_1);
+}
+
+bool
+_ZN44_$LT$u32$u20$as$u20$mini_core__PartialEq$GT$2eq17h6d28b16748474fd6E(uint32_t *_0,
+                                                                        uint32_t *_1) {
+  return (*_0) == (*_1);
+}
+
+bool
+_ZN44_$LT$u32$u20$as$u20$mini_core__PartialEq$GT$2ne17h5972dcbcac211059E(uint32_t *_0,
+                                                                        uint32_t *_1) {
+  return (*_0) != (*_1);
+}
+
+bool
+_ZN44_$LT$u64$u20$as$u20$mini_core__PartialEq$GT$2eq17h47aa07f91ef173e1E(uint64_t *_0,
+                                                                        uint64_t *_1) {
+  return (*_0) == (*_1);
+}
+
+bool
+_ZN44_$LT$u64$u20$as$u20$mini_core__PartialEq$GT$2ne17hb8fb66dc1876f1d0E(uint64_t *_0,
+                                                                        uint64_t *_1) {
+  return (*_0) != (*_1);
+}
+
+bool
+_ZN46_$LT$usize$u20$as$u20$mini_core__PartialEq$GT$2eq17h7bbfd92cd6faf0e1E(uintptr_t *_0,
+                                                                          uintptr_t *_1) {
+  return (*_0) == (*_1);
+}
+
+bool
+_ZN46_$LT$usize$u20$as$u20$mini_core__PartialEq$GT$2ne17h0ed470bbf68e50ecE(uintptr_t *_0,
+                                                                          uintptr_t *_1) {
+  return (*_0) != (*_1);
+}
+
+bool
+_ZN43_$LT$i8$u20$as$u20$mini_core__PartialEq$GT$2eq17h5319231f1a0645e2E(int8_t *_0,
+                                                                       int8_t *_1) {
+  return (*_0) == (*_1);
+}
+
+bool
+_ZN43_$LT$i8$u20$as$u20$mini_core__PartialEq$GT$2ne17h3ef7d3f01399a555E(int8_t *_0,
+                                                                       int8_t *_1) {
+  return (*_0) != (*_1);
+}
+
+bool
+_ZN44_$LT$i32$u20$as$u20$mini_core__PartialEq$GT$2eq17haba2b6766e76b164E(int32_t *_0,
+                                                                        int32_t *_1) {
+  return (*_0) == (*_1);
+}
+
+bool
+_ZN44_$LT$i32$u20$as$u20$mini_core__PartialEq$GT$2ne17h16e487c3a62a80a5E(int32_t *_0,
+                                                                        int32_t *_1) {
+  return (*_0) != (*_1);
+}
+
+bool
+_ZN46_$LT$isize$u20$as$u20$mini_core__PartialEq$GT$2eq17h03b564707d766702E(intptr_t *_0,
+                                                                          intptr_t *_1) {
+  return (*_0) == (*_1);
+}
+
+bool
+_ZN46_$LT$isize$u20$as$u20$mini_core__PartialEq$GT$2ne17ha03d5cfd49c16425E(intptr_t *_0,
+                                                                          intptr_t *_1) {
+  return (*_0) != (*_1);
+}
+
+bool
+_ZN45_$LT$char$u20$as$u20$mini_core__PartialEq$GT$2eq17hc96aa18246d3e801E(int32_t *_0,
+                                                                         int32_t *_1) {
+  return (*_0) == (*_1);
+}
+
+bool
+_ZN45_$LT$char$u20$as$u20$mini_core__PartialEq$GT$2ne17h241efb656cca63eeE(int32_t *_0,
+                                                                         int32_t *_1) {
+  return (*_0) != (*_1);
+}
+
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3pao17hce30cf60fd1ecdbcE(void *_0) {
+  abort();
+  abort();
+}
+
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3pso17h8841d6315f7666a0E(void *_0) {
+  abort();
+  abort();
+}
+
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3pmo17h61824d7883f8290cE(void *_0) {
+  abort();
+  abort();
+}
+
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3pdo17h7b39692c9f92fba3E(void *_0) {
+  abort();
+  abort();
+}
+
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3pro17h78d93095538e98fbE(void *_0) {
+  abort();
+  abort();
+}
+
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3pno17he7852900627d5fdbE(void *_0) {
+  abort();
+  abort();
+}
+
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3pho17h6094f364f4b493adE(void *_0) {
+  abort();
+  abort();
+}
+
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3plo17h4c621624466de1daE(void *_0) {
+  abort();
+  abort();
+}
+
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3pdz17h51ce5b5c2d5c2502E(void *_0) {
+  abort();
+  abort();
+}
+
+__attribute__((noreturn)) void
+_ZN9mini_core11panic_const3prz17he1fd10b09fdae774E(void *_0) {
+  abort();
+  abort();
+}
@@ -0,0 +1,191 @@
+/* file: attrs.4248b3787476a6a2-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+static __attribute__((aligned(8))) const char _g0uabbxocij7ye_0[16] =
+  "\026\000\000\000\000\000\000\000\026\000\000\000\005\000\000\000";
+static struct Location_6 *const _g0uabbxocij7ye_1 =
+  (struct Location_6*) _g0uabbxocij7ye_0;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(struct Location_6*);
+static __attribute__((aligned(8))) const char _g0uabbxocij7ye_2[16] =
+  "\026\000\000\000\000\000\000\000\037\000\000\000\005\000\000\000";
+static struct Location_6 *const _g0uabbxocij7ye_3 =
+  (struct Location_6*) _g0uabbxocij7ye_2;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_sub_overflow17h35f73ecc35fc5ba3E(struct Location_6*);
+static __attribute__((aligned(8))) const char _g0uabbxocij7ye_4[16] =
+  "\026\000\000\000\000\000\000\000&\000\000\000\005\000\000\000";
+static struct Location_6 *const _g0uabbxocij7ye_5 =
+  (struct Location_6*) _g0uabbxocij7ye_4;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_mul_overflow17h8148a706815a3943E(struct Location_6*);
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+__attribute__((noinline)) int32_t never(int32_t _0);
+__attribute__((cold, section(".text.unlikely_path"))) int32_t
+unlikely(int32_t _0);
+__attribute__((aligned(64))) int32_t aligned(int32_t _0);
+__attribute__((noreturn)) void stop();
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  abort();
+  abort();
+}
+
+__attribute__((noinline)) int32_t never(int32_t _0) {
+  int32_t _1;
+  if (!(bool) __rust_ckd_add_i32(_0, 1, &_1)) return _1;
+  _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+      _g0uabbxocij7ye_1);
+  abort();
+}
+
+__attribute__((cold, section(".text.unlikely_path"))) int32_t
+unlikely(int32_t _0) {
+  int32_t _1;
+  if (!(bool) __rust_ckd_sub_i32(_0, 1, &_1)) return _1;
+  _ZN4core9panicking11panic_const24panic_const_sub_overflow17h35f73ecc35fc5ba3E(
+      _g0uabbxocij7ye_3);
+  abort();
+}
+
+__attribute__((aligned(64))) int32_t aligned(int32_t _0) {
+  int32_t _1;
+  if (!(bool) __rust_ckd_mul_i32(_0, 3, &_1)) return _1;
+  _ZN4core9panicking11panic_const24panic_const_mul_overflow17h8148a706815a3943E(
+      _g0uabbxocij7ye_5);
+  abort();
+}
+
+__attribute__((noreturn)) void stop() {
+  abort();
+  abort();
+}
+
+int32_t main() {
+  int32_t _0;
+  if (never(1) == 2) {
+    if (unlikely(1) == 0) {
+      if (aligned(2) == 6) {
+        if (((uintptr_t) aligned % 64) == 0) {
+          (*(int32_t*) (&_0)) = 0;
+        } else {
+          (*(int32_t*) (&_0)) = 1;
+        }
+        return _0;
+      }
+    }
+  }
+  stop();
+  abort();
+}
@@ -0,0 +1,199 @@
+/* file: binary.3d0ee111f7e634cd-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+static __attribute__((aligned(8))) const char _g0l3jghsnzn23q_0[16] =
+  "\027\000\000\000\000\000\000\000\026\000\000\000\005\000\000\000";
+static struct Location_6 *const _g0l3jghsnzn23q_1 =
+  (struct Location_6*) _g0l3jghsnzn23q_0;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(struct Location_6*);
+static __attribute__((aligned(8))) const char _g0l3jghsnzn23q_2[16] =
+  "\027\000\000\000\000\000\000\000\026\000\000\000\005\000\000\000";
+static struct Location_6 *const _g0l3jghsnzn23q_3 =
+  (struct Location_6*) _g0l3jghsnzn23q_2;
+static __attribute__((aligned(8))) const char _g0l3jghsnzn23q_4[16] =
+  "\027\000\000\000\000\000\000\000\035\000\000\000\005\000\000\000";
+static struct Location_6 *const _g0l3jghsnzn23q_5 =
+  (struct Location_6*) _g0l3jghsnzn23q_4;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_sub_overflow17h35f73ecc35fc5ba3E(struct Location_6*);
+static __attribute__((aligned(8))) const char _g0l3jghsnzn23q_6[16] =
+  "\027\000\000\000\000\000\000\000\035\000\000\000\005\000\000\000";
+static struct Location_6 *const _g0l3jghsnzn23q_7 =
+  (struct Location_6*) _g0l3jghsnzn23q_6;
+static __attribute__((aligned(8))) const char _g0l3jghsnzn23q_8[16] =
+  "\027\000\000\000\000\000\000\000$\000\000\000\005\000\000\000";
+static struct Location_6 *const _g0l3jghsnzn23q_9 =
+  (struct Location_6*) _g0l3jghsnzn23q_8;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_mul_overflow17h8148a706815a3943E(struct Location_6*);
+static __attribute__((aligned(8))) const char _g0l3jghsnzn23q_10[16] =
+  "\027\000\000\000\000\000\000\000$\000\000\000\005\000\000\000";
+static struct Location_6 *const _g0l3jghsnzn23q_11 =
+  (struct Location_6*) _g0l3jghsnzn23q_10;
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+int32_t test_add(int32_t _0, int32_t _1, int32_t _2);
+int32_t test_sub(int32_t _0, int32_t _1, int32_t _2);
+int32_t test_mul(int32_t _0, int32_t _1, int32_t _2);
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  abort();
+  abort();
+}
+
+int32_t test_add(int32_t _0, int32_t _1, int32_t _2) {
+  int32_t _3;
+  if ((bool) __rust_ckd_add_i32(_0, _1, &_3)) {
+    _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+        _g0l3jghsnzn23q_1);
+    abort();
+  }
+  int32_t _5;
+  if (!(bool) __rust_ckd_add_i32(_3, _2, &_5)) return _5;
+  _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+      _g0l3jghsnzn23q_3);
+  abort();
+}
+
+int32_t test_sub(int32_t _0, int32_t _1, int32_t _2) {
+  int32_t _3;
+  if ((bool) __rust_ckd_sub_i32(_0, _1, &_3)) {
+    _ZN4core9panicking11panic_const24panic_const_sub_overflow17h35f73ecc35fc5ba3E(
+        _g0l3jghsnzn23q_5);
+    abort();
+  }
+  int32_t _5;
+  if (!(bool) __rust_ckd_sub_i32(_3, _2, &_5)) return _5;
+  _ZN4core9panicking11panic_const24panic_const_sub_overflow17h35f73ecc35fc5ba3E(
+      _g0l3jghsnzn23q_7);
+  abort();
+}
+
+int32_t test_mul(int32_t _0, int32_t _1, int32_t _2) {
+  int32_t _3;
+  if ((bool) __rust_ckd_mul_i32(_0, _1, &_3)) {
+    _ZN4core9panicking11panic_const24panic_const_mul_overflow17h8148a706815a3943E(
+        _g0l3jghsnzn23q_9);
+    abort();
+  }
+  int32_t _5;
+  if (!(bool) __rust_ckd_mul_i32(_3, _2, &_5)) return _5;
+  _ZN4core9panicking11panic_const24panic_const_mul_overflow17h8148a706815a3943E(
+      _g0l3jghsnzn23q_11);
+  abort();
+}
+
+int32_t main() {
+  return 0;
+}
@@ -0,0 +1,186 @@
+/* file: c_std.caa36e01b48ca13d-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+typedef char __rust_static_assert_0[(sizeof(struct str_2) == 16) ? 1 : -1];
+typedef char __rust_static_assert_1[(__alignof__(struct str_2)
+  == 8) ? 1 : -1];
+typedef char __rust_static_assert_2[(offsetof(struct str_2, f0)
+  == 0) ? 1 : -1];
+typedef char __rust_static_assert_3[(offsetof(struct str_2, f1)
+  == 8) ? 1 : -1];
+typedef char __rust_static_assert_4[(sizeof(struct core_fmt_rt_Argument_3)
+  == 16) ? 1 : -1];
+typedef char __rust_static_assert_5[(__alignof__(struct core_fmt_rt_Argument_3)
+  == 8) ? 1 : -1];
+typedef char __rust_static_assert_6[(offsetof(struct core_fmt_rt_Argument_3,
+  f0) == 0) ? 1 : -1];
+typedef char __rust_static_assert_7[(offsetof(struct core_fmt_rt_Argument_3,
+  f1) == 8) ? 1 : -1];
+typedef char __rust_static_assert_8[(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16) ? 1 : -1];
+typedef char __rust_static_assert_9[(__alignof__(struct core_fmt_rt_Placeholder_5)
+  == 8) ? 1 : -1];
+typedef char __rust_static_assert_10[(offsetof(struct core_fmt_rt_Placeholder_5,
+  f0) == 0) ? 1 : -1];
+typedef char __rust_static_assert_11[(offsetof(struct core_fmt_rt_Placeholder_5,
+  f1) == 8) ? 1 : -1];
+typedef char __rust_static_assert_12[(sizeof(struct Option_4)
+  == 16) ? 1 : -1];
+typedef char __rust_static_assert_13[(__alignof__(struct Option_4)
+  == 8) ? 1 : -1];
+typedef char __rust_static_assert_14[(sizeof(struct Arguments_1)
+  == 48) ? 1 : -1];
+typedef char __rust_static_assert_15[(__alignof__(struct Arguments_1)
+  == 8) ? 1 : -1];
+typedef char __rust_static_assert_16[(offsetof(struct Arguments_1, f0)
+  == 0) ? 1 : -1];
+typedef char __rust_static_assert_17[(offsetof(struct Arguments_1, f2)
+  == 16) ? 1 : -1];
+typedef char __rust_static_assert_18[(offsetof(struct Arguments_1, f1)
+  == 32) ? 1 : -1];
+typedef char __rust_static_assert_19[(sizeof(struct str_7) == 16) ? 1 : -1];
+typedef char __rust_static_assert_20[(__alignof__(struct str_7)
+  == 8) ? 1 : -1];
+typedef char __rust_static_assert_21[(offsetof(struct str_7, f0)
+  == 0) ? 1 : -1];
+typedef char __rust_static_assert_22[(offsetof(struct str_7, f1)
+  == 8) ? 1 : -1];
+typedef char __rust_static_assert_23[(sizeof(struct Location_6)
+  == 24) ? 1 : -1];
+typedef char __rust_static_assert_24[(__alignof__(struct Location_6)
+  == 8) ? 1 : -1];
+typedef char __rust_static_assert_25[(offsetof(struct Location_6, f0)
+  == 0) ? 1 : -1];
+typedef char __rust_static_assert_26[(offsetof(struct Location_6, f1)
+  == 16) ? 1 : -1];
+typedef char __rust_static_assert_27[(offsetof(struct Location_6, f2)
+  == 20) ? 1 : -1];
+typedef char __rust_static_assert_28[(sizeof(struct PanicInfo_0)
+  == 64) ? 1 : -1];
+typedef char __rust_static_assert_29[(__alignof__(struct PanicInfo_0)
+  == 8) ? 1 : -1];
+typedef char __rust_static_assert_30[(offsetof(struct PanicInfo_0, f0)
+  == 0) ? 1 : -1];
+typedef char __rust_static_assert_31[(offsetof(struct PanicInfo_0, f1)
+  == 48) ? 1 : -1];
+typedef char __rust_static_assert_32[(offsetof(struct PanicInfo_0, f2)
+  == 56) ? 1 : -1];
+typedef char __rust_static_assert_33[(offsetof(struct PanicInfo_0, f3)
+  == 57) ? 1 : -1];
+static __attribute__((aligned(8))) const char _g2jiu8o8b6kjzu_0[16] =
+  "\026\000\000\000\000\000\000\000+\000\000\000\t\000\000\000";
+static struct Location_6 *const _g2jiu8o8b6kjzu_1 =
+  (struct Location_6*) _g2jiu8o8b6kjzu_0;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(struct Location_6*);
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+__attribute__((noinline)) double scale(double _0);
+double sum_scaled(double _0, uint32_t _1);
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  abort();
+  abort();
+}
+
+__attribute__((noinline)) double scale(double _0) {
+  return _0 * 2.5e-1;
+}
+
+double sum_scaled(double _0, uint32_t _1) {
+  double _2;
+  uint32_t _3;
+  double _7;
+  uint32_t _11;
+  (*(double*) (&_2)) = 0e0;
+  (*(uint32_t*) (&_3)) = 0;
+  while (_3 < _1) {
+    _7 = scale(_0);
+    _2 = _2 + _7;
+    if ((bool) __rust_ckd_add_u32(_3, 1, &_11)) {
+      _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+          _g2jiu8o8b6kjzu_1);
+      abort();
+    }
+    _3 = _11;
+  }
+  return _2;
+}
+
+int32_t main() {
+  int32_t _0;
+  if (sum_scaled(2e0, 4) == 2e0) {
+    (*(int32_t*) (&_0)) = 0;
+  } else {
+    (*(int32_t*) (&_0)) = 1;
+  }
+  return _0;
+}
@@ -0,0 +1,192 @@
+/* file: cfg.1c61d20359959747-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+static __attribute__((aligned(8))) const char _g1y0khl7vpqqem_0[16] =
+  "\024\000\000\000\000\000\000\000&\000\000\000-\000\000\000";
+static struct Location_6 *const _g1y0khl7vpqqem_1 =
+  (struct Location_6*) _g1y0khl7vpqqem_0;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(struct Location_6*);
+static __attribute__((always_inline)) uintptr_t
+_ZN4core3mem7size_of17h66568111a0da7ea6E();
+static __attribute__((always_inline)) int32_t
+_ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_mul17h5bdc5155fd1deaf9E(int32_t _0,
+                                                                     int32_t _1);
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+static __attribute__((noinline)) int32_t
+_ZN3cfg5scale17h793c4299193a1623E(int32_t _0);
+int32_t both(int32_t _0);
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+static __attribute__((always_inline)) uintptr_t
+_ZN4core3mem7size_of17h66568111a0da7ea6E() {
+  return 8;
+}
+
+static __attribute__((always_inline)) int32_t
+_ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_mul17h5bdc5155fd1deaf9E(int32_t _0,
+                                                                     int32_t _1) {
+  return _0 * _1;
+}
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  abort();
+  abort();
+}
+
+static __attribute__((noinline)) int32_t
+_ZN3cfg5scale17h793c4299193a1623E(int32_t _0) {
+  int32_t _1;
+  if (_ZN4core3mem7size_of17h66568111a0da7ea6E() > 4) {
+    (*(int32_t*) (&_1))
+      = _ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_mul17h5bdc5155fd1deaf9E(
+          _0, 2);
+  } else {
+    (*(int32_t*) (&_1))
+      = _ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_mul17h5bdc5155fd1deaf9E(
+          _0, 3);
+  }
+  return _1;
+}
+
+int32_t both(int32_t _0) {
+  int32_t _1;
+  if (_ZN3cfg5scale17h793c4299193a1623E(_0) == 2) {
+    int32_t _4;
+    if ((bool) __rust_ckd_add_i32(_0, 1, &_4)) {
+      _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+          _g1y0khl7vpqqem_1);
+      abort();
+    }
+    if (_ZN3cfg5scale17h793c4299193a1623E(_4) == 4) {
+      (*(int32_t*) (&_1)) = 1;
+      goto bb7;
+    }
+  }
+  (*(int32_t*) (&_1)) = 0;
+bb7:;
+  return _1;
+}
+
+int32_t main() {
+  int32_t _0;
+  if (both(1) == 1) {
+    if (both(2) == 0) {
+      (*(int32_t*) (&_0)) = 0;
+      goto bb6;
+    }
+  }
+  (*(int32_t*) (&_0)) = 1;
+bb6:;
+  return _0;
+}
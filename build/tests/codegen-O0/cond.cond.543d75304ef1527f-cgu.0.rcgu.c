@@ -0,0 +1,179 @@
+/* file: cond.543d75304ef1527f-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+static __attribute__((aligned(8))) const char _g1zxpjdmor3qwr_0[16] =
+  "\025\000\000\000\000\000\000\000\035\000\000\000\t\000\000\000";
+static struct Location_6 *const _g1zxpjdmor3qwr_1 =
+  (struct Location_6*) _g1zxpjdmor3qwr_0;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_sub_overflow17h35f73ecc35fc5ba3E(struct Location_6*);
+static __attribute__((aligned(8))) const char _g1zxpjdmor3qwr_2[16] =
+  "\025\000\000\000\000\000\000\000\035\000\000\000\t\000\000\000";
+static struct Location_6 *const _g1zxpjdmor3qwr_3 =
+  (struct Location_6*) _g1zxpjdmor3qwr_2;
+static __attribute__((aligned(8))) const char _g1zxpjdmor3qwr_4[16] =
+  "\025\000\000\000\000\000\000\000\033\000\000\000\t\000\000\000";
+static struct Location_6 *const _g1zxpjdmor3qwr_5 =
+  (struct Location_6*) _g1zxpjdmor3qwr_4;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(struct Location_6*);
+static __attribute__((aligned(8))) const char _g1zxpjdmor3qwr_6[16] =
+  "\025\000\000\000\000\000\000\000\033\000\000\000\t\000\000\000";
+static struct Location_6 *const _g1zxpjdmor3qwr_7 =
+  (struct Location_6*) _g1zxpjdmor3qwr_6;
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+int32_t test_cond(int32_t _0, int32_t _1, int32_t _2);
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  abort();
+  abort();
+}
+
+int32_t test_cond(int32_t _0, int32_t _1, int32_t _2) {
+  int32_t _3;
+  if (_0 == _1) {
+    int32_t _9;
+    if ((bool) __rust_ckd_add_i32(_0, _1, &_9)) {
+      _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+          _g1zxpjdmor3qwr_5);
+      abort();
+    }
+    int32_t _11;
+    if ((bool) __rust_ckd_add_i32(_9, _2, &_11)) {
+      _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+          _g1zxpjdmor3qwr_7);
+      abort();
+    }
+    (*(int32_t*) (&_3)) = _11;
+  } else {
+    int32_t _5;
+    if ((bool) __rust_ckd_sub_i32(_0, _1, &_5)) {
+      _ZN4core9panicking11panic_const24panic_const_sub_overflow17h35f73ecc35fc5ba3E(
+          _g1zxpjdmor3qwr_1);
+      abort();
+    }
+    int32_t _7;
+    if ((bool) __rust_ckd_sub_i32(_5, _2, &_7)) {
+      _ZN4core9panicking11panic_const24panic_const_sub_overflow17h35f73ecc35fc5ba3E(
+          _g1zxpjdmor3qwr_3);
+      abort();
+    }
+    (*(int32_t*) (&_3)) = _7;
+  }
+  return _3;
+}
+
+int32_t main() {
+  return 0;
+}
@@ -0,0 +1,172 @@
+/* file: header.e7b30cce3919417a-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct Point_8;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+struct Point_8 {
+  int32_t f0;
+  int32_t f1;
+};
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+_Static_assert(sizeof(struct Point_8) == 8, "layout mismatch");
+_Static_assert(_Alignof(struct Point_8) == 4, "layout mismatch");
+_Static_assert(offsetof(struct Point_8, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Point_8, f1) == 4, "layout mismatch");
+struct Point_8 ORIGIN = {0, 0};
+uint32_t CALLS = 0;
+int32_t point_norm1(struct Point_8*);
+static struct Point_8 *const _g1gkmgle5hqvbm_0 = &ORIGIN;
+bool point_is_origin(struct Point_8*);
+static __attribute__((always_inline)) int32_t
+_ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_mul17h5bdc5155fd1deaf9E(int32_t _0,
+                                                                     int32_t _1);
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+void point_scale(struct Point_8 *_0, int32_t _1);
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+static __attribute__((always_inline)) int32_t
+_ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_mul17h5bdc5155fd1deaf9E(int32_t _0,
+                                                                     int32_t _1) {
+  return _0 * _1;
+}
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  abort();
+  abort();
+}
+
+void point_scale(struct Point_8 *_0, int32_t _1) {
+  (*(int32_t*) _0)
+    = _ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_mul17h5bdc5155fd1deaf9E(
+        *(int32_t*) _0, _1);
+  int32_t _7 =
+    _ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_mul17h5bdc5155fd1deaf9E(
+        *(int32_t*) (int8_t*) (&((int8_t*) _0)[4]), _1);
+  (*(int32_t*) (int8_t*) (&((int8_t*) _0)[4])) = _7;
+  return;
+}
+
+int32_t main() {
+  int32_t _0;
+  _Alignas(4) char _1[8];
+  (*(int32_t*) (&_1)) = 1;
+  (*(int32_t*) (int8_t*) (&((int8_t*) (&_1))[4])) = -2;
+  point_scale((struct Point_8*) (void*) (&_1), 2);
+  if (point_norm1((struct Point_8*) (void*) (&_1)) == 6) {
+    if (!point_is_origin(_g1gkmgle5hqvbm_0)) {
+      (*(int32_t*) (&_0)) = 0;
+      goto bb7;
+    }
+  }
+  (*(int32_t*) (&_0)) = 1;
+bb7:;
+  return _0;
+}
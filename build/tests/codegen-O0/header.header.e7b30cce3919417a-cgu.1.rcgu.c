@@ -0,0 +1,74 @@
+/* file: header.e7b30cce3919417a-cgu.1.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct Point_0;
+struct Point_0 {
+  int32_t f0;
+  int32_t f1;
+};
+_Static_assert(sizeof(struct Point_0) == 8, "layout mismatch");
+_Static_assert(_Alignof(struct Point_0) == 4, "layout mismatch");
+_Static_assert(offsetof(struct Point_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Point_0, f1) == 4, "layout mismatch");
+static __attribute__((always_inline)) int32_t
+_ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_add17h611e69731c3d2f9fE(int32_t _0,
+                                                                     int32_t _1);
+static __attribute__((always_inline)) int32_t
+_ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_neg17hb1f36852da323a11E(int32_t _0);
+int32_t point_norm1(struct Point_0 *_0);
+bool point_is_origin(struct Point_0 *_0);
+static __attribute__((noinline)) int32_t
+_ZN6header4norm3abs17h014b9b7b5e37c4f9E(int32_t _0);
+
+/* rustc_codegen_c: implementation */
+
+static __attribute__((always_inline)) int32_t
+_ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_add17h611e69731c3d2f9fE(int32_t _0,
+                                                                     int32_t _1) {
+  return _0 + _1;
+}
+
+static __attribute__((always_inline)) int32_t
+_ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_neg17hb1f36852da323a11E(int32_t _0)
+{
+  return 0 - _0;
+}
+
+int32_t point_norm1(struct Point_0 *_0) {
+  int32_t _2 = _ZN6header4norm3abs17h014b9b7b5e37c4f9E(*(int32_t*) _0);
+  return
+    _ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_add17h611e69731c3d2f9fE(
+        _2,
+        _ZN6header4norm3abs17h014b9b7b5e37c4f9E(
+            *(int32_t*) (int8_t*) (&((int8_t*) _0)[4]))
+      );
+}
+
+bool point_is_origin(struct Point_0 *_0) {
+  bool _1;
+  if ((*(int32_t*) _0) == 0) {
+    (*(bool*) (&_1)) = (*(int32_t*) (int8_t*) (&((int8_t*) _0)[4])) == 0;
+  } else {
+    (*(bool*) (&_1)) = 0;
+  }
+  return _1;
+}
+
+static __attribute__((noinline)) int32_t
+_ZN6header4norm3abs17h014b9b7b5e37c4f9E(int32_t _0) {
+  int32_t _1;
+  if (_0 < 0) {
+    (*(int32_t*) (&_1))
+      = _ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_neg17hb1f36852da323a11E(
+          _0);
+  } else {
+    (*(int32_t*) (&_1)) = _0;
+  }
+  return _1;
+}
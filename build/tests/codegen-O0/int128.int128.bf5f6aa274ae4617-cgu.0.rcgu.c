@@ -0,0 +1,223 @@
+/* file: int128.bf5f6aa274ae4617-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+static __attribute__((aligned(8))) const char _g06bdyu8a0k3wh_0[16] =
+  "\027\000\000\000\000\000\000\000&\000\000\000\005\000\000\000";
+static struct Location_6 *const _g06bdyu8a0k3wh_1 =
+  (struct Location_6*) _g06bdyu8a0k3wh_0;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_mul_overflow17h8148a706815a3943E(struct Location_6*);
+static __attribute__((aligned(8))) const char _g06bdyu8a0k3wh_2[16] =
+  "\027\000\000\000\000\000\000\000K\000\000\000\005\000\000\000";
+static struct Location_6 *const _g06bdyu8a0k3wh_3 =
+  (struct Location_6*) _g06bdyu8a0k3wh_2;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_sub_overflow17h35f73ecc35fc5ba3E(struct Location_6*);
+static __attribute__((always_inline)) __rust_u128
+_ZN4core3num22_$LT$impl$u20$u128$GT$12wrapping_add17h57b266fcdb98db47E(__rust_u128 _0,
+                                                                      __rust_u128 _1);
+static __attribute__((always_inline)) __rust_u128
+_ZN4core3num22_$LT$impl$u20$u128$GT$12wrapping_mul17h1ced82dcb44e811dE(__rust_u128 _0,
+                                                                      __rust_u128 _1);
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+__rust_u128 mul_add(__rust_u128 _0, __rust_u128 _1, __rust_u128 _2);
+__rust_i128 sar(__rust_i128 _0);
+__rust_i128 widen(int64_t _0, uint32_t _1);
+int32_t narrow(__rust_u128 _0);
+bool less(__rust_i128 _0, __rust_i128 _1);
+__rust_u128 big();
+__rust_i128 minus();
+uint64_t big_u64();
+int64_t min_i64();
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+static __attribute__((always_inline)) __rust_u128
+_ZN4core3num22_$LT$impl$u20$u128$GT$12wrapping_add17h57b266fcdb98db47E(__rust_u128 _0,
+                                                                      __rust_u128 _1) {
+  return __rust_add_u128(_0, _1);
+}
+
+static __attribute__((always_inline)) __rust_u128
+_ZN4core3num22_$LT$impl$u20$u128$GT$12wrapping_mul17h1ced82dcb44e811dE(__rust_u128 _0,
+                                                                      __rust_u128 _1) {
+  return __rust_mul_u128(_0, _1);
+}
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  abort();
+  abort();
+}
+
+__rust_u128 mul_add(__rust_u128 _0, __rust_u128 _1, __rust_u128 _2) {
+  return
+    _ZN4core3num22_$LT$impl$u20$u128$GT$12wrapping_add17h57b266fcdb98db47E(
+        _ZN4core3num22_$LT$impl$u20$u128$GT$12wrapping_mul17h1ced82dcb44e811dE(
+            _0, _1),
+        _2
+      );
+}
+
+__rust_i128 sar(__rust_i128 _0) {
+  return
+    __rust_shr_i128(_0, __rust_i128_from_u128(__rust_u128_from_u64(100)));
+}
+
+__rust_i128 widen(int64_t _0, uint32_t _1) {
+  __rust_i128 _6;
+  if
+    (!(bool) __rust_ckd_mul_i128(
+            __rust_i128_from_u128(
+                __rust_u128_from_i64(
+                    __rust_utos(uint64_t, int64_t, (uint64_t) _0, INT64_MAX))
+              ),
+            __rust_i128_from_u128(__rust_u128_from_u64((uint64_t) _1)),
+            &_6
+          )) return _6;
+  _ZN4core9panicking11panic_const24panic_const_mul_overflow17h8148a706815a3943E(
+      _g06bdyu8a0k3wh_1);
+  abort();
+}
+
+int32_t narrow(__rust_u128 _0) {
+  return
+    __rust_utos(
+        uint32_t, int32_t, (uint32_t) __rust_u64_from_u128(_0), INT32_MAX);
+}
+
+bool less(__rust_i128 _0, __rust_i128 _1) {
+  return __rust_cmp_i128(_0, _1) < 0;
+}
+
+__rust_u128 big() {
+  return __rust_u128_const(0x123456789abcdef, 0xfedcba9876543210);
+}
+
+__rust_i128 minus() {
+  return __rust_i128_const(0xffffffffffffffff, 0xfffffffffffffffb);
+}
+
+uint64_t big_u64() {
+  uint64_t _0;
+  if (!(bool) __rust_ckd_sub_u64(18446744073709551615u, 1, &_0)) return _0;
+  _ZN4core9panicking11panic_const24panic_const_sub_overflow17h35f73ecc35fc5ba3E(
+      _g06bdyu8a0k3wh_3);
+  abort();
+}
+
+int64_t min_i64() {
+  return (-9223372036854775807 - 1);
+}
+
+int32_t main() {
+  return 0;
+}
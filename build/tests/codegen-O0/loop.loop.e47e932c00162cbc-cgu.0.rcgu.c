@@ -0,0 +1,286 @@
+/* file: loop.e47e932c00162cbc-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+static __attribute__((aligned(8))) const char _g037v2cy1ywtf6_0[16] =
+  "\025\000\000\000\000\000\000\000\034\000\000\000\t\000\000\000";
+static struct Location_6 *const _g037v2cy1ywtf6_1 =
+  (struct Location_6*) _g037v2cy1ywtf6_0;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(struct Location_6*);
+static __attribute__((aligned(8))) const char _g037v2cy1ywtf6_2[16] =
+  "\025\000\000\000\000\000\000\0003\000\000\000\t\000\000\000";
+static struct Location_6 *const _g037v2cy1ywtf6_3 =
+  (struct Location_6*) _g037v2cy1ywtf6_2;
+static __attribute__((aligned(8))) const char _g037v2cy1ywtf6_4[16] =
+  "\025\000\000\000\000\000\000\000K\000\000\000\t\000\000\000";
+static struct Location_6 *const _g037v2cy1ywtf6_5 =
+  (struct Location_6*) _g037v2cy1ywtf6_4;
+static __attribute__((aligned(8))) const char _g037v2cy1ywtf6_6[16] =
+  "\025\000\000\000\000\000\000\000E\000\000\000\r\000\000\000";
+static struct Location_6 *const _g037v2cy1ywtf6_7 =
+  (struct Location_6*) _g037v2cy1ywtf6_6;
+static __attribute__((aligned(8))) const char _g037v2cy1ywtf6_8[16] =
+  "\025\000\000\000\000\000\000\000I\000\000\000\r\000\000\000";
+static struct Location_6 *const _g037v2cy1ywtf6_9 =
+  (struct Location_6*) _g037v2cy1ywtf6_8;
+static __attribute__((aligned(8))) const char _g037v2cy1ywtf6_10[16] =
+  "\025\000\000\000\000\000\000\000X\000\000\000\t\000\000\000";
+static struct Location_6 *const _g037v2cy1ywtf6_11 =
+  (struct Location_6*) _g037v2cy1ywtf6_10;
+static __attribute__((always_inline)) uint32_t
+_ZN4core3num21_$LT$impl$u20$u32$GT$12wrapping_add17hc284865d6da5770aE(uint32_t _0,
+                                                                     uint32_t _1);
+static __attribute__((always_inline)) uint32_t
+_ZN4core3num21_$LT$impl$u20$u32$GT$12wrapping_mul17h6a75524ebc0b85f8E(uint32_t _0,
+                                                                     uint32_t _1);
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+uint32_t sum_to(uint32_t _0);
+uint32_t find(uint32_t _0, uint32_t _1);
+uint32_t pairs(uint32_t _0);
+uint32_t digits(uint32_t _0);
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+static __attribute__((always_inline)) uint32_t
+_ZN4core3num21_$LT$impl$u20$u32$GT$12wrapping_add17hc284865d6da5770aE(uint32_t _0,
+                                                                     uint32_t _1) {
+  return _0 + _1;
+}
+
+static __attribute__((always_inline)) uint32_t
+_ZN4core3num21_$LT$impl$u20$u32$GT$12wrapping_mul17h6a75524ebc0b85f8E(uint32_t _0,
+                                                                     uint32_t _1) {
+  return _0 * _1;
+}
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  abort();
+  abort();
+}
+
+uint32_t sum_to(uint32_t _0) {
+  uint32_t _1;
+  uint32_t _2;
+  (*(uint32_t*) (&_1)) = 0;
+  (*(uint32_t*) (&_2)) = 0;
+  while (_2 < _0) {
+    _1
+      = _ZN4core3num21_$LT$impl$u20$u32$GT$12wrapping_add17hc284865d6da5770aE(
+          _1, _2);
+    uint32_t _10;
+    if ((bool) __rust_ckd_add_u32(_2, 1, &_10)) {
+      _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+          _g037v2cy1ywtf6_1);
+      abort();
+    }
+    _2 = _10;
+  }
+  return _1;
+}
+
+uint32_t find(uint32_t _0, uint32_t _1) {
+  uint32_t _2;
+  uint32_t _3;
+  (*(uint32_t*) (&_3)) = 0;
+  while (_3 != _0) {
+    if (_ZN4core3num21_$LT$impl$u20$u32$GT$12wrapping_mul17h6a75524ebc0b85f8E(
+          _3, _3) == _1) {
+      (*(uint32_t*) (&_2)) = _3;
+      goto bb8;
+    }
+    uint32_t _11;
+    if ((bool) __rust_ckd_add_u32(_3, 1, &_11)) {
+      _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+          _g037v2cy1ywtf6_3);
+      abort();
+    }
+    _3 = _11;
+  }
+  (*(uint32_t*) (&_2)) = 4294967295;
+bb8:;
+  return _2;
+}
+
+uint32_t pairs(uint32_t _0) {
+  uint32_t _1;
+  uint32_t _2;
+  uint32_t _3;
+  (*(uint32_t*) (&_1)) = 0;
+  (*(uint32_t*) (&_2)) = 0;
+  while (_2 < _0) {
+    (*(uint32_t*) (&_3)) = 0;
+    while (_3 < _0) {
+      uint32_t _13;
+      if ((bool) __rust_ckd_add_u32(_3, 1, &_13)) {
+        _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+            _g037v2cy1ywtf6_7);
+        abort();
+      }
+      _3 = _13;
+      if (((_2 ^ _3) & 1) != 0) {
+        uint32_t _21;
+        if ((bool) __rust_ckd_add_u32(_1, 1, &_21)) {
+          _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+              _g037v2cy1ywtf6_9);
+          abort();
+        }
+        _1 = _21;
+      }
+    }
+    uint32_t _10;
+    if ((bool) __rust_ckd_add_u32(_2, 1, &_10)) {
+      _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+          _g037v2cy1ywtf6_5);
+      abort();
+    }
+    _2 = _10;
+  }
+  return _1;
+}
+
+uint32_t digits(uint32_t _0) {
+  uint32_t _1;
+  uint32_t _2;
+  (*(uint32_t*) (&_1)) = _0;
+  (*(uint32_t*) (&_2)) = 0;
+  while (1) {
+    uint32_t _4;
+    if ((bool) __rust_ckd_add_u32(_2, 1, &_4)) break;
+    _2 = _4;
+    _1 = _1 / 10;
+    if (_1 == 0) return _2;
+  }
+  _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+      _g037v2cy1ywtf6_11);
+  abort();
+}
+
+int32_t main() {
+  int32_t _0;
+  if (sum_to(5) == 10) {
+    if (find(10, 49) == 7) {
+      if (find(3, 49) == 4294967295) {
+        if (pairs(3) == 5) {
+          if (digits(0) == 1) {
+            if (digits(1234) == 4) {
+              (*(int32_t*) (&_0)) = 0;
+              goto bb14;
+            }
+          }
+        }
+      }
+    }
+  }
+  (*(int32_t*) (&_0)) = 1;
+bb14:;
+  return _0;
+}
@@ -0,0 +1,174 @@
+/* file: pointer.832c4072456514f9-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+static int32_t
+_ZN4core4cell13Cell$LT$T$GT$3get17ha5cdf586fd88974aE(int32_t *_0);
+static int32_t
+_ZN4core4cell13Cell$LT$T$GT$3new17h6923f69b3591bfa2E(int32_t _0);
+static void
+_ZN4core4cell13Cell$LT$T$GT$3set17hecf6e7769fc7e9deE(int32_t *_0, int32_t _1);
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+__attribute__((noinline)) void set(int32_t *_0, int32_t _1);
+__attribute__((noinline)) int32_t get(int32_t *_0);
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+static int32_t
+_ZN4core4cell13Cell$LT$T$GT$3get17ha5cdf586fd88974aE(int32_t *_0) {
+  return *(int32_t*) (intptr_t) _0;
+}
+
+static int32_t
+_ZN4core4cell13Cell$LT$T$GT$3new17h6923f69b3591bfa2E(int32_t _0) {
+  return _0;
+}
+
+static void
+_ZN4core4cell13Cell$LT$T$GT$3set17hecf6e7769fc7e9deE(int32_t *_0, int32_t _1)
+{
+  intptr_t _2 = (intptr_t) _0;
+  int32_t _3 = *(int32_t*) _2;
+  (*(int32_t*) _2) = _1;
+  return;
+}
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  abort();
+  abort();
+}
+
+__attribute__((noinline)) void set(int32_t *_0, int32_t _1) {
+  _ZN4core4cell13Cell$LT$T$GT$3set17hecf6e7769fc7e9deE(_0, _1);
+  return;
+}
+
+__attribute__((noinline)) int32_t get(int32_t *_0) {
+  return *_0;
+}
+
+int32_t main() {
+  int32_t _0;
+  _Alignas(4) char _1[4];
+  _Alignas(4) char _2[4];
+  (*(int32_t*) (&_1))
+    = _ZN4core4cell13Cell$LT$T$GT$3new17h6923f69b3591bfa2E(1);
+  set((int32_t*) (void*) (&_1), 2);
+  (*(int32_t*) (&_2))
+    = _ZN4core4cell13Cell$LT$T$GT$3get17ha5cdf586fd88974aE(
+        (int32_t*) (void*) (&_1));
+  if (get((int32_t*) (void*) (&_2)) == 2) {
+    (*(int32_t*) (&_0)) = 0;
+  } else {
+    (*(int32_t*) (&_0)) = 1;
+  }
+  return _0;
+}
@@ -0,0 +1,192 @@
+/* file: source_locs.315c6ec8f89eb3b9-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+static __attribute__((aligned(8))) const char _g1n3jyukvcyajo_0[16] =
+  "\022\000\000\000\000\000\000\000+\000\000\000\t\000\000\000";
+static struct Location_6 *const _g1n3jyukvcyajo_1 =
+  (struct Location_6*) _g1n3jyukvcyajo_0;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(struct Location_6*);
+static __attribute__((always_inline)) int32_t
+_ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_add17h611e69731c3d2f9fE(int32_t _0,
+                                                                     int32_t _1);
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+int32_t add_one(int32_t _0);
+uint32_t count(uint32_t _0);
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+static __attribute__((always_inline)) int32_t
+_ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_add17h611e69731c3d2f9fE(int32_t _0,
+                                                                     int32_t _1) {
+  _Alignas(4) char _2[4];
+  _Alignas(4) char _3[4];
+  (*(int32_t*) (&_2)) = _0;
+  (*(int32_t*) (&_3)) = _1;
+  #line 336 "/rustc/6868c831a1eb45c5150ff623cef5e42a8b8946d0/library/core/src/num/mod.rs"
+  return _0 + _1;
+}
+#line 138 "build/tests/codegen-O0/source_locs.source_locs.315c6ec8f89eb3b9-cgu.0.rcgu.c"
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  _Alignas(8) char _1[8];
+  (*(struct PanicInfo_0**) (&_1)) = _0;
+  #line 10 "src/source_locs.rs"
+  abort();
+  abort();
+}
+#line 148 "build/tests/codegen-O0/source_locs.source_locs.315c6ec8f89eb3b9-cgu.0.rcgu.c"
+
+int32_t add_one(int32_t _0) {
+  _Alignas(4) char _1[4];
+  (*(int32_t*) (&_1)) = _0;
+  #line 26 "src/source_locs.rs"
+  return
+    _ZN4core3num21_$LT$impl$u20$i32$GT$12wrapping_add17h611e69731c3d2f9fE(
+        _0, 1);
+}
+#line 158 "build/tests/codegen-O0/source_locs.source_locs.315c6ec8f89eb3b9-cgu.0.rcgu.c"
+
+uint32_t count(uint32_t _0) {
+  uint32_t _1;
+  _Alignas(4) char _2[4];
+  (*(uint32_t*) (&_2)) = _0;
+  #line 41 "src/source_locs.rs"
+  (*(uint32_t*) (&_1)) = 0;
+  #line 42 "src/source_locs.rs"
+  while (_1 < _0) {
+    #line 43 "src/source_locs.rs"
+    uint32_t _7;
+    if ((bool) __rust_ckd_add_u32(_1, 1, &_7)) {
+      _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+          _g1n3jyukvcyajo_1);
+      abort();
+    }
+    _1 = _7;
+  }
+  #line 46 "src/source_locs.rs"
+  return _1;
+}
+#line 180 "build/tests/codegen-O0/source_locs.source_locs.315c6ec8f89eb3b9-cgu.0.rcgu.c"
+
+int32_t main() {
+  int32_t _0;
+  #line 50 "src/source_locs.rs"
+  if (count(3) == 3) {
+    (*(int32_t*) (&_0)) = add_one(-1);
+  } else {
+    (*(int32_t*) (&_0)) = 1;
+  }
+  #line 51 "src/source_locs.rs"
+  return _0;
+}
+#line 193 "build/tests/codegen-O0/source_locs.source_locs.315c6ec8f89eb3b9-cgu.0.rcgu.c"
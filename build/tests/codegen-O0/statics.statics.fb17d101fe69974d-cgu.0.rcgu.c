@@ -0,0 +1,224 @@
+/* file: statics.fb17d101fe69974d-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct Config_8;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+struct Config_8 {
+  uint8_t f0;
+  uint8_t _pad0[3];
+  uint32_t f1;
+  double f2;
+};
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+_Static_assert(sizeof(struct Config_8) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Config_8) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Config_8, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Config_8, f1) == 4, "layout mismatch");
+_Static_assert(offsetof(struct Config_8, f2) == 8, "layout mismatch");
+struct Config_8 CONFIG = {1, {0}, 100, 0x1p-1};
+uint64_t COUNTER = 7;
+static __attribute__((section(".rodata.table"), used)) uint16_t _ZN7statics5TABLE17h6f64d692ef6af5caE[4] =
+  {1, 2, 3, 4};
+static const intptr_t _g14exrho21ixim_0 = (intptr_t) (&COUNTER);
+static __attribute__((aligned(8))) const char _g14exrho21ixim_1[16] =
+  "\030\000\000\000\000\000\000\000:\000\000\000\t\000\000\000";
+static struct Location_6 *const _g14exrho21ixim_2 =
+  (struct Location_6*) _g14exrho21ixim_1;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking36panic_misaligned_pointer_dereference17h696b4844a52c39ddE(uintptr_t,
+                                                                            uintptr_t,
+                                                                            struct Location_6*);
+static __attribute__((aligned(8))) const char _g14exrho21ixim_3[16] =
+  "\030\000\000\000\000\000\000\000:\000\000\000\t\000\000\000";
+static struct Location_6 *const _g14exrho21ixim_4 =
+  (struct Location_6*) _g14exrho21ixim_3;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(struct Location_6*);
+static __attribute__((aligned(8))) const char _g14exrho21ixim_5[16] =
+  "\030\000\000\000\000\000\000\000:\000\000\000\t\000\000\000";
+static struct Location_6 *const _g14exrho21ixim_6 =
+  (struct Location_6*) _g14exrho21ixim_5;
+static __attribute__((aligned(8))) const char _g14exrho21ixim_7[16] =
+  "\030\000\000\000\000\000\000\000;\000\000\000\t\000\000\000";
+static struct Location_6 *const _g14exrho21ixim_8 =
+  (struct Location_6*) _g14exrho21ixim_7;
+static struct Config_8 *const _g14exrho21ixim_9 = &CONFIG;
+extern intptr_t environ;
+static const intptr_t _g14exrho21ixim_10 = (intptr_t) (&environ);
+static __attribute__((aligned(8))) const char _g14exrho21ixim_11[16] =
+  "\030\000\000\000\000\000\000\000A\000\000\0007\000\000\000";
+static struct Location_6 *const _g14exrho21ixim_12 =
+  (struct Location_6*) _g14exrho21ixim_11;
+static bool
+_ZN4core3ptr9const_ptr33_$LT$impl$u20$$BP$const$u20$T$GT$7is_null17ha2c01038c6a6d9e1E(intptr_t _0);
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+__attribute__((noinline)) uint64_t bump();
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+static bool
+_ZN4core3ptr9const_ptr33_$LT$impl$u20$$BP$const$u20$T$GT$7is_null17ha2c01038c6a6d9e1E(intptr_t _0)
+{
+  return (uintptr_t) _0 == 0;
+}
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  abort();
+  abort();
+}
+
+__attribute__((noinline)) uint64_t bump() {
+  uintptr_t _0 = (uintptr_t) _g14exrho21ixim_0;
+  if ((_0 & 7) != 0) {
+    _ZN4core9panicking36panic_misaligned_pointer_dereference17h696b4844a52c39ddE(
+        8, _0, _g14exrho21ixim_2);
+    abort();
+  }
+  uint64_t _4;
+  if ((bool) __rust_ckd_add_u64(*(uint64_t*) _g14exrho21ixim_0, 1, &_4)) {
+    _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+        _g14exrho21ixim_4);
+    abort();
+  }
+  uintptr_t _6 = (uintptr_t) _g14exrho21ixim_0;
+  if ((_6 & 7) != 0) {
+    _ZN4core9panicking36panic_misaligned_pointer_dereference17h696b4844a52c39ddE(
+        8, _6, _g14exrho21ixim_6);
+    abort();
+  }
+  (*(uint64_t*) _g14exrho21ixim_0) = _4;
+  uintptr_t _9 = (uintptr_t) _g14exrho21ixim_0;
+  if ((_9 & 7) == 0) return *(uint64_t*) _g14exrho21ixim_0;
+  _ZN4core9panicking36panic_misaligned_pointer_dereference17h696b4844a52c39ddE(
+      8, _9, _g14exrho21ixim_8);
+  abort();
+}
+
+int32_t main() {
+  int32_t _0;
+  if (bump() == 8) {
+    if ((*(uint32_t*) (int8_t*) (&((int8_t*) _g14exrho21ixim_9)[4])) == 100) {
+      uintptr_t _7 = (uintptr_t) _g14exrho21ixim_10;
+      if ((_7 & 7) != 0) {
+        _ZN4core9panicking36panic_misaligned_pointer_dereference17h696b4844a52c39ddE(
+            8, _7, _g14exrho21ixim_12);
+        abort();
+      }
+      if (!_ZN4core3ptr9const_ptr33_$LT$impl$u20$$BP$const$u20$T$GT$7is_null17ha2c01038c6a6d9e1E(
+              *(intptr_t*) _g14exrho21ixim_10)) {
+        (*(int32_t*) (&_0)) = 0;
+        goto bb7;
+      }
+    }
+  }
+  (*(int32_t*) (&_0)) = 1;
+bb7:;
+  return _0;
+}
@@ -0,0 +1,241 @@
+/* file: switch.faf46dbfb7e24fdc-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+static __attribute__((aligned(8))) const char _g3ucaap3ey8gzg_0[16] =
+  "\027\000\000\000\000\000\000\000D\000\000\000\010\000\000\000";
+static struct Location_6 *const _g3ucaap3ey8gzg_1 =
+  (struct Location_6*) _g3ucaap3ey8gzg_0;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(struct Location_6*);
+static __attribute__((aligned(8))) const char _g3ucaap3ey8gzg_2[16] =
+  "\027\000\000\000\000\000\000\000D\000\000\000\010\000\000\000";
+static struct Location_6 *const _g3ucaap3ey8gzg_3 =
+  (struct Location_6*) _g3ucaap3ey8gzg_2;
+static __attribute__((aligned(8))) const char _g3ucaap3ey8gzg_4[16] =
+  "\027\000\000\000\000\000\000\000D\000\000\000\010\000\000\000";
+static struct Location_6 *const _g3ucaap3ey8gzg_5 =
+  (struct Location_6*) _g3ucaap3ey8gzg_4;
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+int32_t classify(int32_t _0);
+uint8_t byte_class(uint8_t _0);
+uint32_t wide_class(__rust_u128 _0);
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  abort();
+  abort();
+}
+
+int32_t classify(int32_t _0) {
+  int32_t _1;
+  switch (_0) {
+    case -1: goto bb2;
+    case 0: goto bb3;
+    case 7: goto bb4;
+    default: goto bb1;
+  }
+bb2:;
+  (*(int32_t*) (&_1)) = 10;
+  goto bb5;
+bb3:;
+  (*(int32_t*) (&_1)) = 20;
+  goto bb5;
+bb4:;
+  (*(int32_t*) (&_1)) = 30;
+  goto bb5;
+bb1:;
+  (*(int32_t*) (&_1)) = 40;
+bb5:;
+  return _1;
+}
+
+uint8_t byte_class(uint8_t _0) {
+  uint8_t _1;
+  switch (_0) {
+    case 1: goto bb2;
+    case 200: goto bb3;
+    case 255: goto bb4;
+    default: goto bb1;
+  }
+bb2:;
+  (*(uint8_t*) (&_1)) = 2;
+  goto bb5;
+bb3:;
+  (*(uint8_t*) (&_1)) = 3;
+  goto bb5;
+bb4:;
+  (*(uint8_t*) (&_1)) = 4;
+  goto bb5;
+bb1:;
+  (*(uint8_t*) (&_1)) = 5;
+bb5:;
+  return _1;
+}
+
+uint32_t wide_class(__rust_u128 _0) {
+  uint32_t _1;
+  if (__rust_cmp_u128(_0, __rust_u128_const(0x0, 0x1)) == 0) {
+    (*(uint32_t*) (&_1)) = 2;
+  } else if (__rust_cmp_u128(
+        _0, __rust_u128_const(0xffffffffffffffff, 0xffffffffffffffff)) == 0) {
+    (*(uint32_t*) (&_1)) = 3;
+  } else if (__rust_cmp_u128(_0, __rust_u128_const(0x0, 0x5)) == 0) {
+    (*(uint32_t*) (&_1)) = 4;
+  } else {
+    (*(uint32_t*) (&_1)) = 5;
+  }
+  return _1;
+}
+
+int32_t main() {
+  int32_t _0;
+  int32_t _1 = classify(-1);
+  int32_t _2 = classify(0);
+  int32_t _3;
+  if ((bool) __rust_ckd_add_i32(_1, _2, &_3)) {
+    _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+        _g3ucaap3ey8gzg_1);
+    abort();
+  }
+  int32_t _5 = classify(7);
+  int32_t _6;
+  if ((bool) __rust_ckd_add_i32(_3, _5, &_6)) {
+    _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+        _g3ucaap3ey8gzg_3);
+    abort();
+  }
+  int32_t _8 = classify(3);
+  int32_t _9;
+  if ((bool) __rust_ckd_add_i32(_6, _8, &_9)) {
+    _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+        _g3ucaap3ey8gzg_5);
+    abort();
+  }
+  if (_9 == 100) {
+    if (byte_class(255) == 4) {
+      if (byte_class(0) == 5) {
+        if (wide_class(
+              __rust_u128_const(0xffffffffffffffff, 0xffffffffffffffff))
+            == 3) {
+          (*(int32_t*) (&_0)) = 0;
+          goto bb17;
+        }
+      }
+    }
+    (*(int32_t*) (&_0)) = 1;
+  } else {
+    (*(int32_t*) (&_0)) = 1;
+  }
+bb17:;
+  return _0;
+}
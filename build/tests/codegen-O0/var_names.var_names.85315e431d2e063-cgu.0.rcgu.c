@@ -0,0 +1,186 @@
+/* file: var_names.85315e431d2e063-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+static __attribute__((aligned(8))) const char _g2u002plhi3b4y_0[16] =
+  "\032\000\000\000\000\000\000\000 \000\000\000\r\000\000\000";
+static struct Location_6 *const _g2u002plhi3b4y_1 =
+  (struct Location_6*) _g2u002plhi3b4y_0;
+__attribute__((noinline, cold, noreturn)) void
+_ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(struct Location_6*);
+static __attribute__((aligned(8))) const char _g2u002plhi3b4y_2[16] =
+  "\032\000\000\000\000\000\000\000\"\000\000\000\t\000\000\000";
+static struct Location_6 *const _g2u002plhi3b4y_3 =
+  (struct Location_6*) _g2u002plhi3b4y_2;
+static __attribute__((always_inline)) uint64_t
+_ZN4core3num21_$LT$impl$u20$u64$GT$12wrapping_add17h60f93cdccac82c26E(uint64_t self_0,
+                                                                     uint64_t rhs_1);
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *info_0);
+uint32_t count_odd(uint32_t limit_0);
+uint64_t sum(uint32_t pair_0_0, uint64_t pair_1_1);
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+static __attribute__((always_inline)) uint64_t
+_ZN4core3num21_$LT$impl$u20$u64$GT$12wrapping_add17h60f93cdccac82c26E(uint64_t self_0,
+                                                                     uint64_t rhs_1) {
+  return self_0 + rhs_1;
+}
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *info_0) {
+  abort();
+  abort();
+}
+
+uint32_t count_odd(uint32_t limit_0) {
+  uint32_t count_1;
+  uint32_t int_2;
+  (*(uint32_t*) (&count_1)) = 0;
+  (*(uint32_t*) (&int_2)) = 0;
+  while (int_2 < limit_0) {
+    if ((int_2 & 1) == 1) {
+      uint32_t _10;
+      if ((bool) __rust_ckd_add_u32(count_1, 1, &_10)) {
+        _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+            _g2u002plhi3b4y_1);
+        abort();
+      }
+      count_1 = _10;
+    }
+    uint32_t _13;
+    if ((bool) __rust_ckd_add_u32(int_2, 1, &_13)) {
+      _ZN4core9panicking11panic_const24panic_const_add_overflow17h7e8e80f8aac3cc31E(
+          _g2u002plhi3b4y_3);
+      abort();
+    }
+    int_2 = _13;
+  }
+  return count_1;
+}
+
+uint64_t sum(uint32_t pair_0_0, uint64_t pair_1_1) {
+  return
+    _ZN4core3num21_$LT$impl$u20$u64$GT$12wrapping_add17h60f93cdccac82c26E(
+        (uint64_t) pair_0_0, pair_1_1);
+}
+
+int32_t main() {
+  int32_t _0;
+  if (count_odd(5) == 2) {
+    if (sum(1, 2) == 3) {
+      (*(int32_t*) (&_0)) = 0;
+      goto bb6;
+    }
+  }
+  (*(int32_t*) (&_0)) = 1;
+bb6:;
+  return _0;
+}
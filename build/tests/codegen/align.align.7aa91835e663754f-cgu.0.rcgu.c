@@ -0,0 +1,192 @@
+/* file: align.7aa91835e663754f-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct Wide_8;
+struct Packed_9;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+struct Wide_8 {
+  uint32_t f0;
+  uint8_t _pad0[60];
+} __attribute__((aligned(64)));
+struct Packed_9 {
+  uint32_t f1;
+  uint8_t f0;
+  uint8_t _pad0[1];
+} __attribute__((packed, aligned(2)));
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+_Static_assert(sizeof(struct Wide_8) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct Wide_8) == 64, "layout mismatch");
+_Static_assert(offsetof(struct Wide_8, f0) == 0, "layout mismatch");
+_Static_assert(sizeof(struct Packed_9) == 6, "layout mismatch");
+_Static_assert(_Alignof(struct Packed_9) == 2, "layout mismatch");
+_Static_assert(offsetof(struct Packed_9, f1) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Packed_9, f0) == 4, "layout mismatch");
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+__attribute__((noinline)) uint32_t make_wide(uint32_t _0);
+__attribute__((noinline)) uint32_t wide_x(struct Wide_8 *_0);
+__attribute__((noinline)) uint32_t get_b(struct Packed_9 *_0);
+__attribute__((noinline)) void set_b(struct Packed_9 *_0, uint32_t _1);
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  abort();
+  abort();
+}
+
+__attribute__((noinline)) uint32_t make_wide(uint32_t _0) {
+  uint32_t _1;
+  _Alignas(64) char _2[64];
+  (*(uint32_t*) (&_2)) = _0;
+  if (((uintptr_t) (void*) (&_2) % 64) == 0) {
+    (*(uint32_t*) (&_1)) = wide_x((struct Wide_8*) (void*) (&_2));
+  } else {
+    (*(uint32_t*) (&_1)) = 0;
+  }
+  return _1;
+}
+
+__attribute__((noinline)) uint32_t wide_x(struct Wide_8 *_0) {
+  return *(uint32_t*) _0;
+}
+
+__attribute__((noinline)) uint32_t get_b(struct Packed_9 *_0) {
+  uint32_t _1;
+  __rust_memcpy(&_1, _0, sizeof(uint32_t));
+  return _1;
+}
+
+__attribute__((noinline)) void set_b(struct Packed_9 *_0, uint32_t _1) {
+  uint32_t _2 = _1;
+  __rust_memcpy(_0, &_2, sizeof(uint32_t));
+  return;
+}
+
+int32_t main() {
+  int32_t _0;
+  _Alignas(2) char _1[6];
+  if (make_wide(7) == 7) {
+    (*(uint8_t*) (int8_t*) (&((int8_t*) (&_1))[4])) = 1;
+    uint32_t _6 = 2;
+    __rust_memcpy(&_1, &_6, sizeof(uint32_t));
+    set_b((struct Packed_9*) (void*) (&_1), 305419896);
+    if (get_b((struct Packed_9*) (void*) (&_1)) == 305419896) {
+      if ((*(uint8_t*) (int8_t*) (&((int8_t*) (&_1))[4])) == 1) {
+        (*(int32_t*) (&_0)) = 0;
+        goto bb11;
+      }
+    }
+    (*(int32_t*) (&_0)) = 2;
+  } else {
+    (*(int32_t*) (&_0)) = 1;
+  }
+bb11:;
+  return _0;
+}
@@ -0,0 +1,207 @@
+/* file: float.f34a249e9a745323-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+double arith(double _0, double _1);
+float arith32(float _0, float _1);
+bool lt(double _0, double _1);
+bool ne(float _0, float _1);
+double constants();
+double to_f64(int32_t _0);
+int32_t to_int(double _0);
+uint8_t to_byte(float _0);
+uint64_t to_u64(double _0);
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  abort();
+  abort();
+}
+
+double arith(double _0, double _1) {
+  return
+    ((((_0 + _1) * (_0 - _1)) / 0x1.8p+1) + __rust_fmod(_0, _1))
+      - 0x1.999999999999ap-4;
+}
+
+float arith32(float _0, float _1) {
+  return __rust_fmodf(-((_0 * _1) + 0x1.8p+0f), 0x1p+1f);
+}
+
+bool lt(double _0, double _1) {
+  return _0 < _1;
+}
+
+bool ne(float _0, float _1) {
+  return _0 != _1;
+}
+
+double constants() {
+  double _0 = arith(-__rust_inf, 0x0.0000000000001p-1022);
+  return _0 + (double) arith32(__rust_nanf, -0x0p+0f);
+}
+
+double to_f64(int32_t _0) {
+  return (double) _0;
+}
+
+int32_t to_int(double _0) {
+  return
+    (int32_t) ((_0 != _0) ? 0
+        : ((_0 >= 0x1p+31) ? 2147483647
+          : ((_0 <= -0x1p+31) ? -2147483648 : (int32_t) _0)));
+}
+
+uint8_t to_byte(float _0) {
+  return
+    (_0 != _0) ? 0
+      : ((_0 >= 0x1p+8f) ? 255 : ((_0 <= -0x1p+0f) ? 0 : (uint8_t) _0));
+}
+
+uint64_t to_u64(double _0) {
+  return
+    (_0 != _0) ? 0
+      : ((_0 >= 0x1p+64) ? 18446744073709551615u
+        : ((_0 <= -0x1p+0) ? 0 : (uint64_t) _0));
+}
+
+int32_t main() {
+  int32_t _0;
+  if (to_int(__rust_nan) == 0) {
+    if (to_int(0x1.2a05f2p+33) == 2147483647) {
+      if (to_int(-0x1.2a05f2p+33) == -2147483648) {
+        if (to_int(-0x1.d99999999999ap+1) == -3) {
+          if (to_byte(-0x1p-1f) == 0) {
+            if (to_byte(-0x1.cp+2f) == 0) {
+              if (to_byte(0x1.2cp+8f) == 255) {
+                if (to_u64(__rust_inf) == 18446744073709551615u) {
+                  if (to_u64(-__rust_inf) == 0) {
+                    (*(int32_t*) (&_0)) = 0;
+                    goto bb31;
+                  }
+                }
+                (*(int32_t*) (&_0)) = 3;
+                goto bb31;
+              }
+            }
+          }
+        }
+        (*(int32_t*) (&_0)) = 2;
+        goto bb31;
+      }
+    }
+  }
+  (*(int32_t*) (&_0)) = 1;
+bb31:;
+  return _0;
+}
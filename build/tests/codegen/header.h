@@ -0,0 +1,18 @@
+#ifndef HEADER_H
+#define HEADER_H
+
+#include <stdbool.h>
+#include <stdint.h>
+
+struct Point_0;
+struct Point_0 {
+  int32_t f0;
+  int32_t f1;
+};
+extern struct Point_0 ORIGIN;
+extern uint32_t CALLS;
+void point_scale(struct Point_0 *_0, int32_t _1);
+int32_t point_norm1(struct Point_0 *_0);
+bool point_is_origin(struct Point_0 *_0);
+
+#endif /* HEADER_H */
@@ -0,0 +1,166 @@
+/* file: layout.cb7e2a6054d27623-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct Big_8;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+struct Big_8 {
+  uint64_t f0;
+  uint32_t f1;
+  uint8_t f2[20];
+};
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+_Static_assert(sizeof(struct Big_8) == 32, "layout mismatch");
+_Static_assert(_Alignof(struct Big_8) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Big_8, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Big_8, f1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Big_8, f2) == 12, "layout mismatch");
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+void copy_big(struct Big_8 *_0, struct Big_8 *_1);
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  abort();
+  abort();
+}
+
+void copy_big(struct Big_8 *_0, struct Big_8 *_1) {
+  struct Big_8 _2;
+  __rust_memcpy(&_2, _0, sizeof(struct Big_8));
+  __rust_memcpy(_1, &_2, sizeof(struct Big_8));
+  return;
+}
+
+int32_t main() {
+  int32_t _0;
+  _Alignas(8) char _1[32];
+  uint8_t _2[20];
+  _Alignas(8) char _3[32];
+  uint8_t _4[20];
+  uint8_t *_5 = (uint8_t*) (&_2);
+  for(uintptr_t _6 = 0; _6 < 20; ++_6) _5[_6] = 3;
+  (*(uint64_t*) (&_1)) = 1;
+  (*(uint32_t*) (int8_t*) (&((int8_t*) (&_1))[8])) = 2;
+  __rust_memcpy((int8_t*) (&((int8_t*) (&_1))[12]), &_2, sizeof(uint8_t[20]));
+  __rust_memset(&_4, 0, 20);
+  (*(uint64_t*) (&_3)) = 0;
+  (*(uint32_t*) (int8_t*) (&((int8_t*) (&_3))[8])) = 0;
+  __rust_memcpy((int8_t*) (&((int8_t*) (&_3))[12]), &_4, sizeof(uint8_t[20]));
+  copy_big((struct Big_8*) (void*) (&_1), (struct Big_8*) (void*) (&_3));
+  if ((*(uint64_t*) (&_3)) == 1) {
+    if ((*(uint32_t*) (int8_t*) (&((int8_t*) (&_3))[8])) == 2) {
+      (*(int32_t*) (&_0)) = 0;
+      goto bb7;
+    }
+  }
+  (*(int32_t*) (&_0)) = 1;
+bb7:;
+  return _0;
+}
@@ -0,0 +1,179 @@
+/* file: ternary.d86dbe3d4670e431-cgu.0.c */
+#include <stdlib.h>
+#include <stdint.h>
+#include <stddef.h>
+#include <stdbool.h>
+#include <rust_runtime.h>
+
+/* rustc_codegen_c: interface */
+
+struct str_2;
+struct core_fmt_rt_Argument_3;
+struct Option_4_tag;
+struct core_fmt_rt_Placeholder_5;
+struct Option_4_v1;
+union Option_4_u;
+struct Option_4;
+struct Arguments_1;
+struct PanicInfo_0;
+struct str_7;
+struct Location_6;
+struct str_2 {
+  void *f0;
+  uintptr_t f1;
+};
+struct core_fmt_rt_Argument_3 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_tag {
+  intptr_t tag;
+  uint8_t _pad0[8];
+};
+struct core_fmt_rt_Placeholder_5 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Option_4_v1 {
+  struct core_fmt_rt_Placeholder_5 f0;
+};
+union Option_4_u {
+  struct Option_4_tag tag;
+  struct Option_4_v1 v1;
+};
+struct Option_4 {
+  union Option_4_u u;
+};
+struct Arguments_1 {
+  struct str_2 f0;
+  struct core_fmt_rt_Argument_3 f2;
+  struct Option_4 f1;
+};
+struct PanicInfo_0 {
+  struct Arguments_1 f0;
+  struct Location_6 *f1;
+  bool f2;
+  bool f3;
+  uint8_t _pad0[6];
+};
+struct str_7 {
+  void *f0;
+  uintptr_t f1;
+};
+struct Location_6 {
+  struct str_7 f0;
+  uint32_t f1;
+  uint32_t f2;
+};
+_Static_assert(sizeof(struct str_2) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_2) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_2, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Argument_3)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Argument_3)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Argument_3, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct core_fmt_rt_Placeholder_5)
+  == 16, "layout mismatch");
+_Static_assert(_Alignof(struct core_fmt_rt_Placeholder_5)
+  == 8, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f0)
+  == 0, "layout mismatch");
+_Static_assert(offsetof(struct core_fmt_rt_Placeholder_5, f1)
+  == 8, "layout mismatch");
+_Static_assert(sizeof(struct Option_4) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct Option_4) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Arguments_1) == 48, "layout mismatch");
+_Static_assert(_Alignof(struct Arguments_1) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f2) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Arguments_1, f1) == 32, "layout mismatch");
+_Static_assert(sizeof(struct str_7) == 16, "layout mismatch");
+_Static_assert(_Alignof(struct str_7) == 8, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct str_7, f1) == 8, "layout mismatch");
+_Static_assert(sizeof(struct Location_6) == 24, "layout mismatch");
+_Static_assert(_Alignof(struct Location_6) == 8, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f1) == 16, "layout mismatch");
+_Static_assert(offsetof(struct Location_6, f2) == 20, "layout mismatch");
+_Static_assert(sizeof(struct PanicInfo_0) == 64, "layout mismatch");
+_Static_assert(_Alignof(struct PanicInfo_0) == 8, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f0) == 0, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f1) == 48, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f2) == 56, "layout mismatch");
+_Static_assert(offsetof(struct PanicInfo_0, f3) == 57, "layout mismatch");
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0);
+int8_t order(int32_t _0, int32_t _1);
+double smaller(double _0, double _1);
+float larger(float _0, float _1);
+int32_t count(bool _0, bool _1);
+int32_t main();
+
+/* rustc_codegen_c: implementation */
+
+__attribute__((visibility("hidden"), noreturn)) void
+rust_begin_unwind(struct PanicInfo_0 *_0) {
+  abort();
+  abort();
+}
+
+int8_t order(int32_t _0, int32_t _1) {
+  return (_0 < _1) ? -1 : (int8_t) ((_0 != _1) ? 1 : 0);
+}
+
+double smaller(double _0, double _1) {
+  double _2;
+  (*(double*) (&_2)) = ((_0 < _1) || (_1 != _1)) ? _0 : _1;
+  return _2;
+}
+
+float larger(float _0, float _1) {
+  float _2;
+  (*(float*) (&_2)) = ((_0 > _1) || (_1 != _1)) ? _0 : _1;
+  return _2;
+}
+
+int32_t count(bool _0, bool _1) {
+  return (int32_t) (_0 ? 1 : 0) + (int32_t) ((!_1) ? 1 : 0);
+}
+
+int32_t main() {
+  int32_t _0;
+  int8_t _1;
+  int8_t _2;
+  int8_t _3;
+  (*(int8_t*) (&_1)) = order(1, 2);
+  if (__rust_utos(uint8_t, int8_t, (uint8_t) _1, INT8_MAX) == -1) {
+    (*(int8_t*) (&_2)) = order(2, 2);
+    if (__rust_utos(uint8_t, int8_t, (uint8_t) _2, INT8_MAX) == 0) {
+      (*(int8_t*) (&_3)) = order(3, 2);
+      if (__rust_utos(uint8_t, int8_t, (uint8_t) _3, INT8_MAX) == 1) {
+        if (smaller(__rust_nan, 0x1p+0) == 0x1p+0) {
+          if (smaller(0x1p+1, 0x1p+0) == 0x1p+0) {
+            if (larger(0x1p+0f, __rust_nanf) == 0x1p+0f) {
+              if (count(1, 0) == 2) {
+                if (count(0, 1) == 0) {
+                  (*(int32_t*) (&_0)) = 0;
+                  goto bb28;
+                }
+              }
+              (*(int32_t*) (&_0)) = 3;
+              goto bb28;
+            }
+          }
+        }
+        (*(int32_t*) (&_0)) = 2;
+        goto bb28;
+      }
+    }
+  }
+  (*(int32_t*) (&_0)) = 1;
+bb28:;
+  return _0;
+}
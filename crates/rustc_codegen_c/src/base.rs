@@ -1,12 +1,24 @@
+use std::fs;
+use std::hash::Hash;
 use std::sync::Arc;
 use std::time::Instant;
 
 use parking_lot::RwLock;
+use rustc_codegen_c_ast::cfg::simplify_cfg;
+use rustc_codegen_c_ast::const_fold::fold_consts;
+use rustc_codegen_c_ast::dump::dump_module;
+use rustc_codegen_c_ast::inline::inline_temps;
+use rustc_codegen_c_ast::pretty::{Printer, PrinterConfig};
+use rustc_codegen_c_ast::structure::structure_cfg;
+use rustc_codegen_c_ast::verify::verify_module;
 use rustc_codegen_c_ast::{ModuleArena, ModuleCtxt};
 use rustc_codegen_ssa::mono_item::MonoItemExt;
 use rustc_codegen_ssa::{ModuleCodegen, ModuleKind};
-use rustc_middle::dep_graph;
+use rustc_data_structures::base_n::{ToBaseN, CASE_INSENSITIVE};
+use rustc_data_structures::stable_hasher::{Hash64, StableHasher};
+use rustc_hir::def_id::LOCAL_CRATE;
 use rustc_middle::ty::TyCtxt;
+use rustc_middle::{bug, dep_graph};
 
 use crate::builder::Builder;
 use crate::context::CodegenCx;
@@ -26,7 +38,7 @@ pub fn compile_codegen_unit(
     let (module, _) = tcx.dep_graph.with_task(
         dep_node,
         tcx,
-        cgu_name,
+        (cgu_name, config.read().clone()),
         module_codegen,
         Some(dep_graph::hash_result),
     );
@@ -45,7 +57,10 @@ pub fn compile_codegen_unit(
     (module, cost)
 }
 
-fn module_codegen(tcx: TyCtxt<'_>, cgu_name: rustc_span::Symbol) -> ModuleCodegen<String> {
+fn module_codegen(
+    tcx: TyCtxt<'_>,
+    (cgu_name, config): (rustc_span::Symbol, BackendConfig),
+) -> ModuleCodegen<String> {
     let cgu = tcx.codegen_unit(cgu_name);
 
     let mcx = ModuleArena::new();
@@ -58,8 +73,9 @@ fn module_codegen(tcx: TyCtxt<'_>, cgu_name: rustc_span::Symbol) -> ModuleCodege
         "stdbool.h",
         "rust_runtime.h",
     ]);
+    mcx.module().set_global_prefix(global_prefix(tcx, cgu_name));
 
-    let cx = CodegenCx::new(tcx, mcx);
+    let cx = CodegenCx::new(tcx, mcx, config.source_locs);
 
     let mono_items = cgu.items_in_deterministic_order(tcx);
     for &(mono_item, data) in &mono_items {
@@ -71,6 +87,72 @@ fn module_codegen(tcx: TyCtxt<'_>, cgu_name: rustc_span::Symbol) -> ModuleCodege
         mono_item.define::<Builder<'_, '_, '_>>(&cx);
     }
 
-    let module = mcx.to_string();
+    fold_consts(mcx, cx.const_eval());
+    simplify_cfg(mcx);
+    inline_temps(mcx);
+    structure_cfg(mcx);
+
+    if config.verify {
+        if let Err(err) = verify_module(mcx) {
+            bug!("{}", err);
+        }
+    }
+
+    if let Some(dir) = &config.dump_ast {
+        let path = dir.join(format!("{cgu_name}.json"));
+        if let Err(err) =
+            fs::create_dir_all(dir).and_then(|()| fs::write(&path, dump_module(mcx.module())))
+        {
+            tcx.dcx().fatal(format!("failed to dump the AST to `{}`: {err}", path.display()));
+        }
+    }
+
+    let mut printer = Printer::with_config(PrinterConfig {
+        source_locs: config.source_locs,
+        c_std: config.c_std,
+        ..PrinterConfig::default()
+    });
+    printer.print_module(mcx.module());
+    let module = printer.finish();
     ModuleCodegen { name: cgu_name.to_string(), module_llvm: module, kind: ModuleKind::Regular }
 }
+
+/// The C header of the crate, declaring the externally visible functions and variables of all
+/// codegen units and defining the records they use.
+///
+/// The exported items are declared in a module of their own, so that each record is defined once
+/// and with the same name, whichever codegen units use it.
+pub fn codegen_header(tcx: TyCtxt<'_>, config: &BackendConfig) -> String {
+    let mcx = ModuleArena::new();
+    let mcx = ModuleCtxt(&mcx);
+    let cx = CodegenCx::new(tcx, mcx, config.source_locs);
+
+    cx.declare_exported_items();
+
+    let mut printer =
+        Printer::with_config(PrinterConfig { c_std: config.c_std, ..PrinterConfig::default() });
+    printer.print_header(mcx.module(), &header_guard(tcx.crate_name(LOCAL_CRATE).as_str()));
+    printer.finish()
+}
+
+/// The prefix of the global variables of a codegen unit, e.g. `abc_` in `_gabc_0`, so that the
+/// globals of different codegen units and crates never collide when they are linked together.
+///
+/// It is derived from the stable crate ID and the name of the codegen unit, so it is the same
+/// across compilation sessions.
+fn global_prefix(tcx: TyCtxt<'_>, cgu_name: rustc_span::Symbol) -> String {
+    let mut hasher = StableHasher::new();
+    tcx.stable_crate_id(LOCAL_CRATE).as_u64().hash(&mut hasher);
+    cgu_name.as_str().hash(&mut hasher);
+    let hash: Hash64 = hasher.finish();
+    format!("{}_", hash.as_u64().to_base_fixed_len(CASE_INSENSITIVE))
+}
+
+/// The include guard of the header of a crate, e.g. `FOO_H` for `foo`.
+fn header_guard(crate_name: &str) -> String {
+    let name: String = crate_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect();
+    format!("{name}_H")
+}
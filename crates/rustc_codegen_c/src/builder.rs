@@ -2,15 +2,17 @@
 
 use std::ops::Deref;
 
-use rustc_abi::{HasDataLayout, TargetDataLayout};
+use rustc_abi::{Abi, HasDataLayout, TargetDataLayout};
 use rustc_codegen_c_ast::expr::CValue;
 use rustc_codegen_c_ast::func::{CBasicBlock, CFunc};
-use rustc_codegen_c_ast::r#type::{CTy, CTyBase};
+use rustc_codegen_c_ast::r#type::{CPTy, CTy, CTyBase};
+use rustc_codegen_c_ast::stmt::{CStmt, SourceLoc};
 use rustc_codegen_ssa::common::{AtomicOrdering, IntPredicate, RealPredicate};
-use rustc_codegen_ssa::mir::operand::OperandRef;
-use rustc_codegen_ssa::mir::place::PlaceRef;
+use rustc_codegen_ssa::mir::operand::{OperandRef, OperandValue};
+use rustc_codegen_ssa::mir::place::{PlaceRef, PlaceValue};
 use rustc_codegen_ssa::traits::{
-    BackendTypes, BuilderMethods, HasCodegen, IntrinsicCallMethods, OverflowOp,
+    BackendTypes, BuilderMethods, ConstMethods, HasCodegen, IntrinsicCallMethods,
+    LayoutTypeMethods, OverflowOp,
 };
 use rustc_codegen_ssa::MemFlags;
 use rustc_middle::middle::codegen_fn_attrs::CodegenFnAttrs;
@@ -23,7 +25,7 @@ use rustc_target::abi::call::FnAbi;
 use rustc_target::spec::{HasTargetSpec, Target};
 use rustc_type_ir::{IntTy, UintTy};
 
-use crate::context::{BasicBlock, CodegenCx, Value};
+use crate::context::{BasicBlock, CodegenCx};
 
 mod abi;
 mod alloc;
@@ -38,6 +40,18 @@ pub struct Builder<'a, 'tcx, 'mx> {
     pub cx: &'a CodegenCx<'tcx, 'mx>,
     func: CFunc<'mx>,
     bb: &'mx CBasicBlock<'mx>,
+    /// The source location of the statements being built
+    loc: Option<SourceLoc>,
+}
+
+impl<'a, 'tcx, 'mx> Builder<'a, 'tcx, 'mx> {
+    /// Append a statement to the current block.
+    fn push_stmt(&self, stmt: CStmt<'mx>) {
+        if let Some(loc) = &self.loc {
+            self.mcx.set_loc(stmt, loc.clone());
+        }
+        self.bb.push_stmt(stmt);
+    }
 }
 
 impl<'a, 'tcx, 'mx> Deref for Builder<'a, 'tcx, 'mx> {
@@ -111,7 +125,7 @@ impl<'tcx, 'mx> FnAbiOfHelpers<'tcx> for Builder<'_, 'tcx, 'mx> {
 
 impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
     fn build(cx: &'a Self::CodegenCx, bb: Self::BasicBlock) -> Self {
-        Self { cx, func: bb.func, bb: bb.cbb }
+        Self { cx, func: bb.func, bb: bb.cbb, loc: None }
     }
 
     fn cx(&self) -> &Self::CodegenCx {
@@ -122,7 +136,9 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
         todo!()
     }
 
-    fn set_span(&mut self, _span: rustc_span::Span) {}
+    fn set_span(&mut self, span: rustc_span::Span) {
+        self.loc = self.cx.source_loc(span);
+    }
 
     fn append_block(cx: &'a Self::CodegenCx, func: Self::Function, name: &str) -> Self::BasicBlock {
         let cbb = func.0.new_bb(name, &cx.mcx);
@@ -143,24 +159,24 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
     }
 
     fn ret_void(&mut self) {
-        self.bb.push_stmt(self.cx.mcx.ret(None));
+        self.push_stmt(self.cx.mcx.ret(None));
     }
 
     fn ret(&mut self, mut v: Self::Value) {
         v = self.pointercast(v, self.func.fn_ptr().ret);
-        self.bb.push_stmt(self.cx.mcx.ret(Some(self.cx.mcx.value(v.cval()))))
+        self.push_stmt(self.cx.mcx.ret(Some(self.cx.mcx.value(v.cval()))))
     }
 
     fn br(&mut self, dest: Self::BasicBlock) {
         assert_eq!(self.func, dest.func, "br called on a block from a different function");
-        self.bb.push_stmt(self.cx.mcx.goto(dest.cbb.label));
+        self.push_stmt(self.cx.mcx.goto(dest.cbb.label));
     }
 
     fn cond_br(&mut self, cond: Self::Value, then_bb: Self::BasicBlock, else_bb: Self::BasicBlock) {
         assert_eq!(self.func, then_bb.func, "br called on a block from a different function");
         assert_eq!(self.func, else_bb.func, "br called on a block from a different function");
         let mcx = &self.cx.mcx;
-        self.bb.push_stmt(self.cx.mcx.if_stmt(
+        self.push_stmt(self.cx.mcx.if_stmt(
             mcx.value(cond.cval()),
             mcx.goto(then_bb.cbb.label),
             Some(mcx.goto(else_bb.cbb.label)),
@@ -173,7 +189,29 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
         else_llbb: Self::BasicBlock,
         cases: impl ExactSizeIterator<Item = (u128, Self::BasicBlock)>,
     ) {
-        todo!()
+        assert_eq!(self.func, else_llbb.func, "switch to a block from a different function");
+        let mcx = self.cx.mcx;
+        let ty = v.ty();
+
+        // 128-bit integers may be structs, so they are compared one case at a time
+        if ty.is_int128() {
+            for (val, bb) in cases {
+                let cond = self.icmp(IntPredicate::IntEQ, v, self.const_uint_big(ty, val));
+                let next = self.append_sibling_block("next_case");
+                self.cond_br(cond, bb, next);
+                self.switch_to_block(next);
+            }
+            self.br(else_llbb);
+            return;
+        }
+
+        let cases: Vec<_> = cases
+            .map(|(val, bb)| {
+                assert_eq!(self.func, bb.func, "switch to a block from a different function");
+                (mcx.value(self.const_uint_big(ty, val).cval()), bb.cbb.label)
+            })
+            .collect();
+        self.push_stmt(mcx.switch(mcx.value(v.cval()), cases, else_llbb.cbb.label));
     }
 
     fn invoke(
@@ -200,15 +238,15 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
     }
 
     fn fadd(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.binary_arith("+", lhs, rhs)
     }
 
     fn fadd_fast(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.fadd(lhs, rhs)
     }
 
     fn fadd_algebraic(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.fadd(lhs, rhs)
     }
 
     fn sub(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
@@ -216,15 +254,15 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
     }
 
     fn fsub(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.binary_arith("-", lhs, rhs)
     }
 
     fn fsub_fast(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.fsub(lhs, rhs)
     }
 
     fn fsub_algebraic(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.fsub(lhs, rhs)
     }
 
     fn mul(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
@@ -232,23 +270,23 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
     }
 
     fn fmul(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.binary_arith("*", lhs, rhs)
     }
 
     fn fmul_fast(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.fmul(lhs, rhs)
     }
 
     fn fmul_algebraic(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.fmul(lhs, rhs)
     }
 
     fn udiv(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.binary_arith("/", lhs, rhs)
     }
 
     fn exactudiv(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.udiv(lhs, rhs)
     }
 
     fn sdiv(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
@@ -256,51 +294,64 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
     }
 
     fn exactsdiv(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.sdiv(lhs, rhs)
     }
 
     fn fdiv(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.binary_arith("/", lhs, rhs)
     }
 
     fn fdiv_fast(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.fdiv(lhs, rhs)
     }
 
     fn fdiv_algebraic(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.fdiv(lhs, rhs)
     }
 
     fn urem(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.binary_arith("%", lhs, rhs)
     }
 
     fn srem(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.binary_arith("%", lhs, rhs)
     }
 
     fn frem(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        assert!(lhs.ty() == rhs.ty(), "cannot perform binary operation on different types");
+
+        let mcx = self.cx.mcx;
+        let ty = lhs.ty();
+        let fmod = match ty.base {
+            CTyBase::Primitive(CPTy::F32) => "__rust_fmodf",
+            CTyBase::Primitive(CPTy::F64) => "__rust_fmod",
+            _ => unreachable!("frem on non-float type {:?}", ty),
+        };
+        let ret = self.func.0.next_local_var();
+        let call = mcx.call(mcx.raw(fmod), [mcx.value(lhs.cval()), mcx.value(rhs.cval())]);
+        self.push_stmt(mcx.decl(mcx.var(ret, ty, Some(call))));
+
+        (ret, ty).into()
     }
 
     fn frem_fast(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.frem(lhs, rhs)
     }
 
     fn frem_algebraic(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.frem(lhs, rhs)
     }
 
     fn shl(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.shift("<<", lhs, rhs, false)
     }
 
     fn lshr(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.shift(">>", lhs, rhs, false)
     }
 
     fn ashr(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        self.shift(">>", lhs, rhs, true)
     }
 
     fn unchecked_sadd(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
@@ -340,15 +391,19 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
     }
 
     fn neg(&mut self, v: Self::Value) -> Self::Value {
-        todo!()
+        self.unary("-", v)
     }
 
     fn fneg(&mut self, v: Self::Value) -> Self::Value {
-        todo!()
+        self.unary("-", v)
     }
 
     fn not(&mut self, v: Self::Value) -> Self::Value {
-        todo!()
+        if v.ty() == self.cx.mcx.bool() {
+            self.unary("!", v)
+        } else {
+            self.unary("~", v)
+        }
     }
 
     // returns: (value: ty, overflowed: bool)
@@ -377,6 +432,8 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
                 TyKind::Uint(UintTy::U32) => "__rust_ckd_add_u32",
                 TyKind::Uint(UintTy::U64) => "__rust_ckd_add_u64",
                 TyKind::Uint(UintTy::Usize) => "__rust_ckd_add_uintptr",
+                TyKind::Int(IntTy::I128) => "__rust_ckd_add_i128",
+                TyKind::Uint(UintTy::U128) => "__rust_ckd_add_u128",
                 _ => todo!(),
             },
             OverflowOp::Sub => match ty.kind() {
@@ -390,6 +447,8 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
                 TyKind::Uint(UintTy::U32) => "__rust_ckd_sub_u32",
                 TyKind::Uint(UintTy::U64) => "__rust_ckd_sub_u64",
                 TyKind::Uint(UintTy::Usize) => "__rust_ckd_sub_uintptr",
+                TyKind::Int(IntTy::I128) => "__rust_ckd_sub_i128",
+                TyKind::Uint(UintTy::U128) => "__rust_ckd_sub_u128",
                 _ => todo!(),
             },
             OverflowOp::Mul => match ty.kind() {
@@ -403,12 +462,14 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
                 TyKind::Uint(UintTy::U32) => "__rust_ckd_mul_u32",
                 TyKind::Uint(UintTy::U64) => "__rust_ckd_mul_u64",
                 TyKind::Uint(UintTy::Usize) => "__rust_ckd_mul_uintptr",
+                TyKind::Int(IntTy::I128) => "__rust_ckd_mul_i128",
+                TyKind::Uint(UintTy::U128) => "__rust_ckd_mul_u128",
                 _ => todo!(),
             },
         };
 
-        self.bb.push_stmt(mcx.decl(mcx.var(ret, lhs.ty(), None)));
-        self.bb.push_stmt(mcx.decl(mcx.var(
+        self.push_stmt(mcx.decl(mcx.var(ret, lhs.ty(), None)));
+        self.push_stmt(mcx.decl(mcx.var(
             overflow,
             mcx.bool(),
             Some(mcx.call(
@@ -429,38 +490,20 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
     }
 
     fn alloca(&mut self, size: rustc_abi::Size, align: rustc_abi::Align) -> Self::Value {
-        self.alloc(size.bytes_usize())
+        self.alloc(size.bytes_usize(), align)
     }
 
     fn dynamic_alloca(&mut self, size: Self::Value, align: rustc_abi::Align) -> Self::Value {
         todo!()
     }
 
-    fn load(
-        &mut self,
-        ty: Self::Type,
-        ptr_or_lval: Self::Value,
-        align: rustc_abi::Align,
-    ) -> Self::Value {
-        match ptr_or_lval {
-            Value::LValue { .. } => ptr_or_lval,
-            Value::RValue { cval, .. } => {
-                let mcx = self.cx.mcx;
-
-                let ret = self.func.0.next_local_var();
-                self.bb.push_stmt(mcx.decl(mcx.var(
-                    ret,
-                    ty,
-                    Some(mcx.unary("*", mcx.value(cval))),
-                )));
-
-                (ret, ty).into()
-            }
-        }
+    fn load(&mut self, ty: Self::Type, ptr: Self::Value, align: rustc_abi::Align) -> Self::Value {
+        self.load_with_flags(ty, ptr, align, MemFlags::empty())
     }
 
     fn volatile_load(&mut self, ty: Self::Type, ptr: Self::Value) -> Self::Value {
-        todo!()
+        let align = self.cx.scalar_align(ty);
+        self.load_with_flags(ty, ptr, align, MemFlags::VOLATILE)
     }
 
     fn atomic_load(
@@ -479,7 +522,11 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
     ) -> OperandRef<'tcx, Self::Value> {
         if place.val.llextra.is_none() {
             let (ty, lval) = self.realize(place);
-            let val = self.load(ty, lval, place.layout.align.abi);
+            if let Abi::Aggregate { .. } = place.layout.abi {
+                // aggregates stay in memory and are copied with `typed_place_copy`
+                return OperandRef { val: OperandValue::Ref(place.val), layout: place.layout };
+            }
+            let val = self.load(ty, lval, place.val.align);
             OperandRef::from_immediate_or_packed_pair(self, val, place.layout)
         } else {
             todo!()
@@ -495,21 +542,25 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
         assert!(dest.val.llextra.is_none(), "TODO");
 
         let mcx = self.cx.mcx;
+        self.realize(dest);
+        let elem = elem.immediate();
+        let arr = self.func.0.next_local_var();
+        let arr_ty = mcx.ptr(elem.ty());
+        let arr_init = mcx.cast(arr_ty, self.ptr_expr(dest.val.llval));
+        self.push_stmt(mcx.decl(mcx.var(arr, arr_ty, Some(arr_init))));
         let loop_var = self.func.0.next_local_var();
-        let (_, dest) = self.realize(dest);
 
         let init = mcx.var(loop_var, mcx.uint(UintTy::Usize), Some(mcx.value(mcx.scalar(0))));
         let cond = mcx.binary(mcx.value(loop_var), mcx.value(mcx.scalar(count.into())), "<");
         let next = mcx.unary("++", mcx.value(loop_var));
 
-        self.bb.push_stmt(mcx.for_stmt(
+        self.push_stmt(mcx.for_stmt(
             mcx.decl(init),
             cond,
             mcx.expr(next),
-            mcx.expr(mcx.assign(
-                mcx.index(mcx.value(dest.cval()), mcx.value(loop_var)),
-                mcx.value(elem.immediate().cval()),
-            )),
+            mcx.expr(
+                mcx.assign(mcx.index(mcx.value(arr), mcx.value(loop_var)), mcx.value(elem.cval())),
+            ),
         ));
     }
 
@@ -527,7 +578,7 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
         ptr: Self::Value,
         align: rustc_abi::Align,
     ) -> Self::Value {
-        todo!()
+        self.store_with_flags(val, ptr, align, MemFlags::empty())
     }
 
     fn store_with_flags(
@@ -535,15 +586,10 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
         val: Self::Value,
         ptr_or_lval: Self::Value,
         align: rustc_abi::Align,
-        flags: MemFlags, // TODO: align & flags
+        flags: MemFlags,
     ) -> Self::Value {
         let mcx = self.cx.mcx;
-        let lval = match ptr_or_lval {
-            Value::LValue { cval } => mcx.value(cval),
-            Value::RValue { cval, .. } => mcx.unary("*", mcx.value(cval)),
-        };
-
-        self.bb.push_stmt(mcx.expr(mcx.assign(lval, mcx.value(val.cval()))));
+        self.store_with_align(val, ptr_or_lval, align, flags);
         (mcx.scalar(0), mcx.int(IntTy::I32)).into()
     }
 
@@ -571,13 +617,9 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
         let mcx = self.cx.mcx;
         let ptr_ty = mcx.ptr(ty);
         let arr = self.func.0.next_local_var();
-        self.bb.push_stmt(mcx.decl(mcx.var(
-            arr,
-            ptr_ty,
-            Some(mcx.cast(ptr_ty, mcx.value(ptr.cval()))),
-        )));
+        self.push_stmt(mcx.decl(mcx.var(arr, ptr_ty, Some(mcx.cast(ptr_ty, self.ptr_expr(ptr))))));
         let ret = self.func.0.next_local_var();
-        self.bb.push_stmt(mcx.decl(mcx.var(
+        self.push_stmt(mcx.decl(mcx.var(
             ret,
             ptr_ty,
             Some(mcx.unary("&", mcx.index(mcx.value(arr), mcx.value(indices[0].cval())))),
@@ -586,47 +628,48 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
     }
 
     fn trunc(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value {
-        todo!()
+        self.intcast(val, dest_ty, false)
     }
 
     fn sext(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value {
-        todo!()
+        self.intcast(val, dest_ty, true)
     }
 
     fn fptoui_sat(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value {
-        todo!()
+        self.float_to_int_sat(val, dest_ty, false)
     }
 
     fn fptosi_sat(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value {
-        todo!()
+        self.float_to_int_sat(val, dest_ty, true)
     }
 
     fn fptoui(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value {
-        todo!()
+        self.numeric_cast(val, dest_ty)
     }
 
     fn fptosi(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value {
-        todo!()
+        self.numeric_cast(val, dest_ty)
     }
 
     fn uitofp(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value {
-        todo!()
+        self.numeric_cast(val, dest_ty)
     }
 
     fn sitofp(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value {
-        todo!()
+        self.numeric_cast(val, dest_ty)
     }
 
     fn fptrunc(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value {
-        todo!()
+        self.numeric_cast(val, dest_ty)
     }
 
     fn fpext(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value {
-        todo!()
+        self.numeric_cast(val, dest_ty)
     }
 
     fn ptrtoint(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value {
-        self.intcast(val, dest_ty, val.ty().is_signed())
+        let val = self.rvalue(val);
+        self.numeric_cast(val, dest_ty)
     }
 
     fn inttoptr(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value {
@@ -652,9 +695,14 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
     /// result to a signed integer.
     fn intcast(&mut self, val: Self::Value, dest_ty: Self::Type, is_signed: bool) -> Self::Value {
         let mcx = self.cx.mcx;
-        let ret = self.func.0.next_local_var();
 
         let dest = if let CTyBase::Primitive(ty) = dest_ty.base { ty } else { unreachable!() };
+        if val.ty() == mcx.bool() && dest_ty != mcx.bool() {
+            return self.bool_to_int(val, dest_ty);
+        }
+        if dest.is_int128() || val.ty().is_int128() {
+            return self.intcast128(val, dest, is_signed);
+        }
 
         let cast = if dest.is_signed() {
             let cast = mcx.cast(CTy::primitive(dest.to_unsigned()), mcx.value(val.cval()));
@@ -670,19 +718,19 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
         } else {
             mcx.cast(CTy::primitive(dest), mcx.value(val.cval()))
         };
-        self.bb.push_stmt(mcx.decl(mcx.var(ret, dest_ty, Some(cast))));
-        (ret, dest_ty).into()
+        self.define(dest_ty, cast)
     }
 
     fn pointercast(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value {
-        if matches!(val, Value::LValue { .. }) || val.ty() == dest_ty {
+        let val = self.rvalue(val);
+        if val.ty() == dest_ty {
             return val;
         }
 
         let mcx = self.cx.mcx;
         let ret = self.func.0.next_local_var();
 
-        self.bb.push_stmt(mcx.decl(mcx.var(
+        self.push_stmt(mcx.decl(mcx.var(
             ret,
             dest_ty,
             Some(mcx.cast(dest_ty, mcx.value(val.cval()))),
@@ -708,7 +756,44 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
     }
 
     fn fcmp(&mut self, op: RealPredicate, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
-        todo!()
+        assert!(lhs.ty() == rhs.ty(), "cannot perform binary operation on different types");
+
+        let mcx = self.cx.mcx;
+        let (a, b) = (mcx.value(lhs.cval()), mcx.value(rhs.cval()));
+
+        // C comparisons are false if either operand is NaN, i.e. they are ordered, except `!=`.
+        // Unordered comparisons are the negation of the inverse ordered comparison.
+        let cmp = match op {
+            RealPredicate::RealPredicateFalse => mcx.value(mcx.scalar(0)),
+            RealPredicate::RealOEQ => mcx.binary(a, b, "=="),
+            RealPredicate::RealOGT => mcx.binary(a, b, ">"),
+            RealPredicate::RealOGE => mcx.binary(a, b, ">="),
+            RealPredicate::RealOLT => mcx.binary(a, b, "<"),
+            RealPredicate::RealOLE => mcx.binary(a, b, "<="),
+            RealPredicate::RealONE => {
+                mcx.binary(mcx.binary(a, b, "<"), mcx.binary(a, b, ">"), "||")
+            }
+            RealPredicate::RealORD => {
+                mcx.binary(mcx.binary(a, a, "=="), mcx.binary(b, b, "=="), "&&")
+            }
+            RealPredicate::RealUNO => {
+                mcx.binary(mcx.binary(a, a, "!="), mcx.binary(b, b, "!="), "||")
+            }
+            RealPredicate::RealUEQ => {
+                mcx.unary("!", mcx.binary(mcx.binary(a, b, "<"), mcx.binary(a, b, ">"), "||"))
+            }
+            RealPredicate::RealUGT => mcx.unary("!", mcx.binary(a, b, "<=")),
+            RealPredicate::RealUGE => mcx.unary("!", mcx.binary(a, b, "<")),
+            RealPredicate::RealULT => mcx.unary("!", mcx.binary(a, b, ">=")),
+            RealPredicate::RealULE => mcx.unary("!", mcx.binary(a, b, ">")),
+            RealPredicate::RealUNE => mcx.binary(a, b, "!="),
+            RealPredicate::RealPredicateTrue => mcx.value(mcx.scalar(1)),
+        };
+
+        let ty = mcx.bool();
+        let ret = self.func.0.next_local_var();
+        self.push_stmt(mcx.decl(mcx.var(ret, ty, Some(cmp))));
+        (ret, ty).into()
     }
 
     fn memcpy(
@@ -720,7 +805,9 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
         size: Self::Value,
         flags: rustc_codegen_ssa::MemFlags,
     ) {
-        todo!()
+        let size = self.cx.mcx.value(size.cval());
+        let src = self.ptr_expr(src);
+        self.mem_call("__rust_memcpy", dst, src, size);
     }
 
     fn memmove(
@@ -732,7 +819,9 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
         size: Self::Value,
         flags: rustc_codegen_ssa::MemFlags,
     ) {
-        todo!()
+        let size = self.cx.mcx.value(size.cval());
+        let src = self.ptr_expr(src);
+        self.mem_call("__rust_memmove", dst, src, size);
     }
 
     fn memset(
@@ -743,7 +832,32 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
         align: rustc_abi::Align,
         flags: rustc_codegen_ssa::MemFlags,
     ) {
-        todo!()
+        let mcx = self.cx.mcx;
+        self.mem_call("__rust_memset", ptr, mcx.value(fill_byte.cval()), mcx.value(size.cval()));
+    }
+
+    fn typed_place_copy_with_flags(
+        &mut self,
+        dst: PlaceValue<Self::Value>,
+        src: PlaceValue<Self::Value>,
+        layout: TyAndLayout<'tcx>,
+        flags: MemFlags,
+    ) {
+        if layout.is_zst() {
+            return;
+        }
+        if self.cx.is_backend_immediate(layout) {
+            let (ty, src_val) = self.realize(src.with_type(layout));
+            let val = self.load_with_flags(ty, src_val, src.align, MemFlags::empty());
+            let (_, dst_val) = self.realize(dst.with_type(layout));
+            self.store_with_align(val, dst_val, dst.align, flags);
+        } else {
+            // copy with `sizeof`, which is checked against the rustc layout in the module
+            let (ty, dst) = self.realize(dst.with_type(layout));
+            let (_, src) = self.realize(src.with_type(layout));
+            let src = self.ptr_expr(src);
+            self.mem_call("__rust_memcpy", dst, src, self.cx.mcx.size_of(ty));
+        }
     }
 
     fn select(
@@ -752,7 +866,7 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
         then_val: Self::Value,
         else_val: Self::Value,
     ) -> Self::Value {
-        todo!()
+        self.ternary(cond, then_val, else_val)
     }
 
     fn va_arg(&mut self, list: Self::Value, ty: Self::Type) -> Self::Value {
@@ -896,11 +1010,11 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
         let call = mcx.call(mcx.value(llfn.cval()), args);
 
         let ret = if fn_ptr.ret.is_void() {
-            self.bb.push_stmt(mcx.expr(call));
+            self.push_stmt(mcx.expr(call));
             CValue::Null
         } else {
             let ret = self.func.0.next_local_var();
-            self.bb.push_stmt(mcx.decl(mcx.var(ret, fn_ptr.ret, Some(call))));
+            self.push_stmt(mcx.decl(mcx.var(ret, fn_ptr.ret, Some(call))));
             ret
         };
 
@@ -908,7 +1022,7 @@ impl<'a, 'tcx, 'mx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx, 'mx> {
     }
 
     fn zext(&mut self, val: Self::Value, dest_ty: Self::Type) -> Self::Value {
-        todo!()
+        self.intcast(val, dest_ty, false)
     }
 
     fn apply_attrs_to_cleanup_callsite(&mut self, llret: Self::Value) {
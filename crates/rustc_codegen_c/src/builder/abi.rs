@@ -1,7 +1,8 @@
+use rustc_codegen_ssa::mir::operand::OperandValue;
 use rustc_codegen_ssa::mir::place::PlaceRef;
-use rustc_codegen_ssa::traits::{AbiBuilderMethods, ArgAbiMethods};
+use rustc_codegen_ssa::traits::{AbiBuilderMethods, ArgAbiMethods, LayoutTypeMethods};
 use rustc_middle::ty::Ty;
-use rustc_target::abi::call::ArgAbi;
+use rustc_target::abi::call::{ArgAbi, PassMode};
 
 use crate::builder::Builder;
 
@@ -20,7 +21,23 @@ impl<'tcx, 'mx> ArgAbiMethods<'tcx> for Builder<'_, 'tcx, 'mx> {
         idx: &mut usize,
         dst: PlaceRef<'tcx, Self::Value>,
     ) {
-        todo!()
+        let mut next = || {
+            let val = self.get_param(*idx);
+            *idx += 1;
+            val
+        };
+        match arg_abi.mode {
+            PassMode::Ignore => {}
+            PassMode::Direct(_) => {
+                let val = next();
+                self.store_arg(arg_abi, val, dst);
+            }
+            PassMode::Pair(..) => {
+                let (a, b) = (next(), next());
+                OperandValue::Pair(a, b).store(self, dst);
+            }
+            PassMode::Cast { .. } | PassMode::Indirect { .. } => todo!(),
+        }
     }
 
     fn store_arg(
@@ -29,10 +46,14 @@ impl<'tcx, 'mx> ArgAbiMethods<'tcx> for Builder<'_, 'tcx, 'mx> {
         val: Self::Value,
         dst: PlaceRef<'tcx, Self::Value>,
     ) {
-        todo!()
+        match arg_abi.mode {
+            PassMode::Ignore => {}
+            PassMode::Direct(_) => OperandValue::Immediate(val).store(self, dst),
+            PassMode::Pair(..) | PassMode::Cast { .. } | PassMode::Indirect { .. } => todo!(),
+        }
     }
 
     fn arg_memory_ty(&self, arg_abi: &ArgAbi<'tcx, Ty<'tcx>>) -> Self::Type {
-        todo!()
+        self.cx.backend_type(arg_abi.layout)
     }
 }
@@ -1,12 +1,15 @@
-use rustc_codegen_c_ast::r#type::CTy;
+use rustc_abi::Align;
+use rustc_codegen_c_ast::expr::CExpr;
+use rustc_codegen_c_ast::r#type::{CTy, CTyBase, CTyKind};
 use rustc_codegen_ssa::mir::place::PlaceRef;
 use rustc_codegen_ssa::traits::LayoutTypeMethods;
+use rustc_codegen_ssa::MemFlags;
 
 use crate::builder::Builder;
 use crate::context::Value;
 
 impl<'a, 'tcx, 'mx> Builder<'a, 'tcx, 'mx> {
-    pub fn alloc(&mut self, size: usize) -> Value<'mx> {
+    pub fn alloc(&mut self, size: usize, align: Align) -> Value<'mx> {
         let mcx = self.cx.mcx;
 
         /* Pending allocation
@@ -14,21 +17,139 @@ impl<'a, 'tcx, 'mx> Builder<'a, 'tcx, 'mx> {
            placeholder. But if we can know the type later (e.g. if we get a `PlaceRef` of
            the allocation), we can use that type instead.
         */
-        let alloc =
-            self.func.0.new_pending_alloc(mcx.arr(mcx.char(), Some(size.try_into().unwrap())));
+        let fallback = mcx.arr(mcx.char(), Some(size.try_into().unwrap()));
+        let alloc = self.func.0.new_pending_alloc(fallback, align.bytes());
 
         Value::LValue { cval: alloc }
     }
 
     /// Realize an allocation.
-    pub fn realize(&self, place: PlaceRef<'tcx, Value<'mx>>) -> (CTy<'mx>, Value<'mx>) {
+    ///
+    /// The allocation is only realized if the place covers the whole allocation, e.g. not
+    /// for the tag of an enum.
+    pub fn realize(&mut self, place: PlaceRef<'tcx, Value<'mx>>) -> (CTy<'mx>, Value<'mx>) {
         let ty = self.cx.backend_type(place.layout);
-        match place.val.llval {
-            Value::LValue { cval } => self.func.0.realize_alloc(cval, ty),
-            Value::RValue { cval, ty: alloc_ty } => {
-                assert_eq!(self.mcx.ptr(ty), alloc_ty)
+        if let Value::LValue { cval } = place.val.llval {
+            let alloc = self.func.0.pending_alloc(cval).expect("lvalue is not an allocation");
+            let CTyBase::Ref(fallback) = alloc.fallback.base else { unreachable!() };
+            let CTyKind::Array(_, Some(size)) = fallback.0 else { unreachable!() };
+            if alloc.ty.is_none() && size.get() as u64 == place.layout.size.bytes() {
+                self.func.0.realize_alloc(cval, ty, place.layout.align.abi.bytes());
             }
         }
         (ty, place.val.llval)
     }
+
+    /// The address of a value in memory.
+    ///
+    /// For an allocation `x`, this is `&x`; for a pointer, this is the pointer itself.
+    pub fn ptr_expr(&self, ptr: Value<'mx>) -> CExpr<'mx> {
+        let mcx = self.mcx;
+        match ptr {
+            Value::LValue { cval } => mcx.unary("&", mcx.value(cval)),
+            Value::RValue { cval, .. } => mcx.value(cval),
+        }
+    }
+
+    /// An lvalue expression accessing a value of type `ty` in memory.
+    ///
+    /// If the memory has a different type, the access is done through a casted pointer, e.g.
+    /// `*(int32_t*)&x`.
+    pub fn deref_expr(&self, ptr: Value<'mx>, ty: CTy<'mx>) -> CExpr<'mx> {
+        let mcx = self.mcx;
+        let ptr_ty = match ptr {
+            Value::LValue { cval } => {
+                let alloc = self.func.0.pending_alloc(cval).expect("lvalue is not an allocation");
+                if alloc.ty() == ty {
+                    return mcx.value(cval);
+                }
+                None
+            }
+            Value::RValue { ty: ptr_ty, .. } => Some(ptr_ty),
+        };
+        let ptr_expr = self.ptr_expr(ptr);
+        if ptr_ty == Some(mcx.ptr(ty)) {
+            mcx.unary("*", ptr_expr)
+        } else {
+            mcx.unary("*", mcx.cast(mcx.ptr(ty), ptr_expr))
+        }
+    }
+
+    /// Load a value of type `ty` from memory aligned to `align`.
+    ///
+    /// Loads with less than the alignment of `ty` are done with `memcpy`, and volatile loads
+    /// through a `volatile` pointer.
+    pub fn load_with_flags(
+        &mut self,
+        ty: CTy<'mx>,
+        ptr: Value<'mx>,
+        align: Align,
+        flags: MemFlags,
+    ) -> Value<'mx> {
+        let mcx = self.mcx;
+        let ret = self.func.0.next_local_var();
+        if self.is_underaligned(ty, align, flags) {
+            self.push_stmt(mcx.decl(mcx.var(ret, ty, None)));
+            let dst = mcx.unary("&", mcx.value(ret));
+            let args = [dst, self.ptr_expr(ptr), mcx.size_of(ty)];
+            self.push_stmt(mcx.expr(mcx.call(mcx.raw("__rust_memcpy"), args)));
+        } else {
+            let src = self.deref_expr(ptr, ty.to_volatile_if(flags.contains(MemFlags::VOLATILE)));
+            self.push_stmt(mcx.decl(mcx.var(ret, ty, Some(src))));
+        }
+        (ret, ty).into()
+    }
+
+    /// Store `val` to memory aligned to `align`, like [`Builder::load_with_flags`].
+    pub fn store_with_align(
+        &mut self,
+        val: Value<'mx>,
+        ptr: Value<'mx>,
+        align: Align,
+        flags: MemFlags,
+    ) {
+        let mcx = self.mcx;
+        let val = self.rvalue(val);
+        let ty = val.ty();
+        if self.is_underaligned(ty, align, flags) {
+            // the value may be a constant, which has no address
+            let temp = self.func.0.next_local_var();
+            self.push_stmt(mcx.decl(mcx.var(temp, ty, Some(mcx.value(val.cval())))));
+            self.mem_call("__rust_memcpy", ptr, mcx.unary("&", mcx.value(temp)), mcx.size_of(ty));
+        } else {
+            let dst = self.deref_expr(ptr, ty.to_volatile_if(flags.contains(MemFlags::VOLATILE)));
+            self.push_stmt(mcx.expr(mcx.assign(dst, mcx.value(val.cval()))));
+        }
+    }
+
+    /// Whether an access of a scalar of type `ty` with alignment `align` cannot be done through
+    /// a pointer to `ty`.
+    fn is_underaligned(&self, ty: CTy<'mx>, align: Align, flags: MemFlags) -> bool {
+        let ty_align = self.cx.scalar_align(ty);
+        ty_align > Align::ONE && (flags.contains(MemFlags::UNALIGNED) || align < ty_align)
+    }
+
+    /// Call `memcpy`, `memmove` or `memset` of `rust_runtime.h` on the memory at `dst`.
+    pub fn mem_call(
+        &mut self,
+        func: &'static str,
+        dst: Value<'mx>,
+        arg: CExpr<'mx>,
+        size: CExpr<'mx>,
+    ) {
+        let mcx = self.mcx;
+        let dst = self.ptr_expr(dst);
+        self.push_stmt(mcx.expr(mcx.call(mcx.raw(func), [dst, arg, size])));
+    }
+
+    /// Convert an allocation to a `void*` pointer value. Other values are returned as is.
+    pub fn rvalue(&mut self, val: Value<'mx>) -> Value<'mx> {
+        let Value::LValue { .. } = val else { return val };
+
+        let mcx = self.mcx;
+        let ty = mcx.ptr(mcx.void());
+        let ret = self.func.0.next_local_var();
+        self.push_stmt(mcx.decl(mcx.var(ret, ty, Some(self.ptr_expr(val)))));
+        (ret, ty).into()
+    }
 }
@@ -15,18 +15,26 @@ impl DebugInfoBuilderMethods for Builder<'_, '_, '_> {
         // if this is a fragment of a composite `DIVariable`.
         fragment: Option<std::ops::Range<rustc_abi::Size>>,
     ) {
-        todo!()
+        // only a variable held by a whole local names it
+        if direct_offset == rustc_abi::Size::ZERO
+            && indirect_offsets.is_empty()
+            && fragment.is_none()
+        {
+            self.func.0.set_local_name(variable_alloca.cval(), dbg_var.as_str());
+        }
     }
 
-    fn set_dbg_loc(&mut self, dbg_loc: Self::DILocation) {
-        todo!()
-    }
+    /// Source locations are taken from the span of the builder instead.
+    fn set_dbg_loc(&mut self, dbg_loc: Self::DILocation) {}
 
     fn insert_reference_to_gdb_debug_scripts_section_global(&mut self) {
         todo!()
     }
 
+    /// Name the local variable of a value after the Rust variable it holds. This is only called
+    /// if rustc is not asked for fewer names, which it is by default, so variables other than
+    /// the arguments are mostly named through [`Self::dbg_var_addr`] with full debug info.
     fn set_var_name(&mut self, value: Self::Value, name: &str) {
-        todo!()
+        self.func.0.set_local_name(value.cval(), name);
     }
 }
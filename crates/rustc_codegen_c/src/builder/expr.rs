@@ -1,15 +1,38 @@
+use rustc_codegen_c_ast::expr::CExpr;
+use rustc_codegen_c_ast::r#type::{CPTy, CTy, CTyBase};
+use rustc_codegen_ssa::traits::{BaseTypeMethods, BuilderMethods, ConstMethods};
+
 use crate::builder::Builder;
 use crate::context::Value;
 
 impl<'a, 'tcx, 'mx> Builder<'a, 'tcx, 'mx> {
-    pub fn unary(&mut self, op: &'static str, expr: Value<'mx>) -> Value<'mx> {
+    /// Define a new local of type `ty` initialized with `expr`, or fold `expr` to a constant if
+    /// its operands are constants.
+    pub fn define(&mut self, ty: CTy<'mx>, expr: CExpr<'mx>) -> Value<'mx> {
+        if let Some(value) = self.const_eval().eval(expr, ty) {
+            return (value, ty).into();
+        }
+
         let mcx = self.mcx;
-        let ty = expr.ty();
         let ret = self.func.0.next_local_var();
+        self.push_stmt(mcx.decl(mcx.var(ret, ty, Some(expr))));
+        (ret, ty).into()
+    }
 
-        self.bb.push_stmt(mcx.decl(mcx.var(ret, ty, Some(mcx.unary(op, mcx.value(expr.cval()))))));
+    pub fn unary(&mut self, op: &'static str, expr: Value<'mx>) -> Value<'mx> {
+        let mcx = self.mcx;
+        let ty = expr.ty();
 
-        (ret, ty).into()
+        let name = match op {
+            "-" => "neg",
+            "~" => "not",
+            _ => op,
+        };
+        let expr = match int128_helper(name, ty) {
+            Some(helper) => mcx.call(mcx.raw(helper), [mcx.value(expr.cval())]),
+            None => mcx.unary(op, mcx.value(expr.cval())),
+        };
+        self.define(ty, expr)
     }
 
     pub fn binary_arith(
@@ -22,15 +45,13 @@ impl<'a, 'tcx, 'mx> Builder<'a, 'tcx, 'mx> {
 
         let mcx = self.mcx;
         let ty = lhs.ty();
-        let ret = self.func.0.next_local_var();
-
-        self.bb.push_stmt(mcx.decl(mcx.var(
-            ret,
-            ty,
-            Some(mcx.binary(mcx.value(lhs.cval()), mcx.value(rhs.cval()), op)),
-        )));
 
-        (ret, ty).into()
+        let (lhs, rhs) = (mcx.value(lhs.cval()), mcx.value(rhs.cval()));
+        let expr = match int128_helper(op, ty) {
+            Some(helper) => mcx.call(mcx.raw(helper), [lhs, rhs]),
+            None => mcx.binary(lhs, rhs, op),
+        };
+        self.define(ty, expr)
     }
 
     pub fn binary_cmp(&mut self, op: &'static str, lhs: Value<'mx>, rhs: Value<'mx>) -> Value<'mx> {
@@ -38,14 +59,238 @@ impl<'a, 'tcx, 'mx> Builder<'a, 'tcx, 'mx> {
 
         let mcx = self.mcx;
         let ty = mcx.bool();
+
+        let (lhs, rhs) = match int128_helper("cmp", lhs.ty()) {
+            // `x op y` becomes `__rust_cmp_u128(x, y) op 0`
+            Some(cmp) => (
+                mcx.call(mcx.raw(cmp), [mcx.value(lhs.cval()), mcx.value(rhs.cval())]),
+                mcx.value(mcx.scalar(0)),
+            ),
+            None => (mcx.value(lhs.cval()), mcx.value(rhs.cval())),
+        };
+        self.define(ty, mcx.binary(lhs, rhs, op))
+    }
+
+    /// Shift with the signedness given by the operation rather than the operand type, e.g.
+    /// a logical right shift of a signed integer is done on its unsigned counterpart.
+    pub fn shift(
+        &mut self,
+        op: &'static str,
+        lhs: Value<'mx>,
+        rhs: Value<'mx>,
+        signed: bool,
+    ) -> Value<'mx> {
+        let ty = lhs.ty();
+        let CTyBase::Primitive(prim) = ty.base else { unreachable!("shift on {:?}", ty) };
+        let op_ty = if prim.is_signed() == signed {
+            ty
+        } else {
+            CTy::primitive(if signed { signed_of(prim) } else { prim.to_unsigned() })
+        };
+
+        // `codegen_ssa` resizes the shift amount to the width of `lhs` but keeps its signedness
+        let lhs = self.cast_to(lhs, op_ty);
+        let rhs = self.cast_to(rhs, op_ty);
+        let ret = self.binary_arith(op, lhs, rhs);
+        self.cast_to(ret, ty)
+    }
+
+    /// Integer cast of `val` to `ty` that extends according to the signedness of `val`, or `val`
+    /// itself if it already has type `ty`.
+    fn cast_to(&mut self, val: Value<'mx>, ty: CTy<'mx>) -> Value<'mx> {
+        if val.ty() == ty {
+            return val;
+        }
+        let CTyBase::Primitive(prim) = val.ty().base else { unreachable!("cast of {:?}", val) };
+        self.intcast(val, ty, prim.is_signed())
+    }
+
+    /// Conversion between arithmetic types with C semantics, e.g. `(double) x`.
+    pub fn numeric_cast(&mut self, val: Value<'mx>, dest_ty: CTy<'mx>) -> Value<'mx> {
+        let mcx = self.mcx;
+        self.define(dest_ty, mcx.cast(dest_ty, mcx.value(val.cval())))
+    }
+
+    /// `cond ? then_val : else_val`, a branch-free `select`.
+    pub fn ternary(
+        &mut self,
+        cond: Value<'mx>,
+        then_val: Value<'mx>,
+        else_val: Value<'mx>,
+    ) -> Value<'mx> {
+        assert!(then_val.ty() == else_val.ty(), "cannot select between different types");
+
+        let mcx = self.mcx;
+        let ty = then_val.ty();
+
+        let expr = mcx.ternary(
+            mcx.value(cond.cval()),
+            mcx.value(then_val.cval()),
+            mcx.value(else_val.cval()),
+        );
+        self.define(ty, expr)
+    }
+
+    /// Convert a `bool` to the integer `1` or `0`.
+    pub fn bool_to_int(&mut self, val: Value<'mx>, dest_ty: CTy<'mx>) -> Value<'mx> {
+        let one = self.const_uint(dest_ty, 1);
+        let zero = self.const_uint(dest_ty, 0);
+        self.ternary(val, one, zero)
+    }
+
+    /// The `minnum`/`maxnum` of IEEE 754-2008 with `op` being `<` or `>`: the lesser or greater
+    /// operand, where a NaN operand is ignored unless both are NaN.
+    pub fn float_min_max(
+        &mut self,
+        op: &'static str,
+        lhs: Value<'mx>,
+        rhs: Value<'mx>,
+    ) -> Value<'mx> {
+        let mcx = self.mcx;
+        let (a, b) = (mcx.value(lhs.cval()), mcx.value(rhs.cval()));
+
+        // `a < b || b != b ? a : b`
+        let cond = mcx.binary(mcx.binary(a, b, op), mcx.binary(b, b, "!="), "||");
+        let ty = mcx.bool();
         let ret = self.func.0.next_local_var();
+        self.push_stmt(mcx.decl(mcx.var(ret, ty, Some(cond))));
 
-        self.bb.push_stmt(mcx.decl(mcx.var(
-            ret,
-            ty,
-            Some(mcx.binary(mcx.value(lhs.cval()), mcx.value(rhs.cval()), op)),
-        )));
+        self.ternary((ret, ty).into(), lhs, rhs)
+    }
 
-        (ret, ty).into()
+    /// Saturating float-to-integer conversion of `as` casts: NaN becomes zero and values out of
+    /// range are clamped to the bounds of the integer type, which avoids the undefined behavior
+    /// of a plain C cast.
+    pub fn float_to_int_sat(
+        &mut self,
+        val: Value<'mx>,
+        dest_ty: CTy<'mx>,
+        signed: bool,
+    ) -> Value<'mx> {
+        let mcx = self.mcx;
+        let float_ty = val.ty();
+        let bits = self.int_width(dest_ty) as u32;
+
+        // values at or beyond these bounds saturate, and being powers of two (or -1), they are
+        // exact in every floating-point type
+        let (lo, hi) = if signed {
+            (-(2f64.powi(bits as i32 - 1)), 2f64.powi(bits as i32 - 1))
+        } else {
+            (-1.0, 2f64.powi(bits as i32))
+        };
+        let (min, max) = if signed {
+            (1 << (bits - 1), u128::MAX >> (129 - bits))
+        } else {
+            (0, u128::MAX >> (128 - bits))
+        };
+
+        let x = mcx.value(val.cval());
+        let [lo, hi] = [lo, hi].map(|bound| mcx.value(self.const_real(float_ty, bound).cval()));
+        let [zero, min, max] =
+            [0, min, max].map(|int| mcx.value(self.const_uint_big(dest_ty, int).cval()));
+
+        // `x != x ? 0 : x >= hi ? MAX : x <= lo ? MIN : (T) x`
+        let expr = mcx.ternary(
+            mcx.binary(x, x, "!="),
+            zero,
+            mcx.ternary(
+                mcx.binary(x, hi, ">="),
+                max,
+                mcx.ternary(mcx.binary(x, lo, "<="), min, mcx.cast(dest_ty, x)),
+            ),
+        );
+        let ret = self.func.0.next_local_var();
+        self.push_stmt(mcx.decl(mcx.var(ret, dest_ty, Some(expr))));
+
+        (ret, dest_ty).into()
     }
+
+    /// Integer casts from or to 128-bit integers, which go through `__rust_u128` and the
+    /// conversion helpers of `rust_runtime.h`.
+    pub fn intcast128(&mut self, val: Value<'mx>, dest: CPTy, is_signed: bool) -> Value<'mx> {
+        let mcx = self.mcx;
+        let u128_ty = CTy::primitive(CPTy::U128);
+
+        let wide = match val.ty().base {
+            CTyBase::Primitive(CPTy::U128) => mcx.value(val.cval()),
+            CTyBase::Primitive(CPTy::I128) => {
+                mcx.call(mcx.raw("__rust_u128_from_i128"), [mcx.value(val.cval())])
+            }
+            _ => {
+                let (word, from) = if is_signed {
+                    (CPTy::I64, "__rust_u128_from_i64")
+                } else {
+                    (CPTy::U64, "__rust_u128_from_u64")
+                };
+                let word = self.intcast(val, CTy::primitive(word), is_signed);
+                mcx.call(mcx.raw(from), [mcx.value(word.cval())])
+            }
+        };
+
+        let (ty, expr): (_, CExpr<'mx>) = match dest {
+            CPTy::U128 => (u128_ty, wide),
+            CPTy::I128 => {
+                (CTy::primitive(dest), mcx.call(mcx.raw("__rust_i128_from_u128"), [wide]))
+            }
+            _ => (CTy::primitive(CPTy::U64), mcx.call(mcx.raw("__rust_u64_from_u128"), [wide])),
+        };
+        let ret = self.func.0.next_local_var();
+        self.push_stmt(mcx.decl(mcx.var(ret, ty, Some(expr))));
+
+        let ret = (ret, ty).into();
+        if dest.is_int128() {
+            ret
+        } else {
+            self.intcast(ret, CTy::primitive(dest), false)
+        }
+    }
+}
+
+/// The signed counterpart of an unsigned integer type.
+fn signed_of(ty: CPTy) -> CPTy {
+    match ty {
+        CPTy::Usize => CPTy::Isize,
+        CPTy::U8 => CPTy::I8,
+        CPTy::U16 => CPTy::I16,
+        CPTy::U32 => CPTy::I32,
+        CPTy::U64 => CPTy::I64,
+        CPTy::U128 => CPTy::I128,
+        _ => unreachable!(),
+    }
+}
+
+/// The `rust_runtime.h` helper implementing an operator on 128-bit integers, if `ty` is one.
+fn int128_helper(op: &str, ty: CTy) -> Option<&'static str> {
+    let CTyBase::Primitive(ty) = ty.base else { return None };
+    let helper = match (op, ty) {
+        ("+", CPTy::I128) => "__rust_add_i128",
+        ("+", CPTy::U128) => "__rust_add_u128",
+        ("-", CPTy::I128) => "__rust_sub_i128",
+        ("-", CPTy::U128) => "__rust_sub_u128",
+        ("*", CPTy::I128) => "__rust_mul_i128",
+        ("*", CPTy::U128) => "__rust_mul_u128",
+        ("/", CPTy::I128) => "__rust_div_i128",
+        ("/", CPTy::U128) => "__rust_div_u128",
+        ("%", CPTy::I128) => "__rust_rem_i128",
+        ("%", CPTy::U128) => "__rust_rem_u128",
+        ("&", CPTy::I128) => "__rust_and_i128",
+        ("&", CPTy::U128) => "__rust_and_u128",
+        ("|", CPTy::I128) => "__rust_or_i128",
+        ("|", CPTy::U128) => "__rust_or_u128",
+        ("^", CPTy::I128) => "__rust_xor_i128",
+        ("^", CPTy::U128) => "__rust_xor_u128",
+        ("<<", CPTy::I128) => "__rust_shl_i128",
+        ("<<", CPTy::U128) => "__rust_shl_u128",
+        (">>", CPTy::I128) => "__rust_shr_i128",
+        (">>", CPTy::U128) => "__rust_shr_u128",
+        ("cmp", CPTy::I128) => "__rust_cmp_i128",
+        ("cmp", CPTy::U128) => "__rust_cmp_u128",
+        ("neg", CPTy::I128) => "__rust_neg_i128",
+        ("neg", CPTy::U128) => "__rust_neg_u128",
+        ("not", CPTy::I128) => "__rust_not_i128",
+        ("not", CPTy::U128) => "__rust_not_u128",
+        _ if ty.is_int128() => unreachable!("unsupported operator `{op}` on {}", ty.to_str()),
+        _ => return None,
+    };
+    Some(helper)
 }
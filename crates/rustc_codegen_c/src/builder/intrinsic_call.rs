@@ -1,6 +1,7 @@
 use rustc_codegen_ssa::mir::operand::OperandRef;
-use rustc_codegen_ssa::traits::IntrinsicCallMethods;
+use rustc_codegen_ssa::traits::{BuilderMethods, IntrinsicCallMethods};
 use rustc_middle::ty::{Instance, Ty};
+use rustc_span::sym;
 use rustc_target::abi::call::FnAbi;
 
 use crate::builder::Builder;
@@ -14,12 +15,23 @@ impl<'tcx, 'mx> IntrinsicCallMethods<'tcx> for Builder<'_, 'tcx, 'mx> {
         llresult: Self::Value,
         span: rustc_span::Span,
     ) -> Result<(), Instance<'tcx>> {
-        todo!()
+        let name = self.tcx.item_name(instance.def_id());
+        let value = match name {
+            sym::minnumf32 | sym::minnumf64 => {
+                self.float_min_max("<", args[0].immediate(), args[1].immediate())
+            }
+            sym::maxnumf32 | sym::maxnumf64 => {
+                self.float_min_max(">", args[0].immediate(), args[1].immediate())
+            }
+            _ => todo!("intrinsic `{name}`"),
+        };
+        self.store(value, llresult, fn_abi.ret.layout.align.abi);
+        Ok(())
     }
 
     fn abort(&mut self) {
         let mcx = self.cx.mcx;
-        self.bb.push_stmt(mcx.expr(mcx.call(mcx.raw("abort"), [])));
+        self.push_stmt(mcx.expr(mcx.call(mcx.raw("abort"), [])));
     }
 
     fn assume(&mut self, val: Self::Value) {
@@ -5,6 +5,8 @@ use crate::builder::Builder;
 
 impl<'tcx, 'mx> StaticBuilderMethods for Builder<'_, 'tcx, 'mx> {
     fn get_static(&mut self, def_id: DefId) -> Self::Value {
-        todo!()
+        let mcx = self.mcx;
+        let var = self.cx.static_var(def_id);
+        self.define(mcx.ptr(var.ty()), self.cx.static_var_addr(def_id))
     }
 }
@@ -1,19 +1,25 @@
 #![allow(unused_variables)] // TODO
 
 use std::cell::RefCell;
+use std::rc::Rc;
 
 use rustc_abi::{HasDataLayout, TargetDataLayout};
+use rustc_codegen_c_ast::decl::CLinkage;
 use rustc_codegen_c_ast::expr::CValue;
 use rustc_codegen_c_ast::func::{CBasicBlock, CFunc};
+use rustc_codegen_c_ast::pretty::SourceLocs;
 use rustc_codegen_c_ast::r#type::CTy;
 use rustc_codegen_c_ast::ModuleCtxt;
 use rustc_codegen_ssa::traits::BackendTypes;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
+use rustc_hir::def_id::DefId;
+use rustc_middle::mir::interpret::AllocId;
 use rustc_middle::ty::layout::{
     FnAbiError, FnAbiOfHelpers, FnAbiRequest, HasParamEnv, HasTyCtxt, LayoutError, LayoutOfHelpers,
     TyAndLayout,
 };
 use rustc_middle::ty::{Instance, ParamEnv, Ty, TyCtxt};
+use rustc_span::{StableSourceFileId, Symbol};
 use rustc_target::abi::call::FnAbi;
 use rustc_target::spec::{HasTargetSpec, Target};
 
@@ -30,20 +36,46 @@ mod type_membership;
 pub struct CodegenCx<'tcx, 'mx> {
     pub tcx: TyCtxt<'tcx>,
     pub mcx: ModuleCtxt<'mx>,
+    pub source_locs: SourceLocs,
 
     // function declarations (in another crate or extern)
     function_declarations: RefCell<FxHashMap<Instance<'tcx>, Value<'mx>>>,
     // function instances (in this crate)
     function_instances: RefCell<FxHashMap<Instance<'tcx>, CFunc<'mx>>>,
+    // static variables (predefined in this codegen unit, or declared `extern`)
+    statics: RefCell<FxHashMap<DefId, (Value<'mx>, CLinkage)>>,
+    // statics declared or defined in the module so far, which initializers may refer to
+    declared_statics: RefCell<FxHashSet<DefId>>,
+    // globals holding the addresses of statics, by the pointer type
+    static_addrs: RefCell<FxHashMap<(DefId, CTy<'mx>), CValue<'mx>>>,
+    // globals holding the addresses of constant allocations, by the pointer type
+    alloc_addrs: RefCell<FxHashMap<(AllocId, CTy<'mx>), CValue<'mx>>>,
+    // C structs of aggregate types
+    record_types: RefCell<FxHashMap<Ty<'tcx>, CTy<'mx>>>,
+    // names of the C structs and unions, which share a namespace
+    record_names: RefCell<FxHashSet<String>>,
+    // string constants
+    const_str_cache: RefCell<FxHashMap<String, CValue<'mx>>>,
+    // displayed names of source files
+    source_file_names: RefCell<FxHashMap<StableSourceFileId, Rc<str>>>,
 }
 
 impl<'tcx, 'mx> CodegenCx<'tcx, 'mx> {
-    pub fn new(tcx: TyCtxt<'tcx>, mcx: ModuleCtxt<'mx>) -> Self {
+    pub fn new(tcx: TyCtxt<'tcx>, mcx: ModuleCtxt<'mx>, source_locs: SourceLocs) -> Self {
         Self {
             tcx,
             mcx,
+            source_locs,
             function_declarations: RefCell::new(FxHashMap::default()),
             function_instances: RefCell::new(FxHashMap::default()),
+            statics: RefCell::new(FxHashMap::default()),
+            declared_statics: RefCell::new(FxHashSet::default()),
+            static_addrs: RefCell::new(FxHashMap::default()),
+            alloc_addrs: RefCell::new(FxHashMap::default()),
+            record_types: RefCell::new(FxHashMap::default()),
+            record_names: RefCell::new(FxHashSet::default()),
+            const_str_cache: RefCell::new(FxHashMap::default()),
+            source_file_names: RefCell::new(FxHashMap::default()),
         }
     }
 }
@@ -90,7 +122,7 @@ impl<'tcx, 'mx> BackendTypes for CodegenCx<'tcx, 'mx> {
     type Funclet = ();
     type DIScope = ();
     type DILocation = ();
-    type DIVariable = ();
+    type DIVariable = Symbol;
 }
 
 impl<'tcx, 'mx> HasTargetSpec for CodegenCx<'tcx, 'mx> {
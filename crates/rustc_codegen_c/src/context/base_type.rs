@@ -1,5 +1,7 @@
+use rustc_codegen_c_ast::r#type::{CPTy, CTyBase, CTyKind};
+use rustc_codegen_ssa::common::TypeKind;
 use rustc_codegen_ssa::traits::BaseTypeMethods;
-use rustc_type_ir::IntTy;
+use rustc_type_ir::{FloatTy, IntTy};
 
 use crate::context::CodegenCx;
 
@@ -33,11 +35,11 @@ impl<'tcx, 'mx> BaseTypeMethods<'tcx> for CodegenCx<'tcx, 'mx> {
     }
 
     fn type_f32(&self) -> Self::Type {
-        todo!()
+        self.mcx.float(FloatTy::F32)
     }
 
     fn type_f64(&self) -> Self::Type {
-        todo!()
+        self.mcx.float(FloatTy::F64)
     }
 
     fn type_f128(&self) -> Self::Type {
@@ -52,8 +54,18 @@ impl<'tcx, 'mx> BaseTypeMethods<'tcx> for CodegenCx<'tcx, 'mx> {
         todo!()
     }
 
-    fn type_kind(&self, ty: Self::Type) -> rustc_codegen_ssa::common::TypeKind {
-        todo!()
+    fn type_kind(&self, ty: Self::Type) -> TypeKind {
+        match ty.base {
+            CTyBase::Primitive(CPTy::Void) => TypeKind::Void,
+            CTyBase::Primitive(CPTy::F32) => TypeKind::Float,
+            CTyBase::Primitive(CPTy::F64) => TypeKind::Double,
+            CTyBase::Primitive(_) => TypeKind::Integer,
+            CTyBase::Ref(kind) => match kind.0 {
+                CTyKind::Pointer(_) | CTyKind::FnPtr(_) => TypeKind::Pointer,
+                CTyKind::Record(_) => TypeKind::Struct,
+                CTyKind::Array(..) => TypeKind::Array,
+            },
+        }
     }
 
     fn type_ptr(&self) -> Self::Type {
@@ -73,11 +85,25 @@ impl<'tcx, 'mx> BaseTypeMethods<'tcx> for CodegenCx<'tcx, 'mx> {
     }
 
     fn float_width(&self, ty: Self::Type) -> usize {
-        todo!()
+        match ty.base {
+            CTyBase::Primitive(CPTy::F32) => 32,
+            CTyBase::Primitive(CPTy::F64) => 64,
+            _ => unreachable!("float_width called on non-float type {:?}", ty),
+        }
     }
 
     fn int_width(&self, ty: Self::Type) -> u64 {
-        todo!()
+        match ty.base {
+            CTyBase::Primitive(CPTy::Bool | CPTy::Char | CPTy::I8 | CPTy::U8) => 8,
+            CTyBase::Primitive(CPTy::I16 | CPTy::U16) => 16,
+            CTyBase::Primitive(CPTy::I32 | CPTy::U32) => 32,
+            CTyBase::Primitive(CPTy::I64 | CPTy::U64) => 64,
+            CTyBase::Primitive(CPTy::I128 | CPTy::U128) => 128,
+            CTyBase::Primitive(CPTy::Isize | CPTy::Usize) => {
+                self.tcx.data_layout.pointer_size.bits()
+            }
+            _ => unreachable!("int_width called on non-integer type {:?}", ty),
+        }
     }
 
     fn val_ty(&self, v: Self::Value) -> Self::Type {
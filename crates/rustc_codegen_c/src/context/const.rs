@@ -1,11 +1,24 @@
-use rustc_abi::HasDataLayout;
-use rustc_codegen_c_ast::expr::CValue;
-use rustc_codegen_c_ast::r#type::{CTyBase, CTyKind};
-use rustc_codegen_ssa::traits::ConstMethods;
-use rustc_const_eval::interpret::{ConstAllocation, GlobalAlloc, Scalar};
-use rustc_type_ir::UintTy;
+use rustc_abi::{Abi, Align, FieldsShape, HasDataLayout, Size, TagEncoding, Variants};
+use rustc_codegen_c_ast::const_fold::ConstEval;
+use rustc_codegen_c_ast::decl::{CAttr, CLinkage};
+use rustc_codegen_c_ast::expr::{CExpr, CValue};
+use rustc_codegen_c_ast::r#type::{CPTy, CTy, CTyBase, CTyKind};
+use rustc_codegen_ssa::traits::{BaseTypeMethods, ConstMethods, LayoutTypeMethods};
+use rustc_const_eval::interpret::{
+    alloc_range, read_target_uint, AllocId, Allocation, ConstAllocation, GlobalAlloc, Scalar,
+};
+use rustc_middle::ty::layout::TyAndLayout;
+use rustc_middle::ty::ScalarInt;
+use rustc_target::abi::VariantIdx;
+use rustc_type_ir::{IntTy, TyKind, UintTy};
 
-use crate::context::CodegenCx;
+use crate::context::layout_type::{record_members, RecordMember};
+use crate::context::{CodegenCx, Value};
+
+/// The linkage of the globals of constant allocations, which are only referenced from the codegen
+/// unit that creates them.
+const CONST_LINKAGE: CLinkage =
+    CLinkage { internal: true, weak: false, hidden: false, external: false };
 
 impl<'tcx, 'mx> ConstMethods<'tcx> for CodegenCx<'tcx, 'mx> {
     fn const_null(&self, t: Self::Type) -> Self::Value {
@@ -27,44 +40,43 @@ impl<'tcx, 'mx> ConstMethods<'tcx> for CodegenCx<'tcx, 'mx> {
     }
 
     fn const_int(&self, t: Self::Type, i: i64) -> Self::Value {
-        todo!()
+        self.const_int_bits(t, i as u128)
     }
 
     fn const_uint(&self, t: Self::Type, i: u64) -> Self::Value {
-        todo!()
+        self.const_int_bits(t, i.into())
     }
 
     fn const_uint_big(&self, t: Self::Type, u: u128) -> Self::Value {
-        let val = self.mcx.scalar(u as i128); // TODO: overflow check
-        (val, t).into()
+        self.const_int_bits(t, u)
     }
 
     fn const_bool(&self, val: bool) -> Self::Value {
-        todo!()
+        (self.mcx.scalar(val.into()), self.mcx.bool()).into()
     }
 
     fn const_i16(&self, i: i16) -> Self::Value {
-        todo!()
+        (self.mcx.scalar(i.into()), self.mcx.int(IntTy::I16)).into()
     }
 
     fn const_i32(&self, i: i32) -> Self::Value {
-        todo!()
+        (self.mcx.scalar(i.into()), self.mcx.int(IntTy::I32)).into()
     }
 
     fn const_i8(&self, i: i8) -> Self::Value {
-        todo!()
+        (self.mcx.scalar(i.into()), self.mcx.int(IntTy::I8)).into()
     }
 
     fn const_u32(&self, i: u32) -> Self::Value {
-        todo!()
+        (self.mcx.scalar(i.into()), self.mcx.uint(UintTy::U32)).into()
     }
 
     fn const_u64(&self, i: u64) -> Self::Value {
-        todo!()
+        (self.mcx.scalar(i.into()), self.mcx.uint(UintTy::U64)).into()
     }
 
     fn const_u128(&self, i: u128) -> Self::Value {
-        todo!()
+        (self.mcx.u128(i), self.mcx.uint(UintTy::U128)).into()
     }
 
     fn const_usize(&self, i: u64) -> Self::Value {
@@ -72,15 +84,24 @@ impl<'tcx, 'mx> ConstMethods<'tcx> for CodegenCx<'tcx, 'mx> {
     }
 
     fn const_u8(&self, i: u8) -> Self::Value {
-        todo!()
+        (self.mcx.scalar(i.into()), self.mcx.uint(UintTy::U8)).into()
     }
 
     fn const_real(&self, t: Self::Type, val: f64) -> Self::Value {
-        todo!()
+        match t.base {
+            CTyBase::Primitive(CPTy::F32) => (self.mcx.f32(val as f32), t).into(),
+            CTyBase::Primitive(CPTy::F64) => (self.mcx.f64(val), t).into(),
+            _ => unreachable!("const_real called on non-float type {:?}", t),
+        }
     }
 
     fn const_str(&self, s: &str) -> (Self::Value, Self::Value) {
-        todo!()
+        let var = *self
+            .const_str_cache
+            .borrow_mut()
+            .entry(s.to_string())
+            .or_insert_with(|| self.const_bytes(s.as_bytes(), Align::ONE));
+        ((var, self.mcx.ptr(self.mcx.void())).into(), self.const_usize(s.len() as u64))
     }
 
     fn const_struct(&self, elts: &[Self::Value], packed: bool) -> Self::Value {
@@ -88,11 +109,28 @@ impl<'tcx, 'mx> ConstMethods<'tcx> for CodegenCx<'tcx, 'mx> {
     }
 
     fn const_to_opt_uint(&self, v: Self::Value) -> Option<u64> {
-        todo!()
+        self.const_to_opt_u128(v, false)?.try_into().ok()
     }
 
     fn const_to_opt_u128(&self, v: Self::Value, sign_ext: bool) -> Option<u128> {
-        None // TODO: why?
+        let Value::RValue { cval, ty } = v else { return None };
+        let bits = match cval {
+            CValue::Scalar(value) => value as u128,
+            CValue::I128(value) => value as u128,
+            CValue::U128(value) => value,
+            _ => return None,
+        };
+        let CTyBase::Primitive(prim) = ty.base else { return None };
+        if prim.is_float() {
+            return None;
+        }
+        // booleans are a single bit
+        let size = if prim == CPTy::Bool {
+            Size::from_bits(1)
+        } else {
+            Size::from_bits(self.int_width(ty))
+        };
+        Some(if sign_ext { size.sign_extend(bits) } else { size.truncate(bits) })
     }
 
     fn const_data_from_alloc(&self, alloc: ConstAllocation<'tcx>) -> Self::Value {
@@ -106,7 +144,15 @@ impl<'tcx, 'mx> ConstMethods<'tcx> for CodegenCx<'tcx, 'mx> {
         ty: Self::Type,
     ) -> Self::Value {
         match cv {
-            Scalar::Int(scalar) => (self.mcx.scalar(scalar.to_int(scalar.size())), ty).into(),
+            Scalar::Int(scalar) => match ty.base {
+                CTyBase::Primitive(CPTy::F32) => {
+                    (self.mcx.f32(f32::from_bits(scalar.to_u32())), ty).into()
+                }
+                CTyBase::Primitive(CPTy::F64) => {
+                    (self.mcx.f64(f64::from_bits(scalar.to_u64())), ty).into()
+                }
+                _ => self.const_int_bits(ty, scalar.to_int(scalar.size()) as u128),
+            },
             Scalar::Ptr(ptr, _) => {
                 let (prov, offset) = ptr.into_parts(); // we know the `offset` is relative
                 assert!(offset.bytes() == 0, "TODO");
@@ -114,10 +160,10 @@ impl<'tcx, 'mx> ConstMethods<'tcx> for CodegenCx<'tcx, 'mx> {
                 let base_addr = match self.tcx.global_alloc(alloc_id) {
                     GlobalAlloc::Function(_) => todo!(),
                     GlobalAlloc::VTable(_, _) => todo!(),
-                    GlobalAlloc::Static(_) => todo!(),
-                    GlobalAlloc::Memory(alloc) => self.const_alloc(alloc),
+                    GlobalAlloc::Static(def_id) => self.static_addr(def_id, ty),
+                    GlobalAlloc::Memory(alloc) => self.alloc_addr(alloc_id, alloc, ty),
                 };
-                (base_addr, ty).into() // this may create a char* implicitly casted to other pointers
+                (base_addr, ty).into()
             }
         }
     }
@@ -132,6 +178,27 @@ impl<'tcx, 'mx> ConstMethods<'tcx> for CodegenCx<'tcx, 'mx> {
 }
 
 impl<'tcx, 'mx> CodegenCx<'tcx, 'mx> {
+    /// A global holding the address of a constant allocation as a `ty`, since the global of the
+    /// allocation itself is an array of `char`s.
+    fn alloc_addr(
+        &self,
+        alloc_id: AllocId,
+        alloc: ConstAllocation<'tcx>,
+        ty: CTy<'mx>,
+    ) -> CValue<'mx> {
+        if let Some(&addr) = self.alloc_addrs.borrow().get(&(alloc_id, ty)) {
+            return addr;
+        }
+
+        let mcx = self.mcx;
+        let init = mcx.cast(ty, mcx.value(self.const_alloc(alloc)));
+        let addr = mcx.next_global_var();
+        let decl = mcx.global_var(addr, ty.to_const_if(true), Some(init), CONST_LINKAGE, []);
+        mcx.module().push_decl(decl);
+        self.alloc_addrs.borrow_mut().insert((alloc_id, ty), addr);
+        addr
+    }
+
     pub fn const_alloc(&self, alloc: ConstAllocation<'tcx>) -> CValue<'mx> {
         let alloc = alloc.inner();
         let mut chunks = Vec::with_capacity(alloc.provenance().ptrs().len() + 1);
@@ -159,16 +226,216 @@ impl<'tcx, 'mx> CodegenCx<'tcx, 'mx> {
         }
 
         assert!(chunks.len() == 1, "TODO");
+        if alloc.mutability.is_not() && !chunks[0].is_empty() {
+            return self.const_bytes(chunks[0], alloc.align);
+        }
+
         let mcx = self.mcx;
         let var = mcx.next_global_var();
         let ty = self.mcx.arr(self.mcx.char().to_const_if(alloc.mutability.is_not()), None);
-        mcx.module().push_decl(mcx.var(
-            var,
-            ty,
-            Some(mcx.init_list(
-                chunks[0].iter().map(|&b| mcx.value(mcx.scalar(b as i128))).collect::<Box<[_]>>(),
-            )),
-        ));
+        let init = mcx.init_list(
+            chunks[0].iter().map(|&b| mcx.value(mcx.scalar(b as i128))).collect::<Box<[_]>>(),
+        );
+        let attrs = align_attr(alloc.align);
+        mcx.module().push_decl(mcx.global_var(var, ty, Some(init), CONST_LINKAGE, attrs));
         var
     }
 }
+
+impl<'tcx, 'mx> CodegenCx<'tcx, 'mx> {
+    /// The initializer of a global of the C type of `layout`, from the bytes of `alloc` at
+    /// `offset`, e.g. `{1, {0, 0, 0}, 2}` for a struct with padding.
+    ///
+    /// Pointers become the addresses of what they point to, and enums initialize the member of
+    /// their union for the variant they hold, e.g. `{{.v1 = {{1, 0, 0, 0}, 7}}}` for `Some(7)`.
+    pub fn const_init(
+        &self,
+        alloc: &Allocation,
+        offset: Size,
+        layout: TyAndLayout<'tcx>,
+    ) -> CExpr<'mx> {
+        let mcx = self.mcx;
+
+        if let Abi::Scalar(scalar) = layout.abi {
+            let ty = self.immediate_backend_type(layout);
+            let bits = self.const_bits(alloc, offset, layout.size);
+            if let Some(prov) = alloc.provenance().get_ptr(offset) {
+                // the bytes of a pointer are its offset into what it points to
+                return self.const_ptr_init(prov.alloc_id(), Size::from_bytes(bits), ty);
+            }
+            let int = ScalarInt::try_from_uint(bits, layout.size).unwrap();
+            return mcx.value(self.scalar_to_backend(Scalar::Int(int), scalar, ty).cval());
+        }
+
+        let exprs = match (layout.ty.kind(), &layout.fields, &layout.variants) {
+            (TyKind::Array(..), &FieldsShape::Array { stride, count }, _) => {
+                let elem = layout.field(self, 0);
+                (0..count).map(|i| self.const_init(alloc, offset + stride * i, elem)).collect()
+            }
+            (_, FieldsShape::Arbitrary { .. }, Variants::Single { .. }) => {
+                let fields = self.record_fields(layout);
+                return self.const_members_init(alloc, offset, &fields, layout.size);
+            }
+            (_, FieldsShape::Arbitrary { .. }, &Variants::Multiple { tag_field, .. }) => {
+                let index = self.const_variant(alloc, offset, layout);
+                let fields = self.record_fields(layout.for_variant(self, index));
+                let tag = layout.field(self, tag_field);
+                let tag_offset = layout.fields.offset(tag_field);
+                // variants without fields have no member in the union and only set the tag
+                let member = if !fields.is_empty() {
+                    let init = self.const_members_init(alloc, offset, &fields, layout.size);
+                    mcx.designated(mcx.alloc_str(&format!("v{}", index.as_u32())), init)
+                } else if tag_offset == Size::ZERO && tag.size == layout.size {
+                    self.const_init(alloc, offset, tag)
+                } else {
+                    let tag = [("tag".to_string(), tag_offset, tag)];
+                    self.const_members_init(alloc, offset, &tag, layout.size)
+                };
+                vec![mcx.init_list([member])]
+            }
+            _ => todo!("initializer of {:?}", layout.ty),
+        };
+        mcx.init_list(exprs)
+    }
+
+    /// The initializer of a record of `fields` sorted by offset, with explicit padding up to
+    /// `size`. The padding holds the bytes of `alloc` it covers, e.g. the tag of an enum.
+    fn const_members_init(
+        &self,
+        alloc: &Allocation,
+        offset: Size,
+        fields: &[(String, Size, TyAndLayout<'tcx>)],
+        size: Size,
+    ) -> CExpr<'mx> {
+        let mcx = self.mcx;
+        let mut next_offset = offset;
+        let members = record_members(fields, size).into_iter().map(|member| match member {
+            RecordMember::Field(_, field_offset, field) => {
+                next_offset = offset + field_offset + field.size;
+                self.const_init(alloc, offset + field_offset, field)
+            }
+            RecordMember::Padding(pad) => {
+                let range = next_offset.bytes_usize()..(next_offset + pad).bytes_usize();
+                next_offset += pad;
+                let bytes = alloc.inspect_with_uninit_and_ptr_outside_interpreter(range);
+                let bytes = if bytes.iter().all(|&b| b == 0) { &[0][..] } else { bytes };
+                mcx.init_list(
+                    bytes.iter().map(|&b| mcx.value(mcx.scalar(b.into()))).collect::<Vec<_>>(),
+                )
+            }
+        });
+        mcx.init_list(members.collect::<Vec<_>>())
+    }
+
+    /// The variant of the enum in `alloc` at `offset`, decoded from its tag like
+    /// `codegen_ssa` does at runtime.
+    fn const_variant(
+        &self,
+        alloc: &Allocation,
+        offset: Size,
+        layout: TyAndLayout<'tcx>,
+    ) -> VariantIdx {
+        let Variants::Multiple { tag_field, ref tag_encoding, .. } = layout.variants else {
+            unreachable!("{:?} has a single variant", layout.ty)
+        };
+        let tag_offset = offset + layout.fields.offset(tag_field);
+        let tag_size = layout.field(self, tag_field).size;
+        match *tag_encoding {
+            TagEncoding::Direct => {
+                let bits = self.const_bits(alloc, tag_offset, tag_size);
+                let TyKind::Adt(def, _) = layout.ty.kind() else {
+                    todo!("initializer of {:?}", layout.ty)
+                };
+                def.discriminants(self.tcx)
+                    .find(|(_, discr)| tag_size.truncate(discr.val) == bits)
+                    .map(|(index, _)| index)
+                    .unwrap_or_else(|| panic!("invalid tag {bits} of {:?}", layout.ty))
+            }
+            TagEncoding::Niche { untagged_variant, ref niche_variants, niche_start } => {
+                // a pointer is never a niche value
+                let range = alloc_range(tag_offset, tag_size);
+                if !alloc.provenance().range_empty(range, self) {
+                    return untagged_variant;
+                }
+                let bits = self.const_bits(alloc, tag_offset, tag_size);
+                let relative = tag_size.truncate(bits.wrapping_sub(niche_start));
+                let niches = niche_variants.end().as_u32() - niche_variants.start().as_u32();
+                if relative <= niches.into() {
+                    VariantIdx::from_u32(niche_variants.start().as_u32() + relative as u32)
+                } else {
+                    untagged_variant
+                }
+            }
+        }
+    }
+
+    /// The address `offset` bytes into a static or constant allocation as a `ty`, for
+    /// initializers, e.g. `(int32_t*) ((char*) (&FOO) + 4)`.
+    fn const_ptr_init(&self, alloc_id: AllocId, offset: Size, ty: CTy<'mx>) -> CExpr<'mx> {
+        let mcx = self.mcx;
+        let base = match self.tcx.global_alloc(alloc_id) {
+            GlobalAlloc::Static(def_id) => self.static_init_addr(def_id),
+            GlobalAlloc::Memory(alloc) => mcx.value(self.const_alloc(alloc)),
+            GlobalAlloc::Function(_) => todo!("function pointers in initializers"),
+            GlobalAlloc::VTable(..) => todo!("vtables in initializers"),
+        };
+        if offset == Size::ZERO {
+            return mcx.cast(ty, base);
+        }
+        let bytes = mcx.cast(mcx.ptr(mcx.char()), base);
+        mcx.cast(ty, mcx.binary(bytes, mcx.value(mcx.scalar(offset.bytes().into())), "+"))
+    }
+
+    /// The bits of the integer of `size` in `alloc` at `offset`, or of the offset of a pointer.
+    fn const_bits(&self, alloc: &Allocation, offset: Size, size: Size) -> u128 {
+        let range = offset.bytes_usize()..(offset + size).bytes_usize();
+        let bytes = alloc.inspect_with_uninit_and_ptr_outside_interpreter(range);
+        read_target_uint(self.data_layout().endian, bytes).unwrap()
+    }
+
+    /// The evaluator of constant integer expressions for the target.
+    pub fn const_eval(&self) -> ConstEval {
+        ConstEval { ptr_width: self.tcx.data_layout.pointer_size.bits() }
+    }
+
+    /// A read-only global initialized with a string literal, e.g.
+    /// `const char _g0[5] = "hello";`. The array has no room for a terminating NUL unless the
+    /// bytes are empty.
+    fn const_bytes(&self, bytes: &[u8], align: Align) -> CValue<'mx> {
+        let mcx = self.mcx;
+        let var = mcx.next_global_var();
+        let ty = mcx.arr(mcx.char().to_const_if(true), bytes.len().try_into().ok());
+        let init = Some(mcx.str_lit(bytes));
+        mcx.module().push_decl(mcx.global_var(var, ty, init, CONST_LINKAGE, align_attr(align)));
+        var
+    }
+
+    /// An integer constant of type `t` from its bits, which are truncated and sign-extended
+    /// as the type requires. 128-bit integers need a dedicated constant, since they may not be a
+    /// C integer type.
+    fn const_int_bits(&self, t: CTy<'mx>, bits: u128) -> Value<'mx> {
+        let val = match t.base {
+            CTyBase::Primitive(CPTy::I128) => self.mcx.i128(bits as i128),
+            CTyBase::Primitive(CPTy::U128) => self.mcx.u128(bits),
+            CTyBase::Primitive(ty) if ty.is_signed() => {
+                let size = Size::from_bits(self.int_width(t));
+                self.mcx.scalar(size.sign_extend(bits) as i128)
+            }
+            CTyBase::Primitive(_) => {
+                let size = Size::from_bits(self.int_width(t));
+                self.mcx.scalar(size.truncate(bits) as i128)
+            }
+            _ => self.mcx.scalar(bits as i128),
+        };
+        (val, t).into()
+    }
+}
+
+/// `__attribute__((aligned(N)))` for a global of `char`s holding data aligned to `align`.
+fn align_attr<'mx>(align: Align) -> Vec<CAttr<'mx>> {
+    if align > Align::ONE {
+        vec![CAttr::Aligned(align.bytes())]
+    } else {
+        Vec::new()
+    }
+}
@@ -1,11 +1,75 @@
-use rustc_codegen_ssa::mir::debuginfo::{FunctionDebugContext, VariableKind};
+use rustc_codegen_c_ast::func::CFunc;
+use rustc_codegen_c_ast::pretty::SourceLocs;
+use rustc_codegen_c_ast::stmt::SourceLoc;
+use rustc_codegen_ssa::mir::debuginfo::{DebugScope, FunctionDebugContext, VariableKind};
 use rustc_codegen_ssa::traits::DebugInfoMethods;
-use rustc_middle::mir::Body;
+use rustc_middle::mir::{Body, VarDebugInfoContents};
 use rustc_middle::ty::{Instance, PolyExistentialTraitRef, Ty};
-use rustc_target::abi::call::FnAbi;
+use rustc_session::config::{DebugInfo, RemapPathScopeComponents};
+use rustc_span::{BytePos, Span};
+use rustc_target::abi::call::{FnAbi, PassMode};
 
 use crate::context::CodegenCx;
 
+impl<'tcx, 'mx> CodegenCx<'tcx, 'mx> {
+    /// The source location of a span if source locations are printed, with the path of the file
+    /// remapped as in debug info. Code expanded from a macro is located at the macro call.
+    pub fn source_loc(&self, span: Span) -> Option<SourceLoc> {
+        if self.source_locs == SourceLocs::Off || span.is_dummy() {
+            return None;
+        }
+        let sess = self.tcx.sess;
+        let loc = sess.source_map().lookup_char_pos(span.source_callsite().lo());
+        let mut names = self.source_file_names.borrow_mut();
+        let file = names.entry(loc.file.stable_id).or_insert_with(|| {
+            let pref = sess.filename_display_preference(RemapPathScopeComponents::DEBUGINFO);
+            loc.file.name.display(pref).to_string().into()
+        });
+        Some(SourceLoc { file: file.clone(), line: loc.line as u32 })
+    }
+
+    /// Name the parameters of a function after the arguments in the debug info of its MIR, e.g.
+    /// `limit` for `fn f(limit: u32)`. The halves of an argument passed as a pair are suffixed
+    /// with `.0` and `.1` as by `codegen_ssa`.
+    fn name_params(&self, fn_abi: &FnAbi<'tcx, Ty<'tcx>>, func: CFunc<'mx>, mir: &Body<'tcx>) {
+        // the arguments of a spread argument, e.g. of closures, are not locals of their own
+        if mir.spread_arg.is_some() {
+            return;
+        }
+        let mut names = vec![None; mir.arg_count];
+        for var in &mir.var_debug_info {
+            let VarDebugInfoContents::Place(place) = var.value else { continue };
+            if var.composite.is_some() || !place.projection.is_empty() {
+                continue;
+            }
+            if let Some(name) = place.local.as_usize().checked_sub(1).and_then(|i| names.get_mut(i))
+            {
+                name.get_or_insert(var.name);
+            }
+        }
+
+        let mut params = func.0.params.iter().skip(fn_abi.ret.is_indirect() as usize);
+        for (arg, name) in fn_abi.args.iter().zip(names) {
+            let count = match arg.mode {
+                PassMode::Ignore => 0,
+                PassMode::Direct(_) | PassMode::Cast { .. } => 1,
+                PassMode::Pair(..) => 2,
+                PassMode::Indirect { meta_attrs, .. } => 1 + meta_attrs.is_some() as usize,
+            };
+            let arg_params: Vec<_> = params.by_ref().take(count).collect();
+            let Some(name) = name else { continue };
+            match *arg_params {
+                [&param] => func.0.set_local_name(param, name.as_str()),
+                [&a, &b] if matches!(arg.mode, PassMode::Pair(..)) => {
+                    func.0.set_local_name(a, &format!("{name}.0"));
+                    func.0.set_local_name(b, &format!("{name}.1"));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
 impl<'tcx, 'mx> DebugInfoMethods<'tcx> for CodegenCx<'tcx, 'mx> {
     fn create_vtable_debuginfo(
         &self,
@@ -16,6 +80,11 @@ impl<'tcx, 'mx> DebugInfoMethods<'tcx> for CodegenCx<'tcx, 'mx> {
         todo!()
     }
 
+    /// Name the parameters after the arguments in the MIR debug info. The locals holding other
+    /// variables are only known to `codegen_ssa`, which names them with full debug info through
+    /// [`dbg_var_addr`], so a debug context with a scope per MIR scope is only returned then.
+    ///
+    /// [`dbg_var_addr`]: rustc_codegen_ssa::traits::DebugInfoBuilderMethods::dbg_var_addr
     fn create_function_debug_context(
         &self,
         instance: Instance<'tcx>,
@@ -23,7 +92,21 @@ impl<'tcx, 'mx> DebugInfoMethods<'tcx> for CodegenCx<'tcx, 'mx> {
         llfn: Self::Function,
         mir: &Body<'tcx>,
     ) -> Option<FunctionDebugContext<'tcx, Self::DIScope, Self::DILocation>> {
-        None
+        self.name_params(fn_abi, llfn, mir);
+
+        if self.tcx.sess.opts.debuginfo != DebugInfo::Full {
+            return None;
+        }
+        let scope = DebugScope {
+            dbg_scope: (),
+            inlined_at: None,
+            file_start_pos: BytePos(0),
+            file_end_pos: BytePos(u32::MAX),
+        };
+        Some(FunctionDebugContext {
+            scopes: mir.source_scopes.iter().map(|_| scope).collect(),
+            inlined_function_scopes: Default::default(),
+        })
     }
 
     fn dbg_scope_fn(
@@ -35,13 +118,13 @@ impl<'tcx, 'mx> DebugInfoMethods<'tcx> for CodegenCx<'tcx, 'mx> {
         todo!()
     }
 
+    /// Source locations are taken from the span of the builder instead.
     fn dbg_loc(
         &self,
         scope: Self::DIScope,
         inlined_at: Option<Self::DILocation>,
         span: rustc_span::Span,
     ) -> Self::DILocation {
-        todo!()
     }
 
     fn extend_scope_to_file(
@@ -49,7 +132,6 @@ impl<'tcx, 'mx> DebugInfoMethods<'tcx> for CodegenCx<'tcx, 'mx> {
         scope_metadata: Self::DIScope,
         file: &rustc_span::SourceFile,
     ) -> Self::DIScope {
-        todo!()
     }
 
     fn debuginfo_finalize(&self) {
@@ -64,6 +146,6 @@ impl<'tcx, 'mx> DebugInfoMethods<'tcx> for CodegenCx<'tcx, 'mx> {
         variable_kind: VariableKind,
         span: rustc_span::Span,
     ) -> Self::DIVariable {
-        todo!()
+        variable_name
     }
 }
@@ -1,10 +1,14 @@
-use rustc_abi::{Abi, Integer, Primitive};
-use rustc_codegen_c_ast::r#type::CTy;
+use rustc_abi::{
+    Abi, Align, FieldsShape, Float, HasDataLayout, Integer, Primitive, Size, Variants,
+};
+use rustc_codegen_c_ast::r#type::{CField, CPTy, CTy, CTyBase};
+use rustc_codegen_c_ast::{is_reserved_ident, sanitize_ident};
 use rustc_codegen_ssa::traits::LayoutTypeMethods;
 use rustc_middle::ty::layout::{HasParamEnv, TyAndLayout};
+use rustc_middle::ty::print::with_no_trimmed_paths;
 use rustc_middle::ty::Ty;
 use rustc_target::abi::call::{Conv, FnAbi, PassMode};
-use rustc_type_ir::{IntTy, TyKind, UintTy};
+use rustc_type_ir::{FloatTy, IntTy, TyKind, UintTy};
 
 use crate::context::CodegenCx;
 
@@ -16,26 +20,26 @@ impl<'tcx, 'mx> CodegenCx<'tcx, 'mx> {
     fn get_cty(&self, layout: TyAndLayout<'tcx>, abi: Conv) -> CTy<'mx> {
         match layout.abi {
             Abi::Uninhabited => self.mcx.void(),
-            Abi::Scalar(scalar) => self.get_cty_scalar(layout.ty, abi),
-            Abi::ScalarPair(_, _) => todo!(),
+            Abi::Scalar(scalar) => self.get_cty_scalar(layout.ty, scalar.primitive(), abi),
+            Abi::ScalarPair(_, _) => self.get_cty_agg(layout, abi),
             Abi::Vector { element, count } => todo!(),
             Abi::Aggregate { sized } => self.get_cty_agg(layout, abi),
         }
     }
 
-    fn get_cty_scalar(&self, ty: Ty<'tcx>, abi: Conv) -> CTy<'mx> {
+    fn get_cty_scalar(&self, ty: Ty<'tcx>, primitive: Primitive, abi: Conv) -> CTy<'mx> {
         let mcx = self.mcx;
         match ty.kind() {
             TyKind::Bool => mcx.bool(),
             TyKind::Char => mcx.int(IntTy::I32),
             TyKind::Int(int) => mcx.int(*int),
             TyKind::Uint(uint) => mcx.uint(*uint),
-            TyKind::Float(_) => todo!(),
-            TyKind::Ref(_, ty, m) => {
-                mcx.ptr(self.get_cty(self.layout_of(*ty), abi)).to_const_if(m.is_not())
-            }
-            TyKind::RawPtr(ty, m) => match abi {
-                Conv::C => mcx.ptr(self.get_cty(self.layout_of(*ty), abi)).to_const_if(m.is_not()),
+            TyKind::Float(float) => mcx.float(*float),
+            // no `const` even for shared references: the pointee may be interior mutable, e.g.
+            // `&Cell<T>`, and `codegen_ssa` freely converts between pointer types
+            TyKind::Ref(_, ty, _) => mcx.ptr(self.get_cty(self.layout_of(*ty), abi)),
+            TyKind::RawPtr(ty, _) => match abi {
+                Conv::C => mcx.ptr(self.get_cty(self.layout_of(*ty), abi)),
                 Conv::Rust => mcx.int(IntTy::Isize),
                 _ => todo!(),
             },
@@ -43,13 +47,40 @@ impl<'tcx, 'mx> CodegenCx<'tcx, 'mx> {
                 if self.tcx.lang_items().c_void().is_some_and(|void| def.did() == void) {
                     self.mcx.void()
                 } else {
-                    todo!()
+                    // newtypes and other single-scalar ADTs are passed as the scalar itself
+                    self.get_cty_primitive(primitive)
                 }
             }
             _ => todo!(),
         }
     }
 
+    fn get_cty_primitive(&self, primitive: Primitive) -> CTy<'mx> {
+        match primitive {
+            Primitive::Int(int, true) => self.mcx.int(match int {
+                Integer::I8 => IntTy::I8,
+                Integer::I16 => IntTy::I16,
+                Integer::I32 => IntTy::I32,
+                Integer::I64 => IntTy::I64,
+                Integer::I128 => IntTy::I128,
+            }),
+            Primitive::Int(int, false) => self.mcx.uint(match int {
+                Integer::I8 => UintTy::U8,
+                Integer::I16 => UintTy::U16,
+                Integer::I32 => UintTy::U32,
+                Integer::I64 => UintTy::U64,
+                Integer::I128 => UintTy::U128,
+            }),
+            Primitive::Float(float) => self.mcx.float(match float {
+                Float::F16 => FloatTy::F16,
+                Float::F32 => FloatTy::F32,
+                Float::F64 => FloatTy::F64,
+                Float::F128 => FloatTy::F128,
+            }),
+            Primitive::Pointer(_) => self.mcx.ptr(self.mcx.void()),
+        }
+    }
+
     fn get_cty_agg(&self, layout: TyAndLayout<'tcx>, abi: Conv) -> CTy<'mx> {
         let mcx = self.mcx;
         let ty = self.tcx.erase_regions(layout.ty);
@@ -58,11 +89,297 @@ impl<'tcx, 'mx> CodegenCx<'tcx, 'mx> {
                 self.get_cty(self.layout_of(*ty), abi),
                 Some(layout.fields.count().try_into().unwrap()), // TODO: [_; 0]
             ),
-            _ => mcx.void(), // TODO
+            _ if layout.is_unsized() || layout.is_zst() => mcx.void(), // TODO
+            _ => self.get_cty_record(layout),
+        }
+    }
+
+    /// Lower an aggregate to a C struct, with fields placed at the offsets computed by rustc.
+    ///
+    /// Gaps between fields are filled with explicit `uint8_t[N]` padding, so that the layout
+    /// does not depend on the padding rules of the C compiler.
+    ///
+    /// Multi-variant enums are lowered to a struct holding a union of per-variant structs.
+    /// Each member of the union spans the whole enum, and the union has an additional `tag`
+    /// member placing the tag (or niche) at its offset, e.g.
+    ///
+    /// ```c
+    /// struct Option_0 { union Option_0_u u; };
+    /// union Option_0_u { uint32_t tag; struct Option_0_v1 v1; };
+    /// struct Option_0_v1 { uint8_t _pad0[4]; uint32_t f0; };
+    /// ```
+    ///
+    /// `#[repr(C)]` ADTs keep their Rust name and field names where C allows, e.g.
+    /// `struct Point { int32_t x; int32_t y; }`, since C code linked with the crate uses them.
+    fn get_cty_record(&self, layout: TyAndLayout<'tcx>) -> CTy<'mx> {
+        let ty = self.tcx.erase_regions(layout.ty);
+        if let Some(&record) = self.record_types.borrow().get(&ty) {
+            return record;
+        }
+
+        let FieldsShape::Arbitrary { .. } = layout.fields else {
+            panic!("unexpected fields shape of {:?}: {:?}", ty, layout.fields)
+        };
+
+        let mcx = self.mcx;
+        let name = match ty.kind() {
+            TyKind::Adt(def, _) => self.tcx.item_name(def.did()).to_string(),
+            TyKind::Tuple(_) => "tuple".to_string(),
+            TyKind::Closure(..) => "closure".to_string(),
+            _ => with_no_trimmed_paths!(ty.to_string()),
+        };
+        let name = match ty.kind() {
+            TyKind::Adt(def, _) if def.repr().c() => self.record_name(sanitize_ident(&name)),
+            _ => self.record_name(format!(
+                "{}_{}",
+                sanitize_ident(&name),
+                self.record_types.borrow().len()
+            )),
+        };
+
+        match layout.variants {
+            Variants::Single { .. } => {
+                let fields = self.record_fields(layout);
+                let packed = self.needs_packed(layout, &fields);
+                let align = extra_align(layout, [natural_align(&fields, packed)]);
+                let record = mcx.record(&name, packed, align);
+                self.record_types.borrow_mut().insert(ty, record);
+
+                record.record().unwrap().set_fields(self.lower_fields(&fields, layout.size));
+                self.assert_layout(record, layout, &fields);
+                record
+            }
+            Variants::Multiple { tag_field, ref variants, .. } => {
+                let tag = layout.field(self, tag_field);
+                let variant_aligns = variants.indices().filter_map(|index| {
+                    let variant = layout.for_variant(self, index);
+                    let fields = self.record_fields(variant);
+                    let packed = self.needs_packed(layout, &fields);
+                    (!variant.abi.is_uninhabited()).then(|| natural_align(&fields, packed))
+                });
+                let align = extra_align(layout, variant_aligns.chain([tag.align.abi]));
+                let record = mcx.record(&name, false, align);
+                self.record_types.borrow_mut().insert(ty, record);
+
+                let mut members = Vec::with_capacity(variants.len() + 1);
+
+                let tag_offset = layout.fields.offset(tag_field);
+                let tag_ty = if tag_offset == Size::ZERO && tag.size == layout.size {
+                    self.get_cty(tag, Conv::Rust)
+                } else {
+                    let tag = [("tag".to_string(), tag_offset, tag)];
+                    let tag_ty =
+                        mcx.record(&self.record_name(format!("{}_tag", name)), false, None);
+                    tag_ty.record().unwrap().set_fields(self.lower_fields(&tag, layout.size));
+                    tag_ty
+                };
+                members.push(CField { name: "tag", ty: tag_ty });
+
+                for (index, _) in variants.iter_enumerated() {
+                    let variant = layout.for_variant(self, index);
+                    if variant.abi.is_uninhabited() {
+                        continue;
+                    }
+                    let fields = self.record_fields(variant);
+                    if fields.is_empty() {
+                        continue;
+                    }
+                    let packed = self.needs_packed(layout, &fields);
+                    let variant_name = self.record_name(format!("{}_v{}", name, index.as_u32()));
+                    let variant_ty = mcx.record(&variant_name, packed, None);
+                    let fields = self.lower_fields(&fields, layout.size);
+                    variant_ty.record().unwrap().set_fields(fields);
+                    members.push(CField {
+                        name: mcx.alloc_str(&format!("v{}", index.as_u32())),
+                        ty: variant_ty,
+                    });
+                }
+
+                let union = mcx.union(&self.record_name(format!("{}_u", name)));
+                union.record().unwrap().set_fields(members);
+                record.record().unwrap().set_fields([CField { name: "u", ty: union }]);
+                self.assert_layout(record, layout, &[]);
+                record
+            }
+        }
+    }
+
+    /// Claim a record name, with a numeric suffix if it is already taken or reserved in C.
+    fn record_name(&self, name: String) -> String {
+        let mut names = self.record_names.borrow_mut();
+        let mut unique = name.clone();
+        let mut suffix = names.len();
+        while unique.is_empty() || is_reserved_ident(&unique) || names.contains(&unique) {
+            unique = format!("{}_{}", name, suffix);
+            suffix += 1;
+        }
+        names.insert(unique.clone());
+        unique
+    }
+
+    /// The non-zero-sized fields of a layout, sorted by offset.
+    pub fn record_fields(
+        &self,
+        layout: TyAndLayout<'tcx>,
+    ) -> Vec<(String, Size, TyAndLayout<'tcx>)> {
+        let mut names = self.field_names(layout);
+        layout
+            .fields
+            .index_by_increasing_offset()
+            .map(|i| {
+                (std::mem::take(&mut names[i]), layout.fields.offset(i), layout.field(self, i))
+            })
+            .filter(|(_, _, field)| !field.is_zst())
+            .collect()
+    }
+
+    /// The C names of the fields of a layout: the Rust names for `#[repr(C)]` ADTs, and `f0`,
+    /// `f1`, ... for other types and for fields whose names are no valid C identifiers, e.g.
+    /// those of tuple structs.
+    fn field_names(&self, layout: TyAndLayout<'tcx>) -> Vec<String> {
+        let count = layout.fields.count();
+        let mut names = vec![String::new(); count];
+        if let (TyKind::Adt(def, _), Variants::Single { index }) =
+            (layout.ty.kind(), &layout.variants)
+        {
+            if def.repr().c() {
+                for (i, field) in def.variant(*index).fields.iter().enumerate() {
+                    let ident = sanitize_ident(field.name.as_str());
+                    if !is_reserved_ident(&ident) && !names.contains(&ident) {
+                        names[i] = ident;
+                    }
+                }
+            }
         }
+        for i in 0..count {
+            if names[i].is_empty() {
+                let mut name = format!("f{}", i);
+                while names.contains(&name) {
+                    name.push('_');
+                }
+                names[i] = name;
+            }
+        }
+        names
+    }
+
+    /// Fields at offsets the C compiler would not choose need a packed struct.
+    fn needs_packed(
+        &self,
+        layout: TyAndLayout<'tcx>,
+        fields: &[(String, Size, TyAndLayout<'tcx>)],
+    ) -> bool {
+        fields.iter().any(|(_, offset, field)| {
+            field.align.abi > layout.align.abi || !offset.is_aligned(field.align.abi)
+        })
+    }
+
+    /// The alignment of a scalar C type, or one for other types.
+    pub fn scalar_align(&self, ty: CTy<'mx>) -> Align {
+        let dl = self.data_layout();
+        let CTyBase::Primitive(prim) = ty.base else {
+            return if ty.is_ptr() { dl.pointer_align.abi } else { Align::ONE };
+        };
+        match prim {
+            CPTy::Void | CPTy::Bool | CPTy::Char | CPTy::I8 | CPTy::U8 => Align::ONE,
+            CPTy::I16 | CPTy::U16 => dl.i16_align.abi,
+            CPTy::I32 | CPTy::U32 => dl.i32_align.abi,
+            CPTy::I64 | CPTy::U64 => dl.i64_align.abi,
+            CPTy::I128 | CPTy::U128 => dl.i128_align.abi,
+            CPTy::Isize | CPTy::Usize => dl.pointer_align.abi,
+            CPTy::F32 => dl.f32_align.abi,
+            CPTy::F64 => dl.f64_align.abi,
+        }
+    }
+
+    /// Check the size and alignment of a record and the offsets of its fields against the rustc
+    /// layout with static assertions, e.g. `_Static_assert(sizeof(struct Foo_0) == 8, ...)`.
+    fn assert_layout(
+        &self,
+        record: CTy<'mx>,
+        layout: TyAndLayout<'tcx>,
+        fields: &[(String, Size, TyAndLayout<'tcx>)],
+    ) {
+        let mcx = self.mcx;
+        let eq = |expr, bytes: u64| mcx.binary(expr, mcx.value(mcx.scalar(bytes.into())), "==");
+
+        mcx.module().push_static_assert(eq(mcx.size_of(record), layout.size.bytes()));
+        mcx.module().push_static_assert(eq(mcx.align_of(record), layout.align.abi.bytes()));
+        for (name, offset, _) in fields {
+            let field = mcx.alloc_str(name);
+            mcx.module().push_static_assert(eq(mcx.offset_of(record, field), offset.bytes()));
+        }
+    }
+
+    /// Lower fields sorted by offset to C fields, with explicit padding up to `size`.
+    fn lower_fields(
+        &self,
+        fields: &[(String, Size, TyAndLayout<'tcx>)],
+        size: Size,
+    ) -> Vec<CField<'mx>> {
+        let mcx = self.mcx;
+        let mut pad = 0;
+        record_members(fields, size)
+            .into_iter()
+            .map(|member| match member {
+                RecordMember::Field(name, _, field) => {
+                    CField { name: mcx.alloc_str(name), ty: self.get_cty(field, Conv::Rust) }
+                }
+                RecordMember::Padding(size) => {
+                    let size = size.bytes_usize().try_into().unwrap();
+                    let ty = mcx.arr(mcx.uint(UintTy::U8), Some(size));
+                    pad += 1;
+                    CField { name: mcx.alloc_str(&format!("_pad{}", pad - 1)), ty }
+                }
+            })
+            .collect()
     }
 }
 
+/// A member of the C struct of an aggregate: a field, or explicit padding of the given size.
+pub enum RecordMember<'a, 'tcx> {
+    Field(&'a str, Size, TyAndLayout<'tcx>),
+    Padding(Size),
+}
+
+/// The members of the C struct of fields sorted by offset, with padding up to `size`.
+pub fn record_members<'a, 'tcx>(
+    fields: &'a [(String, Size, TyAndLayout<'tcx>)],
+    size: Size,
+) -> Vec<RecordMember<'a, 'tcx>> {
+    let mut members = Vec::with_capacity(fields.len());
+    let mut next_offset = Size::ZERO;
+    for (name, offset, field) in fields {
+        if *offset > next_offset {
+            members.push(RecordMember::Padding(*offset - next_offset));
+        }
+        members.push(RecordMember::Field(name, *offset, *field));
+        next_offset = *offset + field.size;
+    }
+    if size > next_offset {
+        members.push(RecordMember::Padding(size - next_offset));
+    }
+    members
+}
+
+/// The alignment a C record gets from its fields, which is one if it is packed.
+fn natural_align(fields: &[(String, Size, TyAndLayout<'_>)], packed: bool) -> Align {
+    if packed {
+        return Align::ONE;
+    }
+    fields.iter().map(|(_, _, field)| field.align.abi).max().unwrap_or(Align::ONE)
+}
+
+/// The alignment to declare on a record, if the alignment of its members falls short of the
+/// layout, e.g. for `#[repr(align(N))]` or packed records.
+fn extra_align(
+    layout: TyAndLayout<'_>,
+    member_aligns: impl IntoIterator<Item = Align>,
+) -> Option<u64> {
+    let natural = member_aligns.into_iter().max().unwrap_or(Align::ONE);
+    (layout.align.abi > natural).then(|| layout.align.abi.bytes())
+}
+
 impl<'tcx, 'mx> LayoutTypeMethods<'tcx> for CodegenCx<'tcx, 'mx> {
     fn backend_type(&self, layout: TyAndLayout<'tcx>) -> Self::Type {
         self.get_cty(layout, Conv::Rust)
@@ -129,24 +446,6 @@ impl<'tcx, 'mx> LayoutTypeMethods<'tcx> for CodegenCx<'tcx, 'mx> {
             _ => panic!("scalar_pair_element_backend_type({:?}): not applicable", layout),
         };
         let scalar = [a, b][index];
-
-        match scalar.primitive() {
-            Primitive::Int(int, true) => self.mcx.int(match int {
-                Integer::I8 => IntTy::I8,
-                Integer::I16 => IntTy::I16,
-                Integer::I32 => IntTy::I32,
-                Integer::I64 => IntTy::I64,
-                Integer::I128 => IntTy::I128,
-            }),
-            Primitive::Int(int, false) => self.mcx.uint(match int {
-                Integer::I8 => UintTy::U8,
-                Integer::I16 => UintTy::U16,
-                Integer::I32 => UintTy::U32,
-                Integer::I64 => UintTy::U64,
-                Integer::I128 => UintTy::U128,
-            }),
-            Primitive::Float(_) => todo!(),
-            Primitive::Pointer(_) => self.mcx.ptr(self.mcx.void()),
-        }
+        self.get_cty_primitive(scalar.primitive())
     }
 }
@@ -1,5 +1,6 @@
 use std::cell::RefCell;
 
+use rustc_codegen_c_ast::decl::CLinkage;
 use rustc_codegen_ssa::traits::{LayoutTypeMethods, MiscMethods};
 use rustc_hash::FxHashMap;
 use rustc_middle::mir::mono::CodegenUnit;
@@ -33,8 +34,10 @@ impl<'tcx, 'mx> MiscMethods<'tcx> for CodegenCx<'tcx, 'mx> {
         let mcx = self.mcx;
 
         let val = mcx.fn_ref(mcx.alloc_str(self.tcx.symbol_name(instance).name));
-        let ty = self.fn_decl_backend_type(self.fn_abi_of_instance(instance, ty::List::empty()));
-        mcx.module().push_decl(mcx.func(val, ty.fn_ptr().unwrap()));
+        let fn_abi = self.fn_abi_of_instance(instance, ty::List::empty());
+        let ty = self.fn_decl_backend_type(fn_abi);
+        let attrs = self.fn_attrs(instance, fn_abi);
+        mcx.module().push_decl(mcx.func(val, ty.fn_ptr().unwrap(), CLinkage::default(), attrs));
 
         self.function_declarations.borrow_mut().insert(instance, (val, ty).into());
 
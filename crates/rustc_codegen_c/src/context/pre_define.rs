@@ -1,11 +1,18 @@
+use rustc_attr::InlineAttr;
+use rustc_codegen_c_ast::decl::{CAttr, CLinkage};
+use rustc_codegen_c_ast::expr::CValue;
 use rustc_codegen_c_ast::func::CFuncKind;
+use rustc_codegen_ssa::mono_item::MonoItemExt;
 use rustc_codegen_ssa::traits::{LayoutTypeMethods, PreDefineMethods};
 use rustc_data_structures::intern::Interned;
 use rustc_hir::def_id::DefId;
-use rustc_middle::mir::mono::{Linkage, Visibility};
+use rustc_middle::middle::codegen_fn_attrs::{CodegenFnAttrFlags, CodegenFnAttrs};
+use rustc_middle::mir::mono::{Linkage, MonoItem, Visibility};
 use rustc_middle::ty::layout::FnAbiOf;
 use rustc_middle::ty::{self, Instance};
+use rustc_target::abi::call::FnAbi;
 
+use crate::builder::Builder;
 use crate::context::CodegenCx;
 
 impl<'tcx, 'mx> PreDefineMethods<'tcx> for CodegenCx<'tcx, 'mx> {
@@ -16,7 +23,12 @@ impl<'tcx, 'mx> PreDefineMethods<'tcx> for CodegenCx<'tcx, 'mx> {
         visibility: Visibility,
         symbol_name: &str,
     ) {
-        todo!()
+        assert!(!self.tcx.is_thread_local_static(def_id), "TODO: thread-local statics");
+
+        let ty = self.backend_type(self.static_layout(def_id));
+        let name = self.mcx.alloc_str(&symbol_name.replace('.', "_"));
+        let linkage = c_linkage(linkage, visibility);
+        self.statics.borrow_mut().insert(def_id, ((CValue::Static(name), ty).into(), linkage));
     }
 
     fn predefine_fn(
@@ -33,9 +45,89 @@ impl<'tcx, 'mx> PreDefineMethods<'tcx> for CodegenCx<'tcx, 'mx> {
         let fn_ptr = self.fn_decl_backend_type(fn_abi);
 
         let symbol_name = symbol_name.replace('.', "_");
-        let func = CFuncKind::new(self.mcx.alloc_str(&symbol_name), fn_ptr, is_main);
+        let func = CFuncKind::new(self.mcx.alloc_str(&symbol_name), fn_ptr, is_main)
+            .with_linkage(c_linkage(linkage, visibility))
+            .with_attrs(self.fn_attrs(instance, fn_abi));
         let func = Interned::new_unchecked(self.mcx.create_func(func));
         self.mcx.module().push_func(func);
         self.function_instances.borrow_mut().insert(instance, func);
     }
 }
+
+impl<'tcx, 'mx> CodegenCx<'tcx, 'mx> {
+    /// Declare the externally visible functions and statics of all codegen units of the crate,
+    /// for its header.
+    pub fn declare_exported_items(&self) {
+        let (_, cgus) = self.tcx.collect_and_partition_mono_items(());
+        for cgu in cgus {
+            for (mono_item, data) in cgu.items_in_deterministic_order(self.tcx) {
+                if !c_linkage(data.linkage, data.visibility).is_exported() {
+                    continue;
+                }
+                mono_item.predefine::<Builder<'_, '_, '_>>(self, data.linkage, data.visibility);
+                // a static is declared from its type, without evaluating its initializer
+                if let MonoItem::Static(def_id) = mono_item {
+                    let (var, linkage) = self.statics.borrow()[&def_id];
+                    let ty = self.static_decl_ty(def_id, var.ty());
+                    let mcx = self.mcx;
+                    mcx.module().push_decl(mcx.global_var(var.cval(), ty, None, linkage, []));
+                }
+            }
+        }
+    }
+
+    /// The GNU attributes of a function from its Rust attributes and return type.
+    pub fn fn_attrs(
+        &self,
+        instance: Instance<'tcx>,
+        fn_abi: &FnAbi<'tcx, ty::Ty<'tcx>>,
+    ) -> Vec<CAttr<'mx>> {
+        let attrs = self.tcx.codegen_fn_attrs(instance.def_id());
+        let mut c_attrs = Vec::new();
+        match attrs.inline {
+            InlineAttr::Never => c_attrs.push(CAttr::NoInline),
+            InlineAttr::Always => c_attrs.push(CAttr::AlwaysInline),
+            InlineAttr::Hint | InlineAttr::None => {}
+        }
+        if attrs.flags.contains(CodegenFnAttrFlags::COLD) {
+            c_attrs.push(CAttr::Cold);
+        }
+        if fn_abi.ret.layout.abi.is_uninhabited() {
+            c_attrs.push(CAttr::NoReturn);
+        }
+        self.push_placement_attrs(attrs, &mut c_attrs);
+        c_attrs
+    }
+
+    /// The GNU attributes of a static from its Rust attributes, e.g. `#[link_section]`.
+    pub fn static_attrs(&self, def_id: DefId) -> Vec<CAttr<'mx>> {
+        let mut c_attrs = Vec::new();
+        self.push_placement_attrs(self.tcx.codegen_fn_attrs(def_id), &mut c_attrs);
+        c_attrs
+    }
+
+    /// The attributes of functions and statics alike, on where and whether they are emitted.
+    fn push_placement_attrs(&self, attrs: &CodegenFnAttrs, c_attrs: &mut Vec<CAttr<'mx>>) {
+        if let Some(section) = attrs.link_section {
+            c_attrs.push(CAttr::Section(self.mcx.alloc_str(section.as_str())));
+        }
+        if attrs.flags.intersects(CodegenFnAttrFlags::USED | CodegenFnAttrFlags::USED_LINKER) {
+            c_attrs.push(CAttr::Used);
+        }
+        if let Some(align) = attrs.alignment {
+            c_attrs.push(CAttr::Aligned(align.bytes()));
+        }
+    }
+}
+
+/// The C storage class and attributes of a definition with the given rustc linkage.
+fn c_linkage(linkage: Linkage, visibility: Visibility) -> CLinkage {
+    let internal = matches!(linkage, Linkage::Internal | Linkage::Private);
+    let weak = matches!(
+        linkage,
+        Linkage::LinkOnceAny | Linkage::LinkOnceODR | Linkage::WeakAny | Linkage::WeakODR
+    );
+    // visibility is meaningless for `static` definitions
+    let hidden = !internal && visibility == Visibility::Hidden;
+    CLinkage { internal, weak, hidden, external: false }
+}
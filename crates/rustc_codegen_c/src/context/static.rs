@@ -1,8 +1,14 @@
 use rustc_abi::Align;
-use rustc_codegen_ssa::traits::StaticMethods;
+use rustc_codegen_c_ast::decl::CLinkage;
+use rustc_codegen_c_ast::expr::{CExpr, CValue};
+use rustc_codegen_c_ast::r#type::CTy;
+use rustc_codegen_ssa::traits::{LayoutTypeMethods, StaticMethods};
 use rustc_hir::def_id::DefId;
+use rustc_middle::ty::layout::TyAndLayout;
+use rustc_middle::ty::{Instance, ParamEnv};
+use rustc_target::abi::Size;
 
-use crate::context::CodegenCx;
+use crate::context::{CodegenCx, Value};
 
 impl<'tcx, 'mx> StaticMethods for CodegenCx<'tcx, 'mx> {
     fn static_addr_of(&self, cv: Self::Value, align: Align, kind: Option<&str>) -> Self::Value {
@@ -10,7 +16,18 @@ impl<'tcx, 'mx> StaticMethods for CodegenCx<'tcx, 'mx> {
     }
 
     fn codegen_static(&self, def_id: DefId) {
-        todo!()
+        let Ok(alloc) = self.tcx.eval_static_initializer(def_id) else {
+            // the error has already been reported
+            return;
+        };
+        let (var, linkage) = self.statics.borrow()[&def_id];
+
+        let init = self.const_init(alloc.inner(), Size::ZERO, self.static_layout(def_id));
+        let attrs = self.static_attrs(def_id);
+        let ty = self.static_decl_ty(def_id, var.ty());
+        let mcx = self.mcx;
+        mcx.module().push_decl(mcx.global_var(var.cval(), ty, Some(init), linkage, attrs));
+        self.declared_statics.borrow_mut().insert(def_id);
     }
 
     fn add_used_global(&self, global: Self::Value) {
@@ -21,3 +38,102 @@ impl<'tcx, 'mx> StaticMethods for CodegenCx<'tcx, 'mx> {
         todo!()
     }
 }
+
+impl<'tcx, 'mx> CodegenCx<'tcx, 'mx> {
+    /// The layout of the type of a static.
+    pub fn static_layout(&self, def_id: DefId) -> TyAndLayout<'tcx> {
+        self.layout_of(Instance::mono(self.tcx, def_id).ty(self.tcx, ParamEnv::reveal_all()))
+    }
+
+    /// Whether a static is declared `const`, i.e. it is neither `static mut` nor interior
+    /// mutable, e.g. `static FOO: u32` but not `static FOO: AtomicU32`. Foreign statics are
+    /// declared as is, since the C code defining them may well change them.
+    ///
+    /// The variable of a static keeps the unqualified type, so its address is cast to a plain
+    /// pointer where it is taken.
+    pub fn is_const_static(&self, def_id: DefId) -> bool {
+        !self.tcx.is_mutable_static(def_id)
+            && !self.tcx.is_foreign_item(def_id)
+            && self.static_layout(def_id).ty.is_freeze(self.tcx, ParamEnv::reveal_all())
+    }
+
+    /// The type a static is declared with, which is `const` if [`Self::is_const_static`].
+    pub fn static_decl_ty(&self, def_id: DefId, ty: CTy<'mx>) -> CTy<'mx> {
+        if self.is_const_static(def_id) {
+            self.mcx.const_obj(ty)
+        } else {
+            ty
+        }
+    }
+
+    /// The address of the variable of a static, as a pointer to its unqualified type.
+    pub fn static_var_addr(&self, def_id: DefId) -> CExpr<'mx> {
+        let mcx = self.mcx;
+        let var = self.static_var(def_id);
+        let addr = mcx.unary("&", mcx.value(var.cval()));
+        if self.is_const_static(def_id) {
+            mcx.cast(mcx.ptr(var.ty()), addr)
+        } else {
+            addr
+        }
+    }
+
+    /// The address of a static for the initializer of a global, e.g. `&FOO`, which still needs
+    /// a cast if the static is `const`. A static of this codegen unit that is not defined yet is
+    /// declared first, since C only refers to declared variables.
+    pub fn static_init_addr(&self, def_id: DefId) -> CExpr<'mx> {
+        let var = self.static_var(def_id);
+        if self.declared_statics.borrow_mut().insert(def_id) {
+            let (_, linkage) = self.statics.borrow()[&def_id];
+            // a `static` cannot be declared `extern`, but it can be tentatively defined
+            let linkage =
+                if linkage.internal { linkage } else { CLinkage { external: true, ..linkage } };
+            let ty = self.static_decl_ty(def_id, var.ty());
+            let mcx = self.mcx;
+            mcx.module().push_decl(mcx.global_var(var.cval(), ty, None, linkage, []));
+        }
+        self.mcx.unary("&", self.mcx.value(var.cval()))
+    }
+
+    /// The variable of a static, which is declared `extern` if it is not defined in this codegen
+    /// unit, e.g. a static of another crate.
+    pub fn static_var(&self, def_id: DefId) -> Value<'mx> {
+        if let Some(&(var, _)) = self.statics.borrow().get(&def_id) {
+            return var;
+        }
+        assert!(!self.tcx.is_thread_local_static(def_id), "TODO: thread-local statics");
+
+        let ty = self.backend_type(self.static_layout(def_id));
+        let name = self.tcx.symbol_name(Instance::mono(self.tcx, def_id)).name.replace('.', "_");
+        let var: Value<'mx> = (CValue::Static(self.mcx.alloc_str(&name)), ty).into();
+        let linkage = CLinkage { external: true, ..CLinkage::default() };
+
+        let mcx = self.mcx;
+        let decl_ty = self.static_decl_ty(def_id, ty);
+        mcx.module().push_decl(mcx.global_var(var.cval(), decl_ty, None, linkage, []));
+        self.statics.borrow_mut().insert(def_id, (var, linkage));
+        self.declared_statics.borrow_mut().insert(def_id);
+        var
+    }
+
+    /// A constant global holding the address of a static as a `ty`, for constants pointing to
+    /// the static, e.g. `static int32_t *const _g0 = &FOO;`.
+    pub fn static_addr(&self, def_id: DefId, ty: CTy<'mx>) -> CValue<'mx> {
+        if let Some(&addr) = self.static_addrs.borrow().get(&(def_id, ty)) {
+            return addr;
+        }
+
+        let mcx = self.mcx;
+        let var = self.static_var(def_id);
+        let mut init = self.static_init_addr(def_id);
+        if ty != mcx.ptr(var.ty()) || self.is_const_static(def_id) {
+            init = mcx.cast(ty, init);
+        }
+        let addr = mcx.next_global_var();
+        let linkage = CLinkage { internal: true, ..CLinkage::default() };
+        let decl = mcx.global_var(addr, ty.to_const_if(true), Some(init), linkage, []);
+        mcx.module().push_decl(decl);
+        self.static_addrs.borrow_mut().insert((def_id, ty), addr);
+        addr
+    }
+}
@@ -3,6 +3,7 @@
 extern crate parking_lot;
 extern crate rustc_abi;
 extern crate rustc_ast;
+extern crate rustc_attr;
 extern crate rustc_codegen_ssa;
 extern crate rustc_const_eval;
 extern crate rustc_data_structures;
@@ -19,12 +20,13 @@ extern crate rustc_target;
 extern crate rustc_type_ir;
 extern crate tracing;
 
-use std::env;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::{env, fs};
 
 use parking_lot::RwLock;
 use rustc_ast::expand::allocator::AllocatorKind;
+use rustc_codegen_c_ast::pretty::{CStd, SourceLocs};
 use rustc_codegen_ssa::back::link::link_binary;
 use rustc_codegen_ssa::back::lto::{LtoModuleCodegen, SerializedModule, ThinModule};
 use rustc_codegen_ssa::back::write::{
@@ -42,7 +44,7 @@ use rustc_metadata::EncodedMetadata;
 use rustc_middle::dep_graph::{WorkProduct, WorkProductId};
 use rustc_middle::ty::TyCtxt;
 use rustc_middle::util::Providers;
-use rustc_session::config::{OptLevel, OutputFilenames};
+use rustc_session::config::{DebugInfo, OptLevel, OutputFilenames};
 use rustc_session::Session;
 use rustc_span::ErrorGuaranteed;
 use tracing::warn;
@@ -64,11 +66,33 @@ pub struct BackendConfig {
     pub cc: PathBuf,
     /// Additional flags to pass to the C compiler. Environment variable: `CFLAGS`.
     pub cflags: Vec<String>,
+    /// Whether to check the generated C for well-formedness. Option: `verify=true`.
+    pub verify: bool,
+    /// How the Rust source locations of statements are printed, `#line` directives by default
+    /// if debug info is enabled. Option: `source-locs=off|comments|lines`.
+    pub source_locs: SourceLocs,
+    /// The directory to dump the AST of each codegen unit to as JSON, before it is printed.
+    /// Option: `dump-ast=<dir>`.
+    pub dump_ast: Option<PathBuf>,
+    /// The C standard of the generated C, which is also passed to the C compiler as `-std=`.
+    /// Option: `c-std=c89|c99|c11|c17|c23`.
+    pub c_std: CStd,
+    /// Whether to write a C header `<crate>.h` next to the output, declaring the externally
+    /// visible functions and variables of the crate. Option: `header=true`.
+    pub header: bool,
 }
 
 impl Default for BackendConfig {
     fn default() -> Self {
-        BackendConfig { cc: "clang".into(), cflags: vec![] }
+        BackendConfig {
+            cc: "clang".into(),
+            cflags: vec![],
+            verify: false,
+            source_locs: SourceLocs::Off,
+            dump_ast: None,
+            c_std: CStd::C11,
+            header: false,
+        }
     }
 }
 
@@ -87,6 +111,29 @@ impl BackendConfig {
                 match key {
                     "cc" => self.cc = value.into(),
                     "cflags" => self.cflags = value.split(' ').map(String::from).collect(),
+                    "verify" => match value.parse() {
+                        Ok(verify) => self.verify = verify,
+                        Err(_) => warn!("invalid value of option `{}`", opt),
+                    },
+                    "source-locs" => match value {
+                        "off" => self.source_locs = SourceLocs::Off,
+                        "comments" => self.source_locs = SourceLocs::Comments,
+                        "lines" => self.source_locs = SourceLocs::Lines,
+                        _ => warn!("invalid value of option `{}`", opt),
+                    },
+                    "dump-ast" => self.dump_ast = Some(value.into()),
+                    "header" => match value.parse() {
+                        Ok(header) => self.header = header,
+                        Err(_) => warn!("invalid value of option `{}`", opt),
+                    },
+                    "c-std" => match value {
+                        "c89" => self.c_std = CStd::C89,
+                        "c99" => self.c_std = CStd::C99,
+                        "c11" => self.c_std = CStd::C11,
+                        "c17" => self.c_std = CStd::C17,
+                        "c23" => self.c_std = CStd::C23,
+                        _ => warn!("invalid value of option `{}`", opt),
+                    },
                     _ => warn!("unknown option `{}`", opt),
                 }
             } else {
@@ -112,7 +159,11 @@ impl CodegenBackend for CCodegen {
     }
 
     fn init(&self, sess: &Session) {
-        self.config.write().load_env(&sess.opts.cg.llvm_args)
+        let mut config = self.config.write();
+        if sess.opts.debuginfo != DebugInfo::None {
+            config.source_locs = SourceLocs::Lines;
+        }
+        config.load_env(&sess.opts.cg.llvm_args)
     }
 
     fn provide(&self, providers: &mut Providers) {
@@ -133,6 +184,15 @@ impl CodegenBackend for CCodegen {
 
         let ongoing_codegen =
             codegen_crate(self.clone(), tcx, target_cpu, metadata, need_metadata_module);
+
+        let config = self.config.read().clone();
+        if config.header {
+            let path = tcx.output_filenames(()).with_extension("h");
+            if let Err(err) = fs::write(&path, base::codegen_header(tcx, &config)) {
+                tcx.dcx().fatal(format!("failed to write the header `{}`: {err}", path.display()));
+            }
+        }
+
         Box::new(ongoing_codegen)
     }
 
@@ -1,12 +1,14 @@
+use std::borrow::Cow;
 use std::fs;
 use std::io::Write;
 use std::process::Stdio;
 
+use rustc_codegen_c_ast::pretty::{resolve_line_resets, CStd, SourceLocs};
 use rustc_codegen_ssa::back::command::Command;
 use rustc_codegen_ssa::back::write::{CodegenContext, ModuleConfig};
 use rustc_codegen_ssa::{CompiledModule, ModuleCodegen};
 use rustc_errors::{DiagCtxtHandle, FatalError};
-use rustc_session::config::{OptLevel, OutputType};
+use rustc_session::config::{DebugInfo, OptLevel, OutputType};
 use tracing::error;
 
 use crate::CodegenModule;
@@ -26,14 +28,28 @@ pub(crate) unsafe fn codegen(
 
     // output c source code
     let c_out_file = fs::File::create(&c_out).map_err(|_| FatalError)?;
-    writeln!(&c_out_file, "// file: {}.c", module.name).map_err(|_| FatalError)?;
-    write!(&c_out_file, "{}", module.module_llvm.module_source).map_err(|_| FatalError)?;
+    writeln!(&c_out_file, "/* file: {}.c */", module.name).map_err(|_| FatalError)?;
+    let mut source = Cow::from(&module.module_llvm.module_source);
+    if backend_config.source_locs == SourceLocs::Lines {
+        // the source starts on the line after the header
+        source = resolve_line_resets(&source, &c_out.to_string_lossy(), 2).into();
+    }
+    write!(&c_out_file, "{}", source).map_err(|_| FatalError)?;
 
     // invoke cc to compile
     // TODO: handle long command line (windows)
     // TODO: flush_linked_file (windows)
     let mut cmd = Command::new(backend_config.cc.clone());
-    cmd.arg(&c_out).arg("-o").arg(&obj_out).arg("-c").args(&backend_config.cflags);
+    cmd.arg(&c_out).arg("-o").arg(&obj_out).arg("-c");
+    cmd.arg(match backend_config.c_std {
+        CStd::C89 => "-std=c89",
+        CStd::C99 => "-std=c99",
+        CStd::C11 => "-std=c11",
+        CStd::C17 => "-std=c17",
+        // the name before GCC 14 and Clang 18
+        CStd::C23 => "-std=c2x",
+    });
+    cmd.args(&backend_config.cflags);
     if let Some(opt_level) = module_config.opt_level {
         cmd.arg(match opt_level {
             OptLevel::No => "-O0",
@@ -44,6 +60,9 @@ pub(crate) unsafe fn codegen(
             OptLevel::SizeMin => "-Oz",
         });
     }
+    if cgcx.opts.debuginfo != DebugInfo::None {
+        cmd.arg("-g");
+    }
 
     let mut cmd = cmd.command();
 
@@ -0,0 +1,150 @@
+//! Cleanup of the control flow graph of functions.
+//!
+//! The builder creates a basic block for every MIR block, many of which end up as a lone
+//! `goto` after constant folding, or are not reachable at all. [`simplify_cfg`] threads jumps
+//! through blocks that only jump elsewhere and removes the blocks that cannot be reached from
+//! the entry block.
+//!
+//! A block that does not end with a jump continues with the block printed after it, so blocks
+//! are never reordered.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::expr::CExprKind;
+use crate::func::{CBasicBlock, CFunc, CFuncKind};
+use crate::stmt::{CStmt, CStmtKind};
+use crate::visit::{walk_func_mut, walk_stmt, Folder, Visitor};
+use crate::ModuleCtxt;
+
+/// Simplify the control flow of the functions of a module.
+pub fn simplify_cfg(mcx: ModuleCtxt<'_>) {
+    for &func in mcx.module().funcs.borrow().iter() {
+        thread_jumps(mcx, func);
+        remove_unreachable(func.0);
+    }
+}
+
+/// Redirect jumps to blocks that consist of a single `goto` to the final target.
+fn thread_jumps<'mx>(mcx: ModuleCtxt<'mx>, func: CFunc<'mx>) {
+    let blocks = func.0.basic_blocks();
+    let forwards: FxHashMap<_, _> = blocks
+        .iter()
+        .skip(1)
+        .filter_map(|bb| match bb.stmts.borrow()[..] {
+            [CStmtKind::Goto(target)] => Some((bb.label, *target)),
+            _ => None,
+        })
+        .collect();
+    if forwards.is_empty() {
+        return;
+    }
+
+    let mut targets = FxHashMap::default();
+    for &label in forwards.keys() {
+        let mut seen = FxHashSet::default();
+        let mut target = label;
+        while let Some(&next) = forwards.get(target) {
+            // a cycle of jumps is an infinite loop, which is kept as is
+            if !seen.insert(target) {
+                target = label;
+                break;
+            }
+            target = next;
+        }
+        targets.insert(label, target);
+    }
+    walk_func_mut(&mut Thread { mcx, targets }, func);
+}
+
+struct Thread<'mx> {
+    mcx: ModuleCtxt<'mx>,
+    targets: FxHashMap<&'mx str, &'mx str>,
+}
+
+impl<'mx> Folder<'mx> for Thread<'mx> {
+    fn mcx(&self) -> ModuleCtxt<'mx> {
+        self.mcx
+    }
+
+    fn fold_label(&mut self, label: &'mx str) -> &'mx str {
+        self.targets.get(label).copied().unwrap_or(label)
+    }
+}
+
+/// Remove the blocks that are not reachable from the entry block.
+fn remove_unreachable(func: &CFuncKind<'_>) {
+    let blocks = func.basic_blocks();
+    let Some(entry) = blocks.first() else { return };
+    let index: FxHashMap<_, _> = blocks.iter().enumerate().map(|(i, bb)| (bb.label, i)).collect();
+
+    let mut reachable = FxHashSet::default();
+    let mut stack = vec![entry.label];
+    while let Some(label) = stack.pop() {
+        if !reachable.insert(label) {
+            continue;
+        }
+        let i = index[label];
+        stack.extend(successors(blocks[i]));
+        if falls_through(blocks[i]) {
+            stack.extend(blocks.get(i + 1).map(|bb| bb.label));
+        }
+    }
+    func.retain_basic_blocks(|bb| reachable.contains(bb.label));
+}
+
+/// The labels jumped to by the statements of a block.
+fn successors<'mx>(bb: &CBasicBlock<'mx>) -> Vec<&'mx str> {
+    targets(&bb.stmts.borrow())
+}
+
+/// The labels jumped to by statements.
+pub(crate) fn targets<'mx>(stmts: &[CStmt<'mx>]) -> Vec<&'mx str> {
+    let mut targets = Targets::default();
+    for &stmt in stmts {
+        targets.visit_stmt(stmt);
+    }
+    targets.0
+}
+
+/// The labels jumped to in a function, which are the only labels that need to be printed.
+pub(crate) fn jump_targets<'mx>(func: &CFuncKind<'mx>) -> FxHashSet<&'mx str> {
+    func.basic_blocks().into_iter().flat_map(successors).collect()
+}
+
+/// Whether control can reach the end of a block and continue with the next one.
+fn falls_through(bb: &CBasicBlock<'_>) -> bool {
+    bb.stmts.borrow().last().map_or(true, |&stmt| !is_jump(stmt))
+}
+
+/// Whether a statement never completes normally.
+pub(crate) fn is_jump(stmt: CStmt<'_>) -> bool {
+    match stmt {
+        CStmtKind::Goto(_)
+        | CStmtKind::Return(_)
+        | CStmtKind::Switch { .. }
+        | CStmtKind::Break
+        | CStmtKind::Continue => true,
+        // the lowering of `unreachable`
+        CStmtKind::Expr(CExprKind::Call { callee: CExprKind::Raw("abort"), .. }) => true,
+        CStmtKind::If { then_br, else_br: Some(else_br), .. } => {
+            is_jump(then_br) && is_jump(else_br)
+        }
+        CStmtKind::Block(stmts) => stmts.last().is_some_and(|stmt| is_jump(stmt)),
+        _ => false,
+    }
+}
+
+#[derive(Default)]
+struct Targets<'mx>(Vec<&'mx str>);
+
+impl<'mx> Visitor<'mx> for Targets<'mx> {
+    fn visit_stmt(&mut self, stmt: CStmt<'mx>) {
+        if !matches!(stmt, CStmtKind::Label(_)) {
+            walk_stmt(self, stmt)
+        }
+    }
+
+    fn visit_label(&mut self, label: &'mx str) {
+        self.0.push(label);
+    }
+}
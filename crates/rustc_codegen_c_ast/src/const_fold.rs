@@ -0,0 +1,261 @@
+//! Constant folding of integer expressions.
+//!
+//! The builder defines a fresh local for the result of every operation, so constant operands
+//! such as `size_of::<T>()` after monomorphization end up as chains of locals like
+//! `size_t _3 = 4 * 2; size_t _4 = _3 + 1;`. [`fold_consts`] evaluates such definitions, removes
+//! them and propagates the constants into their uses.
+//!
+//! Expressions are evaluated with the semantics of the C they are printed as, except that
+//! signed overflow wraps around as in Rust. Integer constants are represented as
+//! [`CValue::Scalar`] holding the value of the type, i.e. sign-extended for signed types.
+//! 128-bit integers, `char` and floating-point types are not folded.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::decl::CDeclKind;
+use crate::expr::{CExpr, CExprKind, CValue};
+use crate::func::CFunc;
+use crate::r#type::{CPTy, CTy, CTyBase};
+use crate::stmt::{CStmt, CStmtKind};
+use crate::visit::{walk_expr, walk_expr_mut, walk_func_mut, Folder, Visitor};
+use crate::ModuleCtxt;
+
+/// Evaluates integer expressions whose operands are constants.
+#[derive(Clone, Copy)]
+pub struct ConstEval {
+    /// The width of `size_t` and `ptrdiff_t` in bits.
+    pub ptr_width: u64,
+}
+
+impl ConstEval {
+    /// Evaluate an expression of type `ty`, if it is constant.
+    pub fn eval<'mx>(self, expr: CExpr<'mx>, ty: CTy<'mx>) -> Option<CValue<'mx>> {
+        self.int(expr, ty).map(CValue::Scalar)
+    }
+
+    fn int(self, expr: CExpr<'_>, ty: CTy<'_>) -> Option<i128> {
+        let value = match *expr {
+            CExprKind::Unary { op: "-", expr } => self.int(expr, ty)?.wrapping_neg(),
+            CExprKind::Unary { op: "~", expr } => !self.int(expr, ty)?,
+            CExprKind::Unary { op: "!", expr } => (self.operand(expr)? == 0).into(),
+            CExprKind::Binary { lhs, rhs, op } => match op {
+                "==" | "!=" | "<" | ">" | "<=" | ">=" | "&&" | "||" => {
+                    let (lhs, rhs) = (self.operand(lhs)?, self.operand(rhs)?);
+                    compare(op, lhs, rhs)?.into()
+                }
+                _ => self.arith(op, self.int(lhs, ty)?, self.int(rhs, ty)?, ty)?,
+            },
+            CExprKind::Ternary { cond, then_expr, else_expr } => {
+                let branch = if self.operand(cond)? != 0 { then_expr } else { else_expr };
+                self.int(branch, ty)?
+            }
+            // the integer cast to a signed type of `__rust_utos(U, S, x, MAX)`
+            CExprKind::Call { callee: CExprKind::Raw("__rust_utos"), ref args }
+                if args.len() == 4 =>
+            {
+                self.operand(args[2])?
+            }
+            _ => self.operand(expr)?,
+        };
+        self.normalize(value, ty)
+    }
+
+    /// The value of a constant operand, whose type is given by the operand itself.
+    fn operand(self, expr: CExpr<'_>) -> Option<i128> {
+        match *expr {
+            CExprKind::Value(CValue::Scalar(value)) => Some(value),
+            CExprKind::Cast { ty, expr } => self.normalize(self.operand(expr)?, ty),
+            _ => None,
+        }
+    }
+
+    fn arith(self, op: &str, lhs: i128, rhs: i128, ty: CTy<'_>) -> Option<i128> {
+        let value = match op {
+            "+" => lhs.wrapping_add(rhs),
+            "-" => lhs.wrapping_sub(rhs),
+            "*" => lhs.wrapping_mul(rhs),
+            "/" if rhs != 0 => lhs.wrapping_div(rhs),
+            "%" if rhs != 0 => lhs.wrapping_rem(rhs),
+            "&" => lhs & rhs,
+            "|" => lhs | rhs,
+            "^" => lhs ^ rhs,
+            // operands narrower than `int` are promoted, and shifting by the width is undefined
+            "<<" | ">>" => {
+                let width = self.width(ty)?.max(32);
+                if !(0..width.into()).contains(&rhs) {
+                    return None;
+                }
+                if op == "<<" {
+                    lhs.wrapping_shl(rhs as u32)
+                } else {
+                    lhs >> rhs
+                }
+            }
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    /// Convert a value to the type as C does, wrapping around for integers.
+    fn normalize(self, value: i128, ty: CTy<'_>) -> Option<i128> {
+        if ty.base == CTyBase::Primitive(CPTy::Bool) {
+            return Some((value != 0).into());
+        }
+        let width = self.width(ty)?;
+        let shift = 128 - width;
+        if ty.is_signed() {
+            Some(value << shift >> shift)
+        } else {
+            Some(((value as u128) << shift >> shift) as i128)
+        }
+    }
+
+    /// The width of an integer type that is folded.
+    fn width(self, ty: CTy<'_>) -> Option<u32> {
+        let CTyBase::Primitive(ty) = ty.base else { return None };
+        let width = match ty {
+            CPTy::Bool | CPTy::I8 | CPTy::U8 => 8,
+            CPTy::I16 | CPTy::U16 => 16,
+            CPTy::I32 | CPTy::U32 => 32,
+            CPTy::I64 | CPTy::U64 => 64,
+            CPTy::Isize | CPTy::Usize => self.ptr_width as u32,
+            CPTy::Void | CPTy::Char | CPTy::I128 | CPTy::U128 | CPTy::F32 | CPTy::F64 => {
+                return None;
+            }
+        };
+        Some(width)
+    }
+}
+
+fn compare(op: &str, lhs: i128, rhs: i128) -> Option<bool> {
+    let value = match op {
+        "==" => lhs == rhs,
+        "!=" => lhs != rhs,
+        "<" => lhs < rhs,
+        ">" => lhs > rhs,
+        "<=" => lhs <= rhs,
+        ">=" => lhs >= rhs,
+        "&&" => lhs != 0 && rhs != 0,
+        "||" => lhs != 0 || rhs != 0,
+        _ => return None,
+    };
+    Some(value)
+}
+
+/// Fold the constant definitions of locals in the functions of a module.
+pub fn fold_consts(mcx: ModuleCtxt<'_>, eval: ConstEval) {
+    for &func in mcx.module().funcs.borrow().iter() {
+        let mut assigned = Assigned::default();
+        assigned.visit_func(func);
+
+        let mut folder = ConstFolder {
+            mcx,
+            eval,
+            assigned: assigned.0,
+            consts: FxHashMap::default(),
+            changed: false,
+        };
+        folder.fold_func(func);
+    }
+}
+
+/// Locals that are assigned or have their address taken, which cannot be replaced by their
+/// initial value.
+#[derive(Default)]
+struct Assigned<'mx>(FxHashSet<CValue<'mx>>);
+
+impl<'mx> Visitor<'mx> for Assigned<'mx> {
+    fn visit_expr(&mut self, expr: CExpr<'mx>) {
+        match *expr {
+            CExprKind::Binary { lhs: CExprKind::Value(value), op: "=", .. }
+            | CExprKind::Unary { op: "&", expr: CExprKind::Value(value) } => {
+                self.0.insert(*value);
+            }
+            _ => {}
+        }
+        walk_expr(self, expr)
+    }
+}
+
+struct ConstFolder<'mx> {
+    mcx: ModuleCtxt<'mx>,
+    eval: ConstEval,
+    assigned: FxHashSet<CValue<'mx>>,
+    consts: FxHashMap<CValue<'mx>, (CValue<'mx>, CTy<'mx>)>,
+    changed: bool,
+}
+
+impl<'mx> ConstFolder<'mx> {
+    /// Fold a statement, or remove it if it defines a constant or is a branch not taken.
+    fn fold_stmt_opt(&mut self, stmt: CStmt<'mx>) -> Option<CStmt<'mx>> {
+        let mcx = self.mcx;
+        let stmt = self.fold_stmt(stmt);
+        match *stmt {
+            CStmtKind::Decl(&CDeclKind::Var { name, ty, init: Some(init), .. })
+                if matches!(name, CValue::Local(_))
+                    && ty.quals.is_empty()
+                    && !self.assigned.contains(&name) =>
+            {
+                let Some(value) = self.eval.eval(init, ty) else { return Some(stmt) };
+                self.consts.insert(name, (value, ty));
+                self.changed = true;
+                None
+            }
+            CStmtKind::If { cond, then_br, else_br } => match self.eval.operand(cond) {
+                Some(0) => else_br,
+                Some(_) => Some(then_br),
+                None => Some(stmt),
+            },
+            CStmtKind::Switch { value, ref cases, default } => {
+                let Some(value) = self.eval.operand(value) else { return Some(stmt) };
+                let label = cases
+                    .iter()
+                    .find(|&&(case, _)| self.eval.operand(case) == Some(value))
+                    .map_or(default, |&(_, label)| label);
+                let goto = mcx.goto(label);
+                mcx.copy_loc(stmt, goto);
+                Some(goto)
+            }
+            _ => Some(stmt),
+        }
+    }
+}
+
+impl<'mx> Folder<'mx> for ConstFolder<'mx> {
+    fn mcx(&self) -> ModuleCtxt<'mx> {
+        self.mcx
+    }
+
+    /// Fold until no more constants are found, since a constant may be used in a block before
+    /// the one defining it.
+    fn fold_func(&mut self, func: CFunc<'mx>) {
+        loop {
+            self.changed = false;
+            walk_func_mut(self, func);
+            if !self.changed {
+                break;
+            }
+        }
+    }
+
+    fn fold_stmts(&mut self, stmts: Vec<CStmt<'mx>>) -> Vec<CStmt<'mx>> {
+        stmts.into_iter().filter_map(|stmt| self.fold_stmt_opt(stmt)).collect()
+    }
+
+    fn fold_expr(&mut self, expr: CExpr<'mx>) -> CExpr<'mx> {
+        // the left operand determines the type of a shift, so the constant keeps its type
+        if let CExprKind::Binary { lhs: CExprKind::Value(lhs), rhs, op: op @ ("<<" | ">>") } = *expr
+        {
+            if let Some(&(value, ty)) = self.consts.get(lhs) {
+                let lhs = self.mcx.cast(ty, self.mcx.value(value));
+                let rhs = self.fold_expr(rhs);
+                return self.mcx.binary(lhs, rhs, op);
+            }
+        }
+        walk_expr_mut(self, expr)
+    }
+
+    fn fold_value(&mut self, value: CValue<'mx>) -> CValue<'mx> {
+        self.consts.get(&value).map_or(value, |&(value, _)| value)
+    }
+}
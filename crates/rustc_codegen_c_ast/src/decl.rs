@@ -1,41 +1,104 @@
-use crate::expr::{CExpr, CValue};
-use crate::pretty::{Printer, INDENT};
+use crate::expr::{str_literal, CExpr, CValue};
+use crate::pretty::{CStd, Printer};
 use crate::r#type::{CFnPtr, CTy};
 use crate::ModuleCtxt;
 
 pub type CDecl<'mx> = &'mx CDeclKind<'mx>;
 
+/// The storage class and linkage attributes of a function or global variable.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CLinkage {
+    /// `static`, i.e. internal linkage.
+    pub internal: bool,
+    /// `__attribute__((weak))`, so that duplicate definitions are merged by the linker.
+    pub weak: bool,
+    /// `__attribute__((visibility("hidden")))`, i.e. not exported from a shared object.
+    pub hidden: bool,
+    /// `extern`, i.e. a declaration of a variable defined elsewhere.
+    pub external: bool,
+}
+
+impl CLinkage {
+    /// Whether a definition is visible to code linked with the module, other than as one of
+    /// the merged copies of a weak definition.
+    pub fn is_exported(self) -> bool {
+        !self.internal && !self.weak && !self.hidden && !self.external
+    }
+}
+
+/// A GNU attribute of a function or global variable, printed in `__attribute__((...))`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CAttr<'mx> {
+    NoInline,
+    AlwaysInline,
+    Cold,
+    NoReturn,
+    Section(&'mx str),
+    Used,
+    Aligned(u64),
+}
+
 #[derive(Debug, Clone)]
 pub enum CDeclKind<'mx> {
     // Typedef { name: String, ty: CType },
     // Record { name: String, fields: Vec<CDecl> },
     // Field { name: String, ty: CType },
     // Enum { name: String, values: Vec<CEnumConstant> },
-    Var { name: CValue<'mx>, ty: CTy<'mx>, init: Option<CExpr<'mx>> },
-    Func { name: CValue<'mx>, fn_ptr: &'mx CFnPtr<'mx> },
+    Var {
+        name: CValue<'mx>,
+        ty: CTy<'mx>,
+        init: Option<CExpr<'mx>>,
+        linkage: CLinkage,
+        attrs: Box<[CAttr<'mx>]>,
+    },
+    Func {
+        name: CValue<'mx>,
+        fn_ptr: &'mx CFnPtr<'mx>,
+        linkage: CLinkage,
+        attrs: Box<[CAttr<'mx>]>,
+    },
 }
 
 impl<'mx> ModuleCtxt<'mx> {
-    fn create_decl(self, decl: CDeclKind<'mx>) -> CDecl<'mx> {
+    pub fn create_decl(self, decl: CDeclKind<'mx>) -> CDecl<'mx> {
         self.arena().alloc(decl)
     }
 
     pub fn var(self, name: CValue<'mx>, ty: CTy<'mx>, init: Option<CExpr<'mx>>) -> CDecl<'mx> {
-        self.create_decl(CDeclKind::Var { name, ty, init })
+        let (linkage, attrs) = (CLinkage::default(), Box::default());
+        self.create_decl(CDeclKind::Var { name, ty, init, linkage, attrs })
     }
 
-    pub fn func(self, name: CValue<'mx>, fn_ptr: &'mx CFnPtr<'mx>) -> CDecl<'mx> {
-        self.create_decl(CDeclKind::Func { name, fn_ptr })
+    pub fn global_var(
+        self,
+        name: CValue<'mx>,
+        ty: CTy<'mx>,
+        init: Option<CExpr<'mx>>,
+        linkage: CLinkage,
+        attrs: impl Into<Box<[CAttr<'mx>]>>,
+    ) -> CDecl<'mx> {
+        self.create_decl(CDeclKind::Var { name, ty, init, linkage, attrs: attrs.into() })
+    }
+
+    pub fn func(
+        self,
+        name: CValue<'mx>,
+        fn_ptr: &'mx CFnPtr<'mx>,
+        linkage: CLinkage,
+        attrs: impl Into<Box<[CAttr<'mx>]>>,
+    ) -> CDecl<'mx> {
+        self.create_decl(CDeclKind::Func { name, fn_ptr, linkage, attrs: attrs.into() })
     }
 }
 
 impl Printer {
     pub fn print_decl(&mut self, decl: CDecl, trailing_semicolon: bool) {
-        match *decl {
-            CDeclKind::Var { name, ty, init } => {
-                self.ibox(INDENT, |this| {
-                    this.print_ty_decl(ty, Some(name));
-                    if let Some(init) = init {
+        match decl {
+            CDeclKind::Var { name, ty, init, linkage, attrs } => {
+                self.ibox(self.indent, |this| {
+                    this.print_decl_specs(*linkage, attrs);
+                    this.print_ty_decl(*ty, Some(*name));
+                    if let Some(init) = *init {
                         this.word(" =");
                         this.softbreak();
                         this.print_expr(init, true);
@@ -45,8 +108,9 @@ impl Printer {
                     }
                 });
             }
-            CDeclKind::Func { name, fn_ptr } => {
+            CDeclKind::Func { name, fn_ptr, linkage, attrs } => {
                 let CValue::Func(name) = name else { unreachable!() };
+                self.print_decl_specs(*linkage, attrs);
                 self.print_signature(fn_ptr.ret, name, &fn_ptr.args, None);
                 if trailing_semicolon {
                     self.word(";");
@@ -54,4 +118,44 @@ impl Printer {
             }
         }
     }
+
+    /// Print the storage class and attributes preceding a declaration, if any. The attributes
+    /// come first in C23, which has them at the start of the declaration.
+    pub fn print_decl_specs(&mut self, linkage: CLinkage, attrs: &[CAttr]) {
+        let c23 = self.config().c_std >= CStd::C23;
+        if !c23 {
+            self.print_storage_class(linkage);
+        }
+        let mut specs = Vec::new();
+        if linkage.weak {
+            specs.push("weak".to_string());
+        }
+        if linkage.hidden {
+            specs.push("visibility(\"hidden\")".to_string());
+        }
+        specs.extend(attrs.iter().map(|attr| match attr {
+            CAttr::NoInline => "noinline".to_string(),
+            CAttr::AlwaysInline => "always_inline".to_string(),
+            CAttr::Cold => "cold".to_string(),
+            CAttr::NoReturn => "noreturn".to_string(),
+            CAttr::Section(section) => format!("section({})", str_literal(section.as_bytes())),
+            CAttr::Used => "used".to_string(),
+            CAttr::Aligned(align) => format!("aligned({align})"),
+        }));
+        if !specs.is_empty() {
+            self.word(format!("{} ", self.attr_spec(&specs)));
+        }
+        if c23 {
+            self.print_storage_class(linkage);
+        }
+    }
+
+    fn print_storage_class(&mut self, linkage: CLinkage) {
+        if linkage.internal {
+            self.word("static ");
+        }
+        if linkage.external {
+            self.word("extern ");
+        }
+    }
 }
@@ -0,0 +1,433 @@
+//! JSON dump of a module, for inspecting the AST independently of the printer.
+//!
+//! The schema is versioned by the `version` field of the module and only changes with it. Every
+//! node is an object, and nodes with variants are tagged by their `kind`:
+//!
+//! - module: `version`, `global_prefix`, `includes`, `records`, `static_asserts` (exprs),
+//!   `decls`, `funcs`
+//! - record: `kind` (`struct` or `union`), `name`, `packed`, `align` (number or null), `fields`
+//!   (array of `name` and `ty`, or null if the record is incomplete)
+//! - ty: `primitive` (`name`), `pointer` (`pointee`), `record` (`name`), `array` (`elem`, `len`
+//!   or null) or `fn_ptr` (`ret`, `args`, `abi`), with `quals` (`const`, `volatile`,
+//!   `restrict`) if any
+//! - value: `null`, `scalar`, `i128` or `u128` (`value`), `f32` or `f64` (`bits`), `local` or
+//!   `global` (`index`), `func` or `static` (`name`)
+//! - expr: `raw` (`text`), `value` (`value`), `unary` (`op`, `expr`), `binary` (`op`, `lhs`,
+//!   `rhs`), `ternary` (`cond`, `then`, `else`), `index` (`expr`, `index`), `cast` (`ty`,
+//!   `expr`), `call` (`callee`, `args`), `member` (`expr`, `arrow`, `field`), `init_list`
+//!   (`exprs`), `designated` (`field`, `expr`), `str` (`bytes`), `size_of` or `align_of`
+//!   (`ty`), `offset_of` (`ty`, `field`)
+//! - decl: `var` (`name`, `ty`, `init` or null) or `func` (`name`, `ty`), with `linkage`
+//!   (`internal`, `weak`, `hidden`, `external`) and `attrs` (`noinline`, `always_inline`, `cold`, `noreturn`,
+//!   `section` with `name`, `used`, `aligned` with `align`)
+//! - func: `name`, `ty`, `params` (values), `main`, `linkage`, `attrs`, `local_names` (array of
+//!   `index` and `name`), `allocs` (array of `value`, `ty` or null if not realized, `fallback`
+//!   and `align` or null), `blocks` (array of `label` and `stmts`)
+//! - stmt: `if` (`cond`, `then`, `else` or null), `for` (`init`, `cond`, `next`, `body`),
+//!   `return` (`expr` or null), `decl` (`decl`), `expr` (`expr`), `goto` (`label`), `switch`
+//!   (`value`, `cases` as array of `value` and `label`, `default`), `block` (`stmts`), `while`
+//!   (`cond`, `body`), `do_while` (`body`, `cond`), `break`, `continue` or `label` (`label`),
+//!   with `loc` (`file`, `line`) if it has a source location
+
+use std::fmt::{self, Display, Write};
+
+use crate::decl::{CAttr, CDecl, CDeclKind, CLinkage};
+use crate::expr::{CExpr, CExprKind, CValue};
+use crate::func::{CBasicBlock, CFunc, PendingAlloc};
+use crate::module::Module;
+use crate::r#type::{CFnPtr, CRecord, CTy, CTyBase, CTyKind, CTyQuals};
+use crate::stmt::{stmt_key, CStmt, CStmtKind};
+
+/// The version of the schema.
+const VERSION: u32 = 4;
+
+/// Dump a module as JSON.
+pub fn dump_module(module: &Module<'_>) -> String {
+    let json = Dumper { module }.module();
+    let mut out = String::new();
+    json.write(&mut out, 0).unwrap();
+    out.push('\n');
+    out
+}
+
+enum Json {
+    Null,
+    Bool(bool),
+    /// A number, printed as is.
+    Num(String),
+    Str(String),
+    Array(Vec<Json>),
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl Json {
+    fn num(n: impl Display) -> Json {
+        Json::Num(n.to_string())
+    }
+
+    fn str(s: impl Into<String>) -> Json {
+        Json::Str(s.into())
+    }
+
+    fn array(elems: impl IntoIterator<Item = Json>) -> Json {
+        Json::Array(elems.into_iter().collect())
+    }
+
+    fn opt<T>(value: Option<T>, f: impl FnOnce(T) -> Json) -> Json {
+        value.map_or(Json::Null, f)
+    }
+
+    /// An object tagged by its `kind`.
+    fn tagged(kind: &'static str, fields: impl IntoIterator<Item = (&'static str, Json)>) -> Json {
+        Json::Object([("kind", Json::str(kind))].into_iter().chain(fields).collect())
+    }
+
+    /// Add a field to an object.
+    fn push(&mut self, key: &'static str, value: Json) {
+        let Json::Object(fields) = self else { panic!("field `{key}` added to a non-object") };
+        fields.push((key, value));
+    }
+
+    /// Write the value with each element of an array or object on its own line.
+    fn write(&self, out: &mut String, indent: usize) -> fmt::Result {
+        match self {
+            Json::Null => out.write_str("null"),
+            Json::Bool(b) => write!(out, "{b}"),
+            Json::Num(n) => out.write_str(n),
+            Json::Str(s) => write_str(out, s),
+            Json::Array(elems) if elems.is_empty() => out.write_str("[]"),
+            Json::Array(elems) => {
+                out.write_str("[")?;
+                for (i, elem) in elems.iter().enumerate() {
+                    out.write_str(if i == 0 { "\n" } else { ",\n" })?;
+                    write!(out, "{:1$}", "", indent + 2)?;
+                    elem.write(out, indent + 2)?;
+                }
+                write!(out, "\n{:1$}]", "", indent)
+            }
+            Json::Object(fields) if fields.is_empty() => out.write_str("{}"),
+            Json::Object(fields) => {
+                out.write_str("{")?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    out.write_str(if i == 0 { "\n" } else { ",\n" })?;
+                    write!(out, "{:1$}", "", indent + 2)?;
+                    write_str(out, key)?;
+                    out.write_str(": ")?;
+                    value.write(out, indent + 2)?;
+                }
+                write!(out, "\n{:1$}}}", "", indent)
+            }
+        }
+    }
+}
+
+fn write_str(out: &mut String, s: &str) -> fmt::Result {
+    out.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => out.write_str("\\\"")?,
+            '\\' => out.write_str("\\\\")?,
+            '\n' => out.write_str("\\n")?,
+            '\t' => out.write_str("\\t")?,
+            c if c < ' ' => write!(out, "\\u{:04x}", c as u32)?,
+            c => out.write_char(c)?,
+        }
+    }
+    out.write_char('"')
+}
+
+struct Dumper<'a, 'mx> {
+    module: &'a Module<'mx>,
+}
+
+impl<'a, 'mx> Dumper<'a, 'mx> {
+    fn module(&self) -> Json {
+        let module = self.module;
+        Json::Object(vec![
+            ("version", Json::num(VERSION)),
+            ("global_prefix", Json::str(module.global_prefix.borrow().as_str())),
+            (
+                "includes",
+                Json::array(module.includes.borrow().iter().map(|&include| Json::str(include))),
+            ),
+            (
+                "records",
+                Json::array(
+                    module.records.borrow().iter().map(|ty| self.record(ty.record().unwrap())),
+                ),
+            ),
+            (
+                "static_asserts",
+                Json::array(module.static_asserts.borrow().iter().map(|&e| self.expr(e))),
+            ),
+            ("decls", Json::array(module.decls.borrow().iter().map(|&d| self.decl(d)))),
+            ("funcs", Json::array(module.funcs.borrow().iter().map(|&f| self.func(f)))),
+        ])
+    }
+
+    fn record(&self, record: &CRecord<'mx>) -> Json {
+        let fields = record.is_complete().then(|| {
+            let fields = record.fields().iter().map(|field| {
+                Json::Object(vec![("name", Json::str(field.name)), ("ty", self.ty(field.ty))])
+            });
+            Json::array(fields)
+        });
+        Json::Object(vec![
+            ("kind", Json::str(record.kind.to_str())),
+            ("name", Json::str(record.name)),
+            ("packed", Json::Bool(record.packed)),
+            ("align", Json::opt(record.align, Json::num)),
+            ("fields", fields.unwrap_or(Json::Null)),
+        ])
+    }
+
+    fn ty(&self, ty: CTy<'mx>) -> Json {
+        let mut json = match ty.base {
+            CTyBase::Primitive(ty) => Json::tagged("primitive", [("name", Json::str(ty.to_str()))]),
+            CTyBase::Ref(kind) => match kind.0 {
+                CTyKind::Pointer(pointee) => {
+                    Json::tagged("pointer", [("pointee", self.ty(*pointee))])
+                }
+                CTyKind::Record(record) => {
+                    Json::tagged("record", [("name", Json::str(record.name))])
+                }
+                CTyKind::Array(elem, len) => Json::tagged(
+                    "array",
+                    [("elem", self.ty(*elem)), ("len", Json::opt(*len, Json::num))],
+                ),
+                CTyKind::FnPtr(fn_ptr) => self.fn_ptr(fn_ptr),
+            },
+        };
+        if !ty.quals.is_empty() {
+            let quals = [
+                (CTyQuals::CONST, "const"),
+                (CTyQuals::VOLATILE, "volatile"),
+                (CTyQuals::RESTRICT, "restrict"),
+            ];
+            let quals = quals.iter().filter(|(qual, _)| ty.quals.contains(*qual));
+            json.push("quals", Json::array(quals.map(|&(_, name)| Json::str(name))));
+        }
+        json
+    }
+
+    fn fn_ptr(&self, fn_ptr: &CFnPtr<'mx>) -> Json {
+        Json::tagged(
+            "fn_ptr",
+            [
+                ("ret", self.ty(fn_ptr.ret)),
+                ("args", Json::array(fn_ptr.args.iter().map(|&ty| self.ty(ty)))),
+                ("abi", Json::str(format!("{:?}", fn_ptr.abi))),
+            ],
+        )
+    }
+
+    fn value(&self, value: CValue<'mx>) -> Json {
+        match value {
+            CValue::Null => Json::tagged("null", []),
+            CValue::Scalar(x) => Json::tagged("scalar", [("value", Json::num(x))]),
+            CValue::I128(x) => Json::tagged("i128", [("value", Json::num(x))]),
+            CValue::U128(x) => Json::tagged("u128", [("value", Json::num(x))]),
+            CValue::F32(bits) => Json::tagged("f32", [("bits", Json::num(bits))]),
+            CValue::F64(bits) => Json::tagged("f64", [("bits", Json::num(bits))]),
+            CValue::Local(i) => Json::tagged("local", [("index", Json::num(i))]),
+            CValue::Global(i) => Json::tagged("global", [("index", Json::num(i))]),
+            CValue::Func(name) => Json::tagged("func", [("name", Json::str(name))]),
+            CValue::Static(name) => Json::tagged("static", [("name", Json::str(name))]),
+        }
+    }
+
+    fn expr(&self, expr: CExpr<'mx>) -> Json {
+        let exprs = |exprs: &[CExpr<'mx>]| Json::array(exprs.iter().map(|&e| self.expr(e)));
+        match *expr {
+            CExprKind::Raw(text) => Json::tagged("raw", [("text", Json::str(text))]),
+            CExprKind::Value(value) => Json::tagged("value", [("value", self.value(value))]),
+            CExprKind::Unary { op, expr } => {
+                Json::tagged("unary", [("op", Json::str(op)), ("expr", self.expr(expr))])
+            }
+            CExprKind::Binary { lhs, rhs, op } => Json::tagged(
+                "binary",
+                [("op", Json::str(op)), ("lhs", self.expr(lhs)), ("rhs", self.expr(rhs))],
+            ),
+            CExprKind::Ternary { cond, then_expr, else_expr } => Json::tagged(
+                "ternary",
+                [
+                    ("cond", self.expr(cond)),
+                    ("then", self.expr(then_expr)),
+                    ("else", self.expr(else_expr)),
+                ],
+            ),
+            CExprKind::Index { expr, index } => {
+                Json::tagged("index", [("expr", self.expr(expr)), ("index", self.expr(index))])
+            }
+            CExprKind::Cast { ty, expr } => {
+                Json::tagged("cast", [("ty", self.ty(ty)), ("expr", self.expr(expr))])
+            }
+            CExprKind::Call { callee, ref args } => {
+                Json::tagged("call", [("callee", self.expr(callee)), ("args", exprs(args))])
+            }
+            CExprKind::Member { expr, arrow, field } => Json::tagged(
+                "member",
+                [
+                    ("expr", self.expr(expr)),
+                    ("arrow", Json::Bool(arrow)),
+                    ("field", Json::str(field)),
+                ],
+            ),
+            CExprKind::InitList { exprs: ref elems } => {
+                Json::tagged("init_list", [("exprs", exprs(elems))])
+            }
+            CExprKind::Designated { field, expr } => {
+                Json::tagged("designated", [("field", Json::str(field)), ("expr", self.expr(expr))])
+            }
+            CExprKind::Str(bytes) => {
+                Json::tagged("str", [("bytes", Json::array(bytes.iter().map(|&b| Json::num(b))))])
+            }
+            CExprKind::SizeOf(ty) => Json::tagged("size_of", [("ty", self.ty(ty))]),
+            CExprKind::AlignOf(ty) => Json::tagged("align_of", [("ty", self.ty(ty))]),
+            CExprKind::OffsetOf { ty, field } => {
+                Json::tagged("offset_of", [("ty", self.ty(ty)), ("field", Json::str(field))])
+            }
+        }
+    }
+
+    fn decl(&self, decl: CDecl<'mx>) -> Json {
+        match *decl {
+            CDeclKind::Var { name, ty, init, linkage, ref attrs } => Json::tagged(
+                "var",
+                [
+                    ("name", self.value(name)),
+                    ("ty", self.ty(ty)),
+                    ("init", Json::opt(init, |init| self.expr(init))),
+                    ("linkage", self.linkage(linkage)),
+                    ("attrs", self.attrs(attrs)),
+                ],
+            ),
+            CDeclKind::Func { name, fn_ptr, linkage, ref attrs } => Json::tagged(
+                "func",
+                [
+                    ("name", self.value(name)),
+                    ("ty", self.fn_ptr(fn_ptr)),
+                    ("linkage", self.linkage(linkage)),
+                    ("attrs", self.attrs(attrs)),
+                ],
+            ),
+        }
+    }
+
+    fn linkage(&self, linkage: CLinkage) -> Json {
+        Json::Object(vec![
+            ("internal", Json::Bool(linkage.internal)),
+            ("weak", Json::Bool(linkage.weak)),
+            ("hidden", Json::Bool(linkage.hidden)),
+            ("external", Json::Bool(linkage.external)),
+        ])
+    }
+
+    fn attrs(&self, attrs: &[CAttr<'mx>]) -> Json {
+        let attr = |&attr| match attr {
+            CAttr::NoInline => Json::tagged("noinline", []),
+            CAttr::AlwaysInline => Json::tagged("always_inline", []),
+            CAttr::Cold => Json::tagged("cold", []),
+            CAttr::NoReturn => Json::tagged("noreturn", []),
+            CAttr::Section(name) => Json::tagged("section", [("name", Json::str(name))]),
+            CAttr::Used => Json::tagged("used", []),
+            CAttr::Aligned(align) => Json::tagged("aligned", [("align", Json::num(align))]),
+        };
+        Json::array(attrs.iter().map(attr))
+    }
+
+    fn func(&self, func: CFunc<'mx>) -> Json {
+        let func = func.0;
+        let local_names = func.local_names().into_iter().map(|(x, name)| {
+            Json::Object(vec![("index", Json::num(x)), ("name", Json::Str(name))])
+        });
+        let allocs = func.allocs().into_iter().map(|(value, alloc)| self.alloc(value, &alloc));
+        Json::Object(vec![
+            ("name", Json::str(func.name)),
+            ("ty", self.ty(func.ty)),
+            ("params", Json::array(func.params.iter().map(|&p| self.value(p)))),
+            ("main", Json::Bool(func.is_main())),
+            ("linkage", self.linkage(func.linkage)),
+            ("attrs", self.attrs(&func.attrs)),
+            ("local_names", Json::array(local_names)),
+            ("allocs", Json::array(allocs)),
+            ("blocks", Json::array(func.basic_blocks().into_iter().map(|bb| self.bb(bb)))),
+        ])
+    }
+
+    fn alloc(&self, value: CValue<'mx>, alloc: &PendingAlloc<'mx>) -> Json {
+        Json::Object(vec![
+            ("value", self.value(value)),
+            ("ty", Json::opt(alloc.ty, |ty| self.ty(ty))),
+            ("fallback", self.ty(alloc.fallback)),
+            ("align", Json::opt(alloc.align, Json::num)),
+        ])
+    }
+
+    fn bb(&self, bb: &CBasicBlock<'mx>) -> Json {
+        Json::Object(vec![
+            ("label", Json::str(bb.label)),
+            ("stmts", self.stmts(&bb.stmts.borrow())),
+        ])
+    }
+
+    fn stmts(&self, stmts: &[CStmt<'mx>]) -> Json {
+        Json::array(stmts.iter().map(|&stmt| self.stmt(stmt)))
+    }
+
+    fn stmt(&self, stmt: CStmt<'mx>) -> Json {
+        let mut json = match *stmt {
+            CStmtKind::If { cond, then_br, else_br } => Json::tagged(
+                "if",
+                [
+                    ("cond", self.expr(cond)),
+                    ("then", self.stmt(then_br)),
+                    ("else", Json::opt(else_br, |else_br| self.stmt(else_br))),
+                ],
+            ),
+            CStmtKind::For { init, cond, next, body } => Json::tagged(
+                "for",
+                [
+                    ("init", self.stmt(init)),
+                    ("cond", self.expr(cond)),
+                    ("next", self.stmt(next)),
+                    ("body", self.stmt(body)),
+                ],
+            ),
+            CStmtKind::Return(expr) => {
+                Json::tagged("return", [("expr", Json::opt(expr, |expr| self.expr(expr)))])
+            }
+            CStmtKind::Decl(decl) => Json::tagged("decl", [("decl", self.decl(decl))]),
+            CStmtKind::Expr(expr) => Json::tagged("expr", [("expr", self.expr(expr))]),
+            CStmtKind::Goto(label) => Json::tagged("goto", [("label", Json::str(label))]),
+            CStmtKind::Switch { value, ref cases, default } => {
+                let cases = cases.iter().map(|&(case, label)| {
+                    Json::Object(vec![("value", self.expr(case)), ("label", Json::str(label))])
+                });
+                Json::tagged(
+                    "switch",
+                    [
+                        ("value", self.expr(value)),
+                        ("cases", Json::array(cases)),
+                        ("default", Json::str(default)),
+                    ],
+                )
+            }
+            CStmtKind::Block(ref stmts) => Json::tagged("block", [("stmts", self.stmts(stmts))]),
+            CStmtKind::While { cond, body } => {
+                Json::tagged("while", [("cond", self.expr(cond)), ("body", self.stmt(body))])
+            }
+            CStmtKind::DoWhile { body, cond } => {
+                Json::tagged("do_while", [("body", self.stmt(body)), ("cond", self.expr(cond))])
+            }
+            CStmtKind::Break => Json::tagged("break", []),
+            CStmtKind::Continue => Json::tagged("continue", []),
+            CStmtKind::Label(label) => Json::tagged("label", [("label", Json::str(label))]),
+        };
+        if let Some(loc) = self.module.locs.borrow().get(&stmt_key(stmt)) {
+            let loc = vec![("file", Json::str(&*loc.file)), ("line", Json::num(loc.line))];
+            json.push("loc", Json::Object(loc));
+        }
+        json
+    }
+}
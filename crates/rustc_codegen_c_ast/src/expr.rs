@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use crate::pretty::{Printer, INDENT};
+use crate::pretty::{CStd, Printer};
 use crate::r#type::CTy;
 use crate::ModuleCtxt;
 
@@ -9,9 +9,19 @@ use crate::ModuleCtxt;
 pub enum CValue<'mx> {
     Null,
     Scalar(i128),
+    /// An `__rust_i128` constant.
+    I128(i128),
+    /// An `__rust_u128` constant.
+    U128(u128),
+    /// A `float` constant, stored as its bit pattern.
+    F32(u32),
+    /// A `double` constant, stored as its bit pattern.
+    F64(u64),
     Local(usize),
     Global(usize),
     Func(&'mx str),
+    /// A static variable, named after its symbol.
+    Static(&'mx str),
 }
 
 impl<'mx> CValue<'mx> {
@@ -22,12 +32,96 @@ impl<'mx> CValue<'mx> {
     pub fn to_string(self) -> Cow<'static, str> {
         match self {
             CValue::Null => "NULL".into(),
-            CValue::Scalar(x) => x.to_string().into(),
+            CValue::Scalar(x) => int_literal(x).into(),
+            CValue::I128(x) => {
+                format!("__rust_i128_const({:#x}, {:#x})", (x >> 64) as u64, x as u64).into()
+            }
+            CValue::U128(x) => {
+                format!("__rust_u128_const({:#x}, {:#x})", (x >> 64) as u64, x as u64).into()
+            }
+            CValue::F32(bits) => hex_float(bits.into(), 23, 8, "f").into(),
+            CValue::F64(bits) => hex_float(bits, 52, 11, "").into(),
             CValue::Local(x) => format!("_{}", x).into(),
-            CValue::Global(x) => format!("_g{}", x).into(), // TODO: module-specific prefix
-            CValue::Func(x) => x.to_string().into(),
+            // the name without the prefix of the module, see `Printer::value_name`
+            CValue::Global(x) => format!("_g{}", x).into(),
+            CValue::Func(x) | CValue::Static(x) => x.to_string().into(),
+        }
+    }
+}
+
+/// Print an integer that fits in 64 bits as a C literal.
+///
+/// A decimal literal without suffix is signed, so values above `INT64_MAX` need the `u` suffix,
+/// and `INT64_MIN` cannot be written as a negated literal.
+fn int_literal(x: i128) -> String {
+    if x == i64::MIN.into() {
+        "(-9223372036854775807 - 1)".to_string()
+    } else if x > i64::MAX.into() {
+        assert!(x <= u64::MAX.into(), "integer literal {x} out of range");
+        format!("{x}u")
+    } else {
+        assert!(x >= i64::MIN.into(), "integer literal {x} out of range");
+        x.to_string()
+    }
+}
+
+/// Print bytes as a C string literal.
+///
+/// Bytes other than printable ASCII are printed as octal escapes, which unlike hex escapes
+/// consume at most three digits. `?` is escaped so that no trigraph (e.g. `??=`) is formed.
+pub(crate) fn str_literal(bytes: &[u8]) -> String {
+    let mut lit = String::with_capacity(bytes.len() + 2);
+    lit.push('"');
+    for &b in bytes {
+        match b {
+            b'"' => lit.push_str("\\\""),
+            b'\\' => lit.push_str("\\\\"),
+            b'?' => lit.push_str("\\?"),
+            b'\n' => lit.push_str("\\n"),
+            b'\t' => lit.push_str("\\t"),
+            b'\r' => lit.push_str("\\r"),
+            b' '..=b'~' => lit.push(b as char),
+            _ => lit.push_str(&format!("\\{:03o}", b)),
         }
     }
+    lit.push('"');
+    lit
+}
+
+/// Print a floating-point number exactly, in the hexadecimal notation of C99, e.g. `0x1.8p+1`
+/// for `3.0`. Infinities and NaNs are printed with the `__rust_inf` and `__rust_nan` macros from
+/// `rust_runtime.h`, and NaNs with a payload with `__rust_qnan` or `__rust_snan`, which take the
+/// payload as a hexadecimal string, e.g. `__rust_qnanf("0x1")`.
+fn hex_float(bits: u64, mant_bits: u32, exp_bits: u32, suffix: &str) -> String {
+    let sign = if bits >> (mant_bits + exp_bits) & 1 == 1 { "-" } else { "" };
+    let exp = (bits >> mant_bits) & ((1 << exp_bits) - 1);
+    let mant = bits & ((1 << mant_bits) - 1);
+    let bias = (1 << (exp_bits - 1)) - 1;
+
+    if exp == (1 << exp_bits) - 1 {
+        let quiet = 1 << (mant_bits - 1);
+        let payload = mant & (quiet - 1);
+        return if mant == 0 {
+            format!("{sign}__rust_inf{suffix}")
+        } else if payload == 0 {
+            format!("{sign}__rust_nan{suffix}")
+        } else if mant & quiet != 0 {
+            format!("{sign}__rust_qnan{suffix}(\"{payload:#x}\")")
+        } else {
+            format!("{sign}__rust_snan{suffix}(\"{payload:#x}\")")
+        };
+    }
+    if exp == 0 && mant == 0 {
+        return format!("{sign}0x0p+0{suffix}");
+    }
+
+    // align the mantissa to whole hex digits
+    let digits = mant_bits.div_ceil(4) as usize;
+    let frac = format!("{:0digits$x}", mant << (digits as u32 * 4 - mant_bits));
+    let frac = frac.trim_end_matches('0');
+    let frac = if frac.is_empty() { String::new() } else { format!(".{frac}") };
+    let (lead, exp) = if exp == 0 { (0, 1 - bias) } else { (1, exp as i64 - bias) };
+    format!("{sign}0x{lead}{frac}p{exp:+}{suffix}")
 }
 
 impl std::fmt::Debug for CValue<'_> {
@@ -42,13 +136,54 @@ pub type CExpr<'mx> = &'mx CExprKind<'mx>;
 pub enum CExprKind<'mx> {
     Raw(&'static str),
     Value(CValue<'mx>),
-    Unary { op: &'static str, expr: CExpr<'mx> },
-    Binary { lhs: CExpr<'mx>, rhs: CExpr<'mx>, op: &'static str },
-    Index { expr: CExpr<'mx>, index: CExpr<'mx> },
-    Cast { ty: CTy<'mx>, expr: CExpr<'mx> },
-    Call { callee: CExpr<'mx>, args: Box<[CExpr<'mx>]> },
-    Member { expr: CExpr<'mx>, arrow: bool, field: &'mx str },
-    InitList { exprs: Box<[CExpr<'mx>]> },
+    Unary {
+        op: &'static str,
+        expr: CExpr<'mx>,
+    },
+    Binary {
+        lhs: CExpr<'mx>,
+        rhs: CExpr<'mx>,
+        op: &'static str,
+    },
+    /// The conditional operator `cond ? then_expr : else_expr`.
+    Ternary {
+        cond: CExpr<'mx>,
+        then_expr: CExpr<'mx>,
+        else_expr: CExpr<'mx>,
+    },
+    Index {
+        expr: CExpr<'mx>,
+        index: CExpr<'mx>,
+    },
+    Cast {
+        ty: CTy<'mx>,
+        expr: CExpr<'mx>,
+    },
+    Call {
+        callee: CExpr<'mx>,
+        args: Box<[CExpr<'mx>]>,
+    },
+    Member {
+        expr: CExpr<'mx>,
+        arrow: bool,
+        field: &'mx str,
+    },
+    InitList {
+        exprs: Box<[CExpr<'mx>]>,
+    },
+    /// The designated initializer `.field = expr` in an initializer list, e.g. of a member of
+    /// a union other than the first. C89 compilers take them as an extension.
+    Designated {
+        field: &'mx str,
+        expr: CExpr<'mx>,
+    },
+    Str(&'mx [u8]),
+    SizeOf(CTy<'mx>),
+    AlignOf(CTy<'mx>),
+    OffsetOf {
+        ty: CTy<'mx>,
+        field: &'mx str,
+    },
 }
 
 impl<'mx> ModuleCtxt<'mx> {
@@ -62,7 +197,7 @@ impl<'mx> ModuleCtxt<'mx> {
         CValue::Func(name)
     }
 
-    fn create_expr(&self, expr: CExprKind<'mx>) -> CExpr<'mx> {
+    pub fn create_expr(&self, expr: CExprKind<'mx>) -> CExpr<'mx> {
         self.arena().alloc(expr)
     }
 
@@ -74,6 +209,22 @@ impl<'mx> ModuleCtxt<'mx> {
         CValue::Scalar(scalar)
     }
 
+    pub fn i128(&self, val: i128) -> CValue<'mx> {
+        CValue::I128(val)
+    }
+
+    pub fn u128(&self, val: u128) -> CValue<'mx> {
+        CValue::U128(val)
+    }
+
+    pub fn f32(&self, val: f32) -> CValue<'mx> {
+        CValue::F32(val.to_bits())
+    }
+
+    pub fn f64(&self, val: f64) -> CValue<'mx> {
+        CValue::F64(val.to_bits())
+    }
+
     pub fn value(&self, value: CValue<'mx>) -> CExpr<'mx> {
         self.create_expr(CExprKind::Value(value))
     }
@@ -86,6 +237,15 @@ impl<'mx> ModuleCtxt<'mx> {
         self.create_expr(CExprKind::Binary { lhs, rhs, op })
     }
 
+    pub fn ternary(
+        &self,
+        cond: CExpr<'mx>,
+        then_expr: CExpr<'mx>,
+        else_expr: CExpr<'mx>,
+    ) -> CExpr<'mx> {
+        self.create_expr(CExprKind::Ternary { cond, then_expr, else_expr })
+    }
+
     pub fn index(&self, expr: CExpr<'mx>, index: CExpr<'mx>) -> CExpr<'mx> {
         self.create_expr(CExprKind::Index { expr, index })
     }
@@ -109,11 +269,72 @@ impl<'mx> ModuleCtxt<'mx> {
     pub fn init_list(&self, exprs: impl Into<Box<[CExpr<'mx>]>>) -> CExpr<'mx> {
         self.create_expr(CExprKind::InitList { exprs: exprs.into() })
     }
+
+    pub fn designated(&self, field: &'mx str, expr: CExpr<'mx>) -> CExpr<'mx> {
+        self.create_expr(CExprKind::Designated { field, expr })
+    }
+
+    /// A string literal of the given bytes, without the terminating NUL.
+    pub fn str_lit(&self, bytes: &[u8]) -> CExpr<'mx> {
+        self.create_expr(CExprKind::Str(self.arena().alloc_slice(bytes)))
+    }
+
+    pub fn size_of(&self, ty: CTy<'mx>) -> CExpr<'mx> {
+        self.create_expr(CExprKind::SizeOf(ty))
+    }
+
+    pub fn align_of(&self, ty: CTy<'mx>) -> CExpr<'mx> {
+        self.create_expr(CExprKind::AlignOf(ty))
+    }
+
+    pub fn offset_of(&self, ty: CTy<'mx>, field: &'mx str) -> CExpr<'mx> {
+        self.create_expr(CExprKind::OffsetOf { ty, field })
+    }
 }
 
 impl Printer {
+    /// Print the operand of a postfix operator, i.e. indexing, a call or member access, which
+    /// binds tighter than a cast.
+    fn print_postfix_operand(&mut self, expr: CExpr) {
+        if let CExprKind::Cast { .. } = expr {
+            self.ibox_delim(self.indent, ("(", ")"), |this| this.print_expr(expr, true));
+        } else {
+            self.print_expr(expr, false);
+        }
+    }
+
+    /// Whether an expression prints starting with a sign, i.e. it is a negative literal.
+    fn starts_with_sign(&self, expr: CExpr) -> bool {
+        matches!(expr, CExprKind::Value(value) if self.value_name(*value).starts_with(['-', '+']))
+    }
+
     pub fn print_value(&mut self, value: CValue) {
-        self.word(value.to_string());
+        self.word(self.value_name(value));
+    }
+
+    /// The name of a value, with the global prefix of the module and the name hints of the
+    /// locals of the function printed.
+    pub(crate) fn value_name(&self, value: CValue) -> Cow<'static, str> {
+        match value {
+            // C89 has no hexadecimal floating constants, the shortest decimal that reads back to
+            // the same value is printed instead
+            CValue::F32(bits) if self.decimal_floats() && f32::from_bits(bits).is_finite() => {
+                format!("{:e}f", f32::from_bits(bits)).into()
+            }
+            CValue::F64(bits) if self.decimal_floats() && f64::from_bits(bits).is_finite() => {
+                format!("{:e}", f64::from_bits(bits)).into()
+            }
+            CValue::Local(x) => match self.local_names.get(&x) {
+                Some(name) => format!("{name}_{x}").into(),
+                None => value.to_string(),
+            },
+            CValue::Global(x) => format!("_g{}{}", self.global_prefix, x).into(),
+            _ => value.to_string(),
+        }
+    }
+
+    fn decimal_floats(&self) -> bool {
+        self.config().c_std < CStd::C99
     }
 
     pub fn print_expr(&mut self, expr: CExpr, outer: bool) {
@@ -121,24 +342,46 @@ impl Printer {
         match expr {
             CExprKind::Raw(raw) => self.word(*raw),
             CExprKind::Value(value) => self.print_value(*value),
-            CExprKind::Unary { op, expr } => self.ibox_delim(INDENT, delim, |this| {
+            CExprKind::Unary { op, expr } => self.ibox_delim(self.indent, delim, |this| {
                 this.word(*op);
                 this.print_expr(expr, false);
             }),
-            CExprKind::Binary { lhs, rhs, op } => self.ibox_delim(INDENT, delim, |this| {
-                this.ibox(-INDENT, |this| this.print_expr(lhs, false));
+            CExprKind::Binary { lhs, rhs, op } => self.ibox_delim(self.indent, delim, |this| {
+                this.ibox(-this.indent, |this| this.print_expr(lhs, false));
 
-                this.softbreak();
-                this.word(*op);
-                this.nbsp();
+                // a space keeps e.g. `x - -1` from printing as the decrement `x--1`
+                if this.config().spaced_ops || *op == "=" || this.starts_with_sign(rhs) {
+                    this.softbreak();
+                    this.word(*op);
+                    this.nbsp();
+                } else {
+                    this.zerobreak();
+                    this.word(*op);
+                }
 
-                this.print_expr(rhs, false);
+                // assignment has the lowest precedence and groups to the right
+                this.print_expr(rhs, *op == "=");
             }),
+            CExprKind::Ternary { cond, then_expr, else_expr } => {
+                self.ibox_delim(self.indent, delim, |this| {
+                    this.ibox(-this.indent, |this| this.print_expr(cond, false));
+
+                    this.softbreak();
+                    this.word("?");
+                    this.nbsp();
+                    this.print_expr(then_expr, false);
+
+                    this.softbreak();
+                    this.word(":");
+                    this.nbsp();
+                    this.print_expr(else_expr, false);
+                })
+            }
             CExprKind::Index { expr, index } => {
-                self.print_expr(expr, false);
-                self.ibox_delim(INDENT, ("[", "]"), |this| this.print_expr(index, false));
+                self.print_postfix_operand(expr);
+                self.ibox_delim(self.indent, ("[", "]"), |this| this.print_expr(index, true));
             }
-            CExprKind::Cast { ty, expr } => self.ibox(INDENT, |this| {
+            CExprKind::Cast { ty, expr } => self.ibox(self.indent, |this| {
                 this.word("(");
                 this.print_ty_decl(*ty, None);
                 this.word(")");
@@ -146,14 +389,14 @@ impl Printer {
                 this.nbsp();
                 this.print_expr(expr, false);
             }),
-            CExprKind::Call { callee, args } => self.ibox(INDENT, |this| {
-                this.print_expr(callee, false);
-                this.cbox_delim(INDENT, ("(", ")"), 0, |this| {
-                    this.seperated(",", args, |this, arg| this.print_expr(arg, false))
+            CExprKind::Call { callee, args } => self.ibox(self.indent, |this| {
+                this.print_postfix_operand(callee);
+                this.cbox_delim(this.indent, ("(", ")"), 0, |this| {
+                    this.seperated(",", args, |this, arg| this.print_expr(arg, true))
                 });
             }),
-            CExprKind::Member { expr, arrow, field } => self.cbox(INDENT, |this| {
-                this.print_expr(expr, false);
+            CExprKind::Member { expr, arrow, field } => self.cbox(self.indent, |this| {
+                this.print_postfix_operand(expr);
                 this.zerobreak();
                 if *arrow {
                     this.word("->");
@@ -162,11 +405,48 @@ impl Printer {
                 }
                 this.word(field.to_string());
             }),
-            CExprKind::InitList { exprs } => self.ibox(INDENT, |this| {
-                this.ibox_delim(INDENT, ("{", "}"), |this| {
-                    this.seperated(",", exprs, |this, expr| this.print_expr(expr, false));
+            CExprKind::InitList { exprs } => self.ibox(self.indent, |this| {
+                this.ibox_delim(this.indent, ("{", "}"), |this| {
+                    this.seperated(",", exprs, |this, expr| this.print_expr(expr, true));
                 })
             }),
+            CExprKind::Designated { field, expr } => self.ibox(self.indent, |this| {
+                this.word(format!(".{field} ="));
+                this.softbreak();
+                this.print_expr(expr, true);
+            }),
+            CExprKind::Str(bytes) => self.cbox(self.indent, |this| {
+                // one literal per line of the string, which are concatenated by the compiler
+                for (i, line) in bytes.split_inclusive(|&b| b == b'\n').enumerate() {
+                    if i > 0 {
+                        this.softbreak();
+                    }
+                    this.word(str_literal(line));
+                }
+                if bytes.is_empty() {
+                    this.word("\"\"");
+                }
+            }),
+            CExprKind::SizeOf(ty) => self.ibox_delim(self.indent, ("sizeof(", ")"), |this| {
+                this.print_ty_decl(*ty, None);
+            }),
+            CExprKind::AlignOf(ty) => {
+                let open = match self.config().c_std {
+                    CStd::C89 | CStd::C99 => "__alignof__(",
+                    CStd::C11 | CStd::C17 | CStd::C23 => "_Alignof(",
+                };
+                self.ibox_delim(self.indent, (open, ")"), |this| {
+                    this.print_ty_decl(*ty, None);
+                })
+            }
+            CExprKind::OffsetOf { ty, field } => {
+                self.ibox_delim(self.indent, ("offsetof(", ")"), |this| {
+                    this.print_ty_decl(*ty, None);
+                    this.word(",");
+                    this.softbreak();
+                    this.word(field.to_string());
+                })
+            }
         }
     }
 }
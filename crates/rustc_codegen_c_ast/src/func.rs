@@ -3,11 +3,14 @@ use std::cell::{Cell, RefCell};
 use rustc_data_structures::fx::FxIndexMap;
 use rustc_data_structures::intern::Interned;
 
-use crate::expr::CValue;
-use crate::pretty::{Printer, INDENT};
+use crate::cfg::jump_targets;
+use crate::decl::{CAttr, CDecl, CDeclKind, CLinkage};
+use crate::expr::{CExpr, CValue};
+use crate::pretty::{BraceStyle, CStd, Printer};
 use crate::r#type::{CFnPtr, CTy};
-use crate::stmt::CStmt;
-use crate::ModuleCtxt;
+use crate::stmt::{is_hoistable, CStmt};
+use crate::visit::{walk_func, Visitor};
+use crate::{sanitize_ident, ModuleCtxt};
 
 pub type CFunc<'mx> = Interned<'mx, CFuncKind<'mx>>;
 
@@ -16,9 +19,13 @@ pub struct CFuncKind<'mx> {
     pub name: &'mx str,
     pub ty: CTy<'mx>,
     pub params: Box<[CValue<'mx>]>,
+    pub linkage: CLinkage,
+    pub attrs: Box<[CAttr<'mx>]>,
     is_main: bool,
     body: RefCell<Vec<&'mx CBasicBlock<'mx>>>,
     alloc: RefCell<FxIndexMap<CValue<'mx>, PendingAlloc<'mx>>>,
+    /// The name hints of local variables, see [`CFuncKind::set_local_name`]
+    local_names: RefCell<FxIndexMap<usize, String>>,
     local_var_counter: Cell<usize>,
 }
 
@@ -29,6 +36,7 @@ impl<'mx> CFuncKind<'mx> {
         let local_var_counter = Cell::new(fn_ptr.args.len());
         let body = RefCell::new(Vec::new());
         let alloc = RefCell::new(FxIndexMap::default());
+        let local_names = RefCell::new(FxIndexMap::default());
 
         // TODO: diagnosis output instead of panic
         if is_main {
@@ -49,7 +57,28 @@ impl<'mx> CFuncKind<'mx> {
             }
         }
 
-        Self { name, ty, params, is_main, body, alloc, local_var_counter }
+        let (linkage, attrs) = (CLinkage::default(), Box::default());
+        Self {
+            name,
+            ty,
+            params,
+            linkage,
+            attrs,
+            is_main,
+            body,
+            alloc,
+            local_names,
+            local_var_counter,
+        }
+    }
+
+    pub fn with_linkage(self, linkage: CLinkage) -> Self {
+        assert!(!self.is_main || linkage == CLinkage::default(), "main function must be external");
+        Self { linkage, ..self }
+    }
+
+    pub fn with_attrs(self, attrs: impl Into<Box<[CAttr<'mx>]>>) -> Self {
+        Self { attrs: attrs.into(), ..self }
     }
 
     pub fn next_local_var(&self) -> CValue<'mx> {
@@ -58,20 +87,53 @@ impl<'mx> CFuncKind<'mx> {
         val
     }
 
-    pub fn new_pending_alloc(&self, fallback: CTy<'mx>) -> CValue<'mx> {
+    /// Give a local variable a name hint, e.g. the name of the Rust variable it holds. The local
+    /// `_12` named `count` is printed as `count_12`.
+    ///
+    /// The name is sanitized with [`sanitize_ident`], and ignored if nothing is left of it or the
+    /// local already has a name. The index suffix keeps the names unique and apart from the
+    /// unnamed `_N` locals.
+    pub fn set_local_name(&self, val: CValue<'mx>, name: &str) {
+        let CValue::Local(x) = val else { return };
+        let name = sanitize_ident(name);
+        if !name.is_empty() {
+            self.local_names.borrow_mut().entry(x).or_insert(name);
+        }
+    }
+
+    /// The name hints of local variables by index, in the order they are given.
+    pub fn local_names(&self) -> Vec<(usize, String)> {
+        self.local_names.borrow().iter().map(|(&x, name)| (x, name.clone())).collect()
+    }
+
+    /// Create an allocation declared as `fallback` until it is realized with a type by
+    /// [`CFuncKind::realize_alloc`]. The allocation is aligned to `align` bytes.
+    pub fn new_pending_alloc(&self, fallback: CTy<'mx>, align: u64) -> CValue<'mx> {
         let val = self.next_local_var();
-        self.alloc.borrow_mut().insert(val, PendingAlloc { ty: None, fallback });
+        let align = (align > 1).then_some(align);
+        self.alloc.borrow_mut().insert(val, PendingAlloc { ty: None, fallback, align });
         val
     }
 
-    pub fn realize_alloc(&self, val: CValue<'mx>, ty: CTy<'mx>) {
+    /// Gets the allocation of a local variable, if it is allocated by
+    /// [`CFuncKind::new_pending_alloc`].
+    pub fn pending_alloc(&self, val: CValue<'mx>) -> Option<PendingAlloc<'mx>> {
+        self.alloc.borrow().get(&val).cloned()
+    }
+
+    /// Declare an allocation with type `ty`, whose alignment is `ty_align` bytes. The explicit
+    /// alignment of the allocation is dropped if the type is aligned enough by itself.
+    pub fn realize_alloc(&self, val: CValue<'mx>, ty: CTy<'mx>, ty_align: u64) {
         let mut alloc = self.alloc.borrow_mut();
         match alloc.get_mut(&val) {
             Some(PendingAlloc { ty: Some(alloc_ty), .. }) => {
                 assert_eq!(ty, *alloc_ty, "alloc mismatch: {:?} vs {:?}", ty, alloc_ty)
             }
-            Some(PendingAlloc { ty: alloc_ty @ None, .. }) => {
+            Some(PendingAlloc { ty: alloc_ty @ None, align, .. }) => {
                 alloc_ty.replace(ty);
+                if align.is_some_and(|align| align <= ty_align) {
+                    *align = None;
+                }
             }
             None => {
                 panic!("alloc not found: {:?}", val)
@@ -79,22 +141,59 @@ impl<'mx> CFuncKind<'mx> {
         }
     }
 
+    /// Append a new basic block. The label is made unique within the function by appending a
+    /// number if needed.
     pub fn new_bb(&self, label: &str, mcx: &ModuleCtxt<'mx>) -> &'mx CBasicBlock<'mx> {
-        let label = mcx.alloc_str(label);
+        let taken = |label: &str| self.body.borrow().iter().any(|bb| bb.label == label);
+        let label = if taken(label) {
+            (1..).map(|i| format!("{label}_{i}")).find(|label| !taken(label)).unwrap()
+        } else {
+            label.to_string()
+        };
+        let label = mcx.alloc_str(&label);
         let bb = mcx.create_bb(CBasicBlock::new(label));
         self.body.borrow_mut().push(bb);
         bb
     }
 
+    /// The basic blocks of the function, in order.
+    pub fn basic_blocks(&self) -> Vec<&'mx CBasicBlock<'mx>> {
+        self.body.borrow().clone()
+    }
+
+    /// Keep only the basic blocks for which `keep` returns true.
+    pub fn retain_basic_blocks(&self, mut keep: impl FnMut(&CBasicBlock<'mx>) -> bool) {
+        self.body.borrow_mut().retain(|bb| keep(bb));
+    }
+
+    /// The allocations of the function, in order.
+    pub fn allocs(&self) -> Vec<(CValue<'mx>, PendingAlloc<'mx>)> {
+        self.alloc.borrow().iter().map(|(&val, alloc)| (val, alloc.clone())).collect()
+    }
+
     pub fn fn_ptr(&self) -> &'mx CFnPtr<'mx> {
         self.ty.fn_ptr().unwrap()
     }
+
+    /// Whether the function is the C `main` function.
+    pub fn is_main(&self) -> bool {
+        self.is_main
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct PendingAlloc<'mx> {
     pub ty: Option<CTy<'mx>>,
     pub fallback: CTy<'mx>, // fallback type char[N] if ty is None
+    /// The alignment declared with `_Alignas(N)`, if the type does not have it.
+    pub align: Option<u64>,
+}
+
+impl<'mx> PendingAlloc<'mx> {
+    /// The type the allocation is declared with.
+    pub fn ty(&self) -> CTy<'mx> {
+        self.ty.unwrap_or(self.fallback)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -125,7 +224,9 @@ impl<'mx> ModuleCtxt<'mx> {
 
 impl Printer {
     pub fn print_func_decl(&mut self, func: CFunc) {
+        self.local_names = func.0.local_names().into_iter().collect();
         let fn_ptr = func.fn_ptr();
+        self.print_decl_specs(func.0.linkage, &func.0.attrs);
         if func.is_main {
             self.print_signature_main(&func.0.params)
         } else {
@@ -135,34 +236,52 @@ impl Printer {
     }
 
     pub fn print_func(&mut self, func: CFunc) {
+        self.begin_func_locs();
+        self.local_names = func.0.local_names().into_iter().collect();
         self.ibox(0, |this| {
             let fn_ptr = func.fn_ptr();
+            this.print_decl_specs(func.0.linkage, &func.0.attrs);
             if func.is_main {
                 this.print_signature_main(&func.0.params)
             } else {
                 this.print_signature(fn_ptr.ret, func.0.name, &fn_ptr.args, Some(&func.0.params));
             }
-            this.softbreak();
-            this.word("{");
-            if func.0.alloc.borrow().is_empty() {
-                this.break_offset(0, 0);
-            } else {
-                this.break_offset(0, INDENT);
+            match this.config().brace_style {
+                BraceStyle::SameLine => this.softbreak(),
+                BraceStyle::NextLine => this.hardbreak(),
             }
-            this.cbox(INDENT, |this| {
-                this.seperated("", func.0.alloc.borrow().iter(), |this, (var, alloc)| {
+            this.word("{");
+            let targets = jump_targets(func.0);
+            let hoisted = if this.hoists_decls() { local_decls(func) } else { Vec::new() };
+            let allocs = func.0.alloc.borrow();
+            let body = func.0.body.borrow();
+            // the line after the brace is outdented if it is a label
+            let first_offset = match body.first() {
+                _ if !allocs.is_empty() || !hoisted.is_empty() => 0,
+                Some(bb) if targets.contains(bb.label) => -this.indent,
+                Some(bb) => bb.stmts.borrow().first().map_or(0, |stmt| this.line_offset(stmt)),
+                None => 0,
+            };
+            this.break_offset(0, this.indent + first_offset);
+            this.cbox(this.indent, |this| {
+                let mut first = true;
+                for (var, alloc) in allocs.iter() {
+                    this.line_break(&mut first, 0);
                     this.print_pending_alloc(*var, alloc);
-                });
-                if !func.0.alloc.borrow().is_empty() {
-                    this.break_offset(0, -INDENT);
                 }
-                for &bb in func.0.body.borrow().iter() {
-                    this.print_bb(bb);
-                    this.break_offset(0, -INDENT);
+                for &(var, ty) in &hoisted {
+                    this.line_break(&mut first, 0);
+                    this.print_ty_decl(ty, Some(var));
+                    this.word(";");
                 }
+                for &bb in body.iter() {
+                    this.print_bb(bb, targets.contains(bb.label), &mut first);
+                }
+                this.hardbreak_offset(-this.indent);
             });
             this.word("}");
-        })
+        });
+        self.end_func_locs();
     }
 
     pub fn print_signature(
@@ -214,20 +333,60 @@ impl Printer {
     }
 
     fn print_pending_alloc(&mut self, val: CValue, alloc: &PendingAlloc) {
-        if let Some(ty) = alloc.ty {
-            self.print_ty_decl(ty, Some(val));
-        } else {
-            self.print_ty_decl(alloc.fallback, Some(val));
+        let c_std = self.config().c_std;
+        match alloc.align {
+            Some(align) if c_std >= CStd::C11 => self.word(format!("_Alignas({align}) ")),
+            _ => {}
+        }
+        self.print_ty_decl(alloc.ty(), Some(val));
+        if let Some(align) = alloc.align.filter(|_| c_std < CStd::C11) {
+            self.word(format!(" {}", self.attr_spec(&[format!("aligned({align})")])));
         }
         self.word(";");
     }
 
-    fn print_bb(&mut self, bb: &CBasicBlock) {
-        self.word(bb.label.to_string());
-        self.word(":;");
-        for stmt in bb.stmts.borrow().iter() {
-            self.hardbreak();
+    /// Print the statements of a basic block, preceded by its label if it is jumped to.
+    fn print_bb(&mut self, bb: &CBasicBlock, labeled: bool, first: &mut bool) {
+        if labeled {
+            self.line_break(first, -self.indent);
+            self.word(bb.label.to_string());
+            self.word(":;");
+        }
+        for &stmt in bb.stmts.borrow().iter() {
+            if self.is_hoisted_decl(stmt) {
+                continue;
+            }
+            self.line_break(first, self.line_offset(stmt));
+            self.print_loc(stmt);
             self.print_stmt(stmt, true);
         }
     }
+
+    /// Start a new line of a function body, unless it is the first one, which follows the
+    /// break after the opening brace.
+    fn line_break(&mut self, first: &mut bool, offset: isize) {
+        if !std::mem::take(first) {
+            self.hardbreak_offset(offset);
+        }
+    }
+}
+
+/// The local variables declared in the body of a function that can be hoisted, in order.
+fn local_decls(func: CFunc<'_>) -> Vec<(CValue<'_>, CTy<'_>)> {
+    struct Decls<'mx>(Vec<(CValue<'mx>, CTy<'mx>)>);
+
+    impl<'mx> Visitor<'mx> for Decls<'mx> {
+        fn visit_decl(&mut self, decl: CDecl<'mx>) {
+            match *decl {
+                CDeclKind::Var { name, ty, .. } if is_hoistable(decl) => self.0.push((name, ty)),
+                _ => {}
+            }
+        }
+
+        fn visit_expr(&mut self, _expr: CExpr<'mx>) {}
+    }
+
+    let mut decls = Decls(Vec::new());
+    walk_func(&mut decls, func);
+    decls.0
 }
@@ -0,0 +1,166 @@
+//! Inference of the types of expressions.
+//!
+//! The AST does not record the types of expressions, so the passes that need them infer the type
+//! where it is evident from the C, e.g. of locals, casts and calls of known functions. Arithmetic
+//! follows the integer promotions of C: arithmetic on types narrower than `int` has the type
+//! `int`, which has no [`CTy`], so it has no evident type.
+
+use rustc_hash::FxHashMap;
+
+use crate::decl::CDeclKind;
+use crate::expr::{CExpr, CExprKind, CValue};
+use crate::module::Module;
+use crate::r#type::{CFnPtr, CPTy, CTy, CTyBase};
+
+/// The types of the globals and functions of a module.
+pub struct GlobalTypes<'mx> {
+    pub globals: FxHashMap<CValue<'mx>, CTy<'mx>>,
+    pub funcs: FxHashMap<&'mx str, &'mx CFnPtr<'mx>>,
+}
+
+impl<'mx> GlobalTypes<'mx> {
+    /// Collect the declared globals and functions, and the functions defined in the module.
+    pub fn new(module: &Module<'mx>) -> Self {
+        let mut globals = FxHashMap::default();
+        let mut funcs = FxHashMap::default();
+        for &decl in module.decls.borrow().iter() {
+            match *decl {
+                CDeclKind::Var { name, ty, .. } => {
+                    globals.insert(name, ty);
+                }
+                CDeclKind::Func { name: CValue::Func(name), fn_ptr, .. } => {
+                    funcs.insert(name, fn_ptr);
+                }
+                CDeclKind::Func { .. } => {}
+            }
+        }
+        for &func in module.funcs.borrow().iter() {
+            funcs.insert(func.0.name, func.fn_ptr());
+        }
+        GlobalTypes { globals, funcs }
+    }
+
+    /// The type of an expression, if it is evident, where `locals` are the types of the locals
+    /// in scope.
+    pub fn type_of(
+        &self,
+        locals: &FxHashMap<CValue<'mx>, CTy<'mx>>,
+        expr: CExpr<'mx>,
+    ) -> Option<CTy<'mx>> {
+        let type_of = |expr| self.type_of(locals, expr);
+        let promoted = |ty: CTy<'mx>| {
+            !matches!(
+                ty.base,
+                CTyBase::Primitive(
+                    CPTy::Bool | CPTy::Char | CPTy::I8 | CPTy::U8 | CPTy::I16 | CPTy::U16
+                )
+            )
+        };
+        match *expr {
+            CExprKind::Value(value) => match value {
+                CValue::Local(_) => locals.get(&value).copied(),
+                CValue::Global(_) | CValue::Static(_) => self.globals.get(&value).copied(),
+                CValue::I128(_) => Some(CTy::primitive(CPTy::I128)),
+                CValue::U128(_) => Some(CTy::primitive(CPTy::U128)),
+                CValue::F32(_) => Some(CTy::primitive(CPTy::F32)),
+                CValue::F64(_) => Some(CTy::primitive(CPTy::F64)),
+                CValue::Null | CValue::Scalar(_) | CValue::Func(_) => None,
+            },
+            CExprKind::Unary { op: "!", .. } => Some(CTy::primitive(CPTy::Bool)),
+            CExprKind::Unary { op: "-" | "~", expr } => type_of(expr).filter(|&ty| promoted(ty)),
+            CExprKind::Unary { op: "*", expr } | CExprKind::Index { expr, .. } => {
+                type_of(expr)?.pointee()
+            }
+            CExprKind::Binary { lhs, rhs, op } => match op {
+                "==" | "!=" | "<" | ">" | "<=" | ">=" | "&&" | "||" => {
+                    Some(CTy::primitive(CPTy::Bool))
+                }
+                "<<" | ">>" => type_of(lhs).filter(|&ty| promoted(ty)),
+                "=" => type_of(lhs),
+                _ => self.common_type(locals, lhs, rhs).filter(|&ty| promoted(ty) && !ty.is_ptr()),
+            },
+            CExprKind::Ternary { then_expr, else_expr, .. } => {
+                self.common_type(locals, then_expr, else_expr)
+            }
+            CExprKind::Cast { ty, .. } => Some(ty),
+            CExprKind::Call { callee: CExprKind::Value(CValue::Func(name)), .. } => {
+                self.funcs.get(name).map(|fn_ptr| fn_ptr.ret)
+            }
+            CExprKind::Call { callee: CExprKind::Raw(name), ref args } => {
+                helper_type(name, args).map(CTy::primitive)
+            }
+            CExprKind::Member { expr, arrow, field } => {
+                let base = type_of(expr)?;
+                let base = if arrow { base.pointee()? } else { base };
+                base.record()?.fields().iter().find(|f| f.name == field).map(|f| f.ty)
+            }
+            _ => None,
+        }
+    }
+
+    /// The type of two operands that have the same type, where an integer literal has the type
+    /// of the other operand if the type of the literal is not larger.
+    fn common_type(
+        &self,
+        locals: &FxHashMap<CValue<'mx>, CTy<'mx>>,
+        lhs: CExpr<'mx>,
+        rhs: CExpr<'mx>,
+    ) -> Option<CTy<'mx>> {
+        // a literal has type `int` if it fits, and otherwise a type with 64 bits; `INT_MIN` is
+        // printed as a negated literal, which does not fit
+        let literal_fits = |expr: CExpr<'mx>, ty: CTy<'mx>| match *expr {
+            CExprKind::Value(CValue::Scalar(value)) => {
+                (-i128::from(i32::MAX)..=i32::MAX.into()).contains(&value)
+                    || matches!(ty.base, CTyBase::Primitive(CPTy::I64 | CPTy::U64))
+            }
+            _ => false,
+        };
+        match (self.type_of(locals, lhs), self.type_of(locals, rhs)) {
+            (Some(lhs), Some(rhs)) => (lhs.base == rhs.base).then_some(lhs),
+            (Some(ty), None) => literal_fits(rhs, ty).then_some(ty),
+            (None, Some(ty)) => literal_fits(lhs, ty).then_some(ty),
+            (None, None) => None,
+        }
+    }
+}
+
+/// The type returned by a helper of `rust_runtime.h`.
+fn helper_type(name: &str, args: &[CExpr<'_>]) -> Option<CPTy> {
+    let named = |name: &str| {
+        [
+            CPTy::Isize,
+            CPTy::I8,
+            CPTy::I16,
+            CPTy::I32,
+            CPTy::I64,
+            CPTy::I128,
+            CPTy::Usize,
+            CPTy::U8,
+            CPTy::U16,
+            CPTy::U32,
+            CPTy::U64,
+            CPTy::U128,
+        ]
+        .into_iter()
+        .find(|ty| ty.to_str() == name)
+    };
+    match name {
+        // `__rust_utos(U, S, x, MAX)`
+        "__rust_utos" => match args.get(1).copied() {
+            Some(CExprKind::Raw(ty)) => named(ty),
+            _ => None,
+        },
+        "__rust_fmod" => Some(CPTy::F64),
+        "__rust_fmodf" => Some(CPTy::F32),
+        _ if name.starts_with("__rust_cmp_") => None,
+        _ => match name.split_once("_from_") {
+            // e.g. `__rust_u64_from_u128`
+            Some(("__rust_u64", _)) => Some(CPTy::U64),
+            Some((to, _)) => named(to),
+            // the arithmetic of `__rust_i128` and `__rust_u128`, e.g. `__rust_add_u128`
+            None if name.ends_with("_i128") => Some(CPTy::I128),
+            None if name.ends_with("_u128") => Some(CPTy::U128),
+            None => None,
+        },
+    }
+}
@@ -0,0 +1,343 @@
+//! Inlining of single-use temporaries.
+//!
+//! The builder stores the result of every operation in a fresh local, e.g. `x + y + z` becomes
+//! `int32_t _3 = _0 + _1; int32_t _4 = _3 + _2; return _4;`. [`inline_temps`] moves the
+//! initializer of a local that is used exactly once, later in the same basic block, into its
+//! use, which gives `return (_0 + _1) + _2;`.
+//!
+//! An initializer is only moved if it has no side effects, and not across statements that could
+//! change what it reads: an initializer reading memory is not moved past a write to memory, and
+//! one reading a local is not moved past an assignment to that local. Volatile accesses count as
+//! side effects, so they are never reordered. The conversion done by the initialization is kept
+//! with a cast, unless the type of the initializer is evidently the type of the local.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::decl::{CDecl, CDeclKind};
+use crate::expr::{CExpr, CExprKind, CValue};
+use crate::func::{CBasicBlock, CFunc};
+use crate::infer::GlobalTypes;
+use crate::r#type::{CTy, CTyQuals};
+use crate::stmt::{CStmt, CStmtKind};
+use crate::visit::{walk_decl, walk_expr, walk_expr_mut, Folder, Visitor};
+use crate::ModuleCtxt;
+
+/// Inline the single-use temporaries of the functions of a module.
+pub fn inline_temps(mcx: ModuleCtxt<'_>) {
+    let module = mcx.module();
+
+    let globals = GlobalTypes::new(module);
+
+    for &func in module.funcs.borrow().iter() {
+        let mut locals = Locals::default();
+        for (&param, &ty) in func.0.params.iter().zip(func.fn_ptr().args.iter()) {
+            locals.types.insert(param, ty);
+        }
+        for (val, alloc) in func.0.allocs() {
+            locals.types.insert(val, alloc.ty());
+            locals.memory.insert(val);
+        }
+        locals.visit_func(func);
+
+        let inliner = Inliner { mcx, globals: &globals, locals };
+        inliner.inline_func(func);
+    }
+}
+
+/// The types and uses of the locals of a function.
+#[derive(Default)]
+struct Locals<'mx> {
+    types: FxHashMap<CValue<'mx>, CTy<'mx>>,
+    uses: FxHashMap<CValue<'mx>, usize>,
+    /// Allocations and locals that have their address taken or are assigned, which are not
+    /// inlined, and whose reads are memory accesses.
+    memory: FxHashSet<CValue<'mx>>,
+}
+
+impl<'mx> Visitor<'mx> for Locals<'mx> {
+    fn visit_decl(&mut self, decl: CDecl<'mx>) {
+        if let CDeclKind::Var { name, ty, .. } = *decl {
+            self.types.insert(name, ty);
+        }
+        walk_decl(self, decl)
+    }
+
+    fn visit_expr(&mut self, expr: CExpr<'mx>) {
+        match *expr {
+            CExprKind::Value(value @ CValue::Local(_)) => *self.uses.entry(value).or_default() += 1,
+            CExprKind::Binary { lhs: place, op: "=", .. }
+            | CExprKind::Unary { op: "&", expr: place } => {
+                self.memory.extend(place_base(place));
+            }
+            _ => {}
+        }
+        walk_expr(self, expr)
+    }
+}
+
+/// The variable an lvalue is part of, e.g. `_1` for `_1.a.b`.
+fn place_base(place: CExpr<'_>) -> Option<CValue<'_>> {
+    match *place {
+        CExprKind::Value(value) => Some(value),
+        CExprKind::Member { expr, arrow: false, .. } => place_base(expr),
+        _ => None,
+    }
+}
+
+/// The side effects of an expression and what it reads.
+#[derive(Default)]
+struct Effects<'a, 'mx> {
+    memory: Option<&'a FxHashSet<CValue<'mx>>>,
+    /// Whether memory is written, including volatile accesses and calls.
+    writes: bool,
+    reads_memory: bool,
+    /// Whether part of the expression is evaluated conditionally, e.g. by `?:` or `&&`.
+    conditional: bool,
+    reads: FxHashSet<CValue<'mx>>,
+    assigns: FxHashSet<CValue<'mx>>,
+}
+
+impl<'a, 'mx> Effects<'a, 'mx> {
+    fn of(memory: &'a FxHashSet<CValue<'mx>>, exprs: &[CExpr<'mx>]) -> Self {
+        let mut effects = Effects { memory: Some(memory), ..Default::default() };
+        for &expr in exprs {
+            effects.visit_expr(expr);
+        }
+        effects
+    }
+}
+
+impl<'a, 'mx> Visitor<'mx> for Effects<'a, 'mx> {
+    fn visit_expr(&mut self, expr: CExpr<'mx>) {
+        match *expr {
+            CExprKind::Value(value) => {
+                let memory = self.memory.is_some_and(|memory| memory.contains(&value));
+                if matches!(value, CValue::Global(_) | CValue::Static(_)) || memory {
+                    self.reads_memory = true;
+                } else if let CValue::Local(_) = value {
+                    self.reads.insert(value);
+                }
+            }
+            CExprKind::Binary { lhs, op: "=", .. } => {
+                self.writes = true;
+                self.assigns.extend(place_base(lhs));
+            }
+            // taking the address of an object does not read it
+            CExprKind::Unary { op: "&", expr: place } => {
+                let mut operands = Vec::new();
+                place_operands(place, &mut operands);
+                for expr in operands {
+                    self.visit_expr(expr);
+                }
+                return;
+            }
+            CExprKind::Call { callee, .. } if !is_pure_helper(callee) => self.writes = true,
+            CExprKind::Cast { ty, .. } if is_volatile(ty) => self.writes = true,
+            CExprKind::Ternary { .. } | CExprKind::Binary { op: "&&" | "||", .. } => {
+                self.conditional = true
+            }
+            CExprKind::Unary { op: "*", .. }
+            | CExprKind::Index { .. }
+            | CExprKind::Member { arrow: true, .. } => self.reads_memory = true,
+            _ => {}
+        }
+        walk_expr(self, expr)
+    }
+}
+
+/// The arithmetic helpers of `rust_runtime.h`, which neither read nor write memory, unlike
+/// e.g. `__rust_memcpy` and the `__rust_ckd_*` overflow checks storing their result.
+fn is_pure_helper(callee: CExpr<'_>) -> bool {
+    let CExprKind::Raw(name) = *callee else { return false };
+    name.starts_with("__rust_")
+        && !name.starts_with("__rust_mem")
+        && !name.starts_with("__rust_ckd_")
+}
+
+/// Whether the type or a type it points to is volatile.
+fn is_volatile(ty: CTy<'_>) -> bool {
+    ty.quals.contains(CTyQuals::VOLATILE) || ty.pointee().is_some_and(is_volatile)
+}
+
+/// The operands of an lvalue, which are evaluated to find the object it designates, e.g. `_1`
+/// for `_1->a`.
+fn place_operands<'mx>(place: CExpr<'mx>, operands: &mut Vec<CExpr<'mx>>) {
+    match *place {
+        CExprKind::Value(_) => {}
+        CExprKind::Member { expr, arrow: false, .. } => place_operands(expr, operands),
+        CExprKind::Member { expr, arrow: true, .. } | CExprKind::Unary { op: "*", expr } => {
+            operands.push(expr)
+        }
+        CExprKind::Index { expr, index } => operands.extend([expr, index]),
+        _ => operands.push(place),
+    }
+}
+
+/// The operands of a statement, which are evaluated in unspecified order before its effect,
+/// i.e. before the assignment, call, return or jump.
+fn operands(stmt: CStmt<'_>) -> Option<Vec<CExpr<'_>>> {
+    fn expr_operands<'mx>(expr: CExpr<'mx>, operands: &mut Vec<CExpr<'mx>>) {
+        match *expr {
+            CExprKind::Binary { lhs, rhs, op: "=" } => {
+                place_operands(lhs, operands);
+                expr_operands(rhs, operands);
+            }
+            CExprKind::Call { callee, ref args } => {
+                operands.push(callee);
+                operands.extend(args.iter());
+            }
+            _ => operands.push(expr),
+        }
+    }
+
+    let mut operands = Vec::new();
+    match *stmt {
+        CStmtKind::Expr(expr)
+        | CStmtKind::Return(Some(expr))
+        | CStmtKind::Decl(&CDeclKind::Var { init: Some(expr), .. }) => {
+            expr_operands(expr, &mut operands)
+        }
+        CStmtKind::If { cond: expr, .. } | CStmtKind::Switch { value: expr, .. } => {
+            operands.push(expr)
+        }
+        _ => return None,
+    }
+    Some(operands)
+}
+
+struct Inliner<'a, 'mx> {
+    mcx: ModuleCtxt<'mx>,
+    globals: &'a GlobalTypes<'mx>,
+    locals: Locals<'mx>,
+}
+
+impl<'a, 'mx> Inliner<'a, 'mx> {
+    fn inline_func(&self, func: CFunc<'mx>) {
+        for bb in func.0.basic_blocks() {
+            self.inline_bb(bb);
+        }
+    }
+
+    /// Inline the temporaries of a block in order, so that a chain of temporaries is inlined
+    /// into the last one.
+    fn inline_bb(&self, bb: &'mx CBasicBlock<'mx>) {
+        let mut stmts: Vec<_> = bb.stmts.take().into_iter().map(Some).collect();
+        for i in 0..stmts.len() {
+            let Some(CStmtKind::Decl(&CDeclKind::Var { name, ty, init: Some(init), .. })) =
+                stmts[i]
+            else {
+                continue;
+            };
+            if !self.is_temp(name, ty, init) {
+                continue;
+            }
+
+            let Some(j) =
+                (i + 1..stmts.len()).find(|&j| stmts[j].is_some_and(|s| mentions(s, name)))
+            else {
+                continue;
+            };
+            let between = stmts[i + 1..j].iter().flatten().copied();
+            let Some(consumer) = stmts[j] else { continue };
+            if !self.can_move(init, between, consumer) {
+                continue;
+            }
+            let Some(expr) = self.converted(init, ty) else { continue };
+
+            stmts[j] = Some(Substitute { mcx: self.mcx, var: name, expr }.fold_stmt(consumer));
+            stmts[i] = None;
+        }
+        *bb.stmts.borrow_mut() = stmts.into_iter().flatten().collect();
+    }
+
+    fn is_temp(&self, name: CValue<'mx>, ty: CTy<'mx>, init: CExpr<'mx>) -> bool {
+        // arrays are not values, so they cannot be initialized with an expression
+        let is_array = ty.pointee().is_some() && !ty.is_ptr();
+        !is_array
+            && matches!(name, CValue::Local(_))
+            && self.locals.uses.get(&name) == Some(&1)
+            && !self.locals.memory.contains(&name)
+            && ty.quals.is_empty()
+            && !matches!(init, CExprKind::InitList { .. } | CExprKind::Str(_))
+    }
+
+    /// Whether `init` can be moved past the statements `between` into the operands of
+    /// `consumer`. An initializer with side effects, e.g. a call, is only moved into the next
+    /// statement, and only if the other operands neither access memory nor are evaluated
+    /// conditionally, so that it is evaluated exactly once and in the same order.
+    fn can_move(
+        &self,
+        init: CExpr<'mx>,
+        between: impl Iterator<Item = CStmt<'mx>>,
+        consumer: CStmt<'mx>,
+    ) -> bool {
+        let memory = &self.locals.memory;
+        let init = Effects::of(memory, &[init]);
+        let conflicts = |effects: &Effects<'_, 'mx>| {
+            init.reads_memory && effects.writes
+                || !init.reads.is_disjoint(&effects.assigns)
+                || init.writes && (effects.writes || effects.reads_memory || effects.conditional)
+        };
+
+        let mut between = between.peekable();
+        if init.writes && between.peek().is_some() {
+            return false;
+        }
+        for stmt in between {
+            let mut effects = Effects { memory: Some(memory), ..Default::default() };
+            effects.visit_stmt(stmt);
+            if conflicts(&effects) {
+                return false;
+            }
+        }
+        let Some(operands) = operands(consumer) else { return false };
+        !conflicts(&Effects::of(memory, &operands))
+    }
+
+    /// The initializer as an expression of the type of the local, if it can be converted.
+    fn converted(&self, init: CExpr<'mx>, ty: CTy<'mx>) -> Option<CExpr<'mx>> {
+        let init_ty = self.globals.type_of(&self.locals.types, init);
+        if init_ty.is_some_and(|init_ty| init_ty.base == ty.base) {
+            Some(init)
+        } else if ty.record().is_none() {
+            Some(self.mcx.cast(ty, init))
+        } else {
+            None
+        }
+    }
+}
+
+/// Whether a statement uses a value.
+fn mentions<'mx>(stmt: CStmt<'mx>, value: CValue<'mx>) -> bool {
+    struct Mentions<'mx>(CValue<'mx>, bool);
+
+    impl<'mx> Visitor<'mx> for Mentions<'mx> {
+        fn visit_value(&mut self, value: CValue<'mx>) {
+            self.1 |= value == self.0;
+        }
+    }
+
+    let mut mentions = Mentions(value, false);
+    mentions.visit_stmt(stmt);
+    mentions.1
+}
+
+/// Replaces the uses of a variable with an expression.
+struct Substitute<'mx> {
+    mcx: ModuleCtxt<'mx>,
+    var: CValue<'mx>,
+    expr: CExpr<'mx>,
+}
+
+impl<'mx> Folder<'mx> for Substitute<'mx> {
+    fn mcx(&self) -> ModuleCtxt<'mx> {
+        self.mcx
+    }
+
+    fn fold_expr(&mut self, expr: CExpr<'mx>) -> CExpr<'mx> {
+        match *expr {
+            CExprKind::Value(value) if value == self.var => self.expr,
+            _ => walk_expr_mut(self, expr),
+        }
+    }
+}
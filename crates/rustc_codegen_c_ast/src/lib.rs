@@ -7,21 +7,30 @@ use crate::r#type::{CTyBase, CTyKind};
 
 extern crate bitflags;
 extern crate rustc_arena;
-extern crate rustc_ast_pretty;
 extern crate rustc_data_structures;
+#[cfg(test)]
+extern crate rustc_driver;
 extern crate rustc_hash;
 extern crate rustc_target;
 extern crate rustc_type_ir;
 
 pub mod arena;
+pub mod cfg;
+pub mod const_fold;
 pub mod decl;
+pub mod dump;
 pub mod expr;
 pub mod func;
+pub mod infer;
+pub mod inline;
 mod intern;
 pub mod module;
 pub mod pretty;
 pub mod stmt;
+pub mod structure;
 pub mod r#type;
+pub mod verify;
+pub mod visit;
 
 #[derive(Clone, Copy)]
 pub struct ModuleCtxt<'mx>(pub &'mx ModuleArena<'mx>);
@@ -75,3 +84,79 @@ impl<'mx> Default for ModuleArena<'mx> {
         Self::new()
     }
 }
+
+/// Turn a name into the stem of a C identifier, e.g. `Foo<'_, u8>` to `Foo_u8`. Runs of other
+/// characters than ASCII letters and digits become a single `_`, and leading underscores and
+/// digits and trailing underscores are dropped, so that a suffix like `_12` keeps the identifier
+/// unique and apart from reserved identifiers and C keywords. The result is empty if nothing is
+/// left of the name.
+pub fn sanitize_ident(name: &str) -> String {
+    let mut ident = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            if !ident.is_empty() || c.is_ascii_alphabetic() {
+                ident.push(c);
+            }
+        } else if !ident.is_empty() && !ident.ends_with('_') {
+            ident.push('_');
+        }
+    }
+    ident.truncate(ident.trim_end_matches('_').len());
+    ident
+}
+
+/// Whether an identifier cannot be used as is in the generated code: a C keyword, or an
+/// object-like macro of the standard headers it includes, e.g. `int`, `true` or `INT32_MAX`.
+pub fn is_reserved_ident(ident: &str) -> bool {
+    const RESERVED: &[&str] = &[
+        "alignas",
+        "alignof",
+        "auto",
+        "bool",
+        "break",
+        "case",
+        "char",
+        "const",
+        "constexpr",
+        "continue",
+        "default",
+        "do",
+        "double",
+        "else",
+        "enum",
+        "extern",
+        "false",
+        "float",
+        "for",
+        "goto",
+        "if",
+        "inline",
+        "int",
+        "long",
+        "NULL",
+        "nullptr",
+        "register",
+        "restrict",
+        "return",
+        "short",
+        "signed",
+        "sizeof",
+        "static",
+        "static_assert",
+        "struct",
+        "switch",
+        "thread_local",
+        "true",
+        "typedef",
+        "typeof",
+        "typeof_unqual",
+        "union",
+        "unsigned",
+        "void",
+        "volatile",
+        "while",
+    ];
+    let is_limit = ident.bytes().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == b'_')
+        && ["_MIN", "_MAX", "_WIDTH"].iter().any(|suffix| ident.ends_with(suffix));
+    RESERVED.contains(&ident) || is_limit
+}
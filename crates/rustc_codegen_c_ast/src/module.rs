@@ -1,29 +1,176 @@
 use std::cell::RefCell;
 
-use crate::decl::CDecl;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::decl::{CDecl, CDeclKind};
+use crate::expr::CExpr;
 use crate::func::CFunc;
-use crate::pretty::Printer;
+use crate::pretty::{CStd, Printer};
+use crate::r#type::{CPTy, CRecord, CTy, CTyBase, CTyKind};
+use crate::stmt::SourceLoc;
+use crate::visit::{walk_ty, Visitor};
 
 #[derive(Debug, Clone)]
 pub struct Module<'mx> {
     pub includes: RefCell<Vec<&'static str>>,
+    pub records: RefCell<Vec<CTy<'mx>>>,
     pub decls: RefCell<Vec<CDecl<'mx>>>,
     pub funcs: RefCell<Vec<CFunc<'mx>>>,
+    /// Conditions checked by the C compiler, e.g. that record layouts match rustc's.
+    pub static_asserts: RefCell<Vec<CExpr<'mx>>>,
+    /// The source locations of statements, see [`ModuleCtxt::set_loc`](crate::ModuleCtxt::set_loc).
+    pub locs: RefCell<FxHashMap<usize, SourceLoc>>,
+    /// The prefix of the numbers of global variables, e.g. `abc_` in `_gabc_0`, which tells the
+    /// globals of modules linked together apart.
+    pub global_prefix: RefCell<String>,
 }
 
 impl<'mx> Module<'mx> {
     pub fn new() -> Self {
         Self {
             includes: RefCell::new(Vec::new()),
+            records: RefCell::new(Vec::new()),
             decls: RefCell::new(Vec::new()),
             funcs: RefCell::new(Vec::new()),
+            static_asserts: RefCell::new(Vec::new()),
+            locs: RefCell::new(FxHashMap::default()),
+            global_prefix: RefCell::new(String::new()),
         }
     }
 
+    pub fn set_global_prefix(&self, prefix: String) {
+        *self.global_prefix.borrow_mut() = prefix;
+    }
+
     pub fn push_includes(&self, includes: &[&'static str]) {
         self.includes.borrow_mut().extend(includes);
     }
 
+    pub fn push_record(&self, record: CTy<'mx>) {
+        self.records.borrow_mut().push(record);
+    }
+
+    /// The records of the module, sorted so that every record comes after the records it
+    /// contains by value.
+    pub fn records_in_dependency_order(&self) -> Vec<&'mx CRecord<'mx>> {
+        fn visit<'mx>(
+            record: &'mx CRecord<'mx>,
+            visited: &mut FxHashSet<&'mx str>,
+            order: &mut Vec<&'mx CRecord<'mx>>,
+        ) {
+            if !visited.insert(record.name) {
+                return;
+            }
+            for field in record.fields() {
+                if let Some(dep) = value_dependency(field.ty) {
+                    visit(dep, visited, order);
+                }
+            }
+            order.push(record);
+        }
+
+        /// The record a type contains by value, looking through arrays but not pointers.
+        fn value_dependency(mut ty: CTy<'_>) -> Option<&CRecord<'_>> {
+            while let CTyBase::Ref(kind) = ty.base {
+                match kind.0 {
+                    CTyKind::Record(record) => return Some(record),
+                    CTyKind::Array(elem, _) => ty = *elem,
+                    CTyKind::Pointer(_) | CTyKind::FnPtr(_) => return None,
+                }
+            }
+            None
+        }
+
+        let mut visited = FxHashSet::default();
+        let mut order = Vec::new();
+        for &record in self.records.borrow().iter() {
+            visit(record.record().unwrap(), &mut visited, &mut order);
+        }
+        order
+    }
+
+    /// The records used by the externally visible functions and variables of the module, directly
+    /// or through other records, in dependency order.
+    pub fn exported_records(&self) -> Vec<&'mx CRecord<'mx>> {
+        fn visit<'mx>(ty: CTy<'mx>, used: &mut FxHashSet<&'mx str>) {
+            let CTyBase::Ref(kind) = ty.base else { return };
+            match kind.0 {
+                CTyKind::Pointer(ty) | CTyKind::Array(ty, _) => visit(*ty, used),
+                CTyKind::FnPtr(fn_ptr) => {
+                    visit(fn_ptr.ret, used);
+                    for &arg in fn_ptr.args.iter() {
+                        visit(arg, used);
+                    }
+                }
+                CTyKind::Record(record) => {
+                    if used.insert(record.name) {
+                        for field in record.fields() {
+                            visit(field.ty, used);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut used = FxHashSet::default();
+        for &decl in self.decls.borrow().iter() {
+            match decl {
+                CDeclKind::Var { ty, linkage, .. } if linkage.is_exported() => {
+                    visit(*ty, &mut used)
+                }
+                _ => {}
+            }
+        }
+        for &func in self.funcs.borrow().iter() {
+            if is_header_func(func) {
+                visit(func.0.ty, &mut used);
+            }
+        }
+        let mut records = self.records_in_dependency_order();
+        records.retain(|record| used.contains(record.name));
+        records
+    }
+
+    /// The primitive types of the externally visible functions and variables of the module and
+    /// of the fields of `records`.
+    fn exported_primitives(&self, records: &[&'mx CRecord<'mx>]) -> FxHashSet<CPTy> {
+        struct Primitives(FxHashSet<CPTy>);
+
+        impl<'mx> Visitor<'mx> for Primitives {
+            fn visit_ty(&mut self, ty: CTy<'mx>) {
+                if let CTyBase::Primitive(ty) = ty.base {
+                    self.0.insert(ty);
+                }
+                walk_ty(self, ty)
+            }
+        }
+
+        let mut primitives = Primitives(FxHashSet::default());
+        for &decl in self.decls.borrow().iter() {
+            match decl {
+                CDeclKind::Var { ty, linkage, .. } if linkage.is_exported() => {
+                    primitives.visit_ty(*ty)
+                }
+                _ => {}
+            }
+        }
+        for &func in self.funcs.borrow().iter() {
+            if is_header_func(func) {
+                primitives.visit_ty(func.0.ty);
+            }
+        }
+        for record in records {
+            for field in record.fields() {
+                primitives.visit_ty(field.ty);
+            }
+        }
+        primitives.0
+    }
+
+    pub fn push_static_assert(&self, cond: CExpr<'mx>) {
+        self.static_asserts.borrow_mut().push(cond);
+    }
+
     pub fn push_decl(&self, decl: CDecl<'mx>) {
         self.decls.borrow_mut().push(decl);
     }
@@ -41,18 +188,29 @@ impl<'mx> Default for Module<'mx> {
 
 impl Printer {
     pub fn print_module(&mut self, module: &Module) {
+        self.set_locs(&module.locs.borrow());
+        self.global_prefix = module.global_prefix.borrow().clone();
         self.cbox(0, |this| {
-            for &include in module.includes.borrow().iter() {
-                this.word("#include <");
-                this.word(include);
-                this.word(">");
-                this.hardbreak();
-            }
+            this.print_includes(module);
 
             this.hardbreak();
             this.word("/* rustc_codegen_c: interface */");
             this.hardbreak();
 
+            let records = module.records_in_dependency_order();
+            for record in &records {
+                this.hardbreak();
+                this.word(format!("{} {};", record.kind.to_str(), record.name));
+            }
+            for record in &records {
+                this.hardbreak();
+                this.print_record_def(record);
+            }
+            for (i, &cond) in module.static_asserts.borrow().iter().enumerate() {
+                this.hardbreak();
+                this.print_static_assert(i, cond);
+            }
+
             for &decl in module.decls.borrow().iter() {
                 this.hardbreak();
                 this.print_decl(decl, true);
@@ -76,4 +234,114 @@ impl Printer {
             this.hardbreak();
         });
     }
+
+    /// Print a header declaring the externally visible functions and variables of a module and
+    /// defining the records they use, for C code linked with the module. The header is guarded
+    /// by the macro `guard`.
+    ///
+    /// The header only includes the standard headers of the types it uses, since a C program
+    /// does not have `rust_runtime.h`. The 128-bit integer types of `rust_runtime.h` are defined
+    /// as `__int128`, which matches the runtime unless it emulates them.
+    pub fn print_header(&mut self, module: &Module, guard: &str) {
+        self.global_prefix = module.global_prefix.borrow().clone();
+        self.cbox(0, |this| {
+            this.word(format!("#ifndef {guard}"));
+            this.hardbreak();
+            this.word(format!("#define {guard}"));
+            this.hardbreak();
+            this.hardbreak();
+
+            let records = module.exported_records();
+            let primitives = module.exported_primitives(&records);
+            if primitives.contains(&CPTy::Bool) {
+                this.print_include("stdbool.h");
+            }
+            let is_stdint = |ty: &CPTy| {
+                !matches!(ty, CPTy::Void | CPTy::Bool | CPTy::Char)
+                    && !ty.is_float()
+                    && !ty.is_int128()
+            };
+            if primitives.iter().any(is_stdint) {
+                this.print_include("stdint.h");
+            }
+            if primitives.contains(&CPTy::I128) {
+                this.word("typedef __int128 __rust_i128;");
+                this.hardbreak();
+            }
+            if primitives.contains(&CPTy::U128) {
+                this.word("typedef unsigned __int128 __rust_u128;");
+                this.hardbreak();
+            }
+
+            for record in &records {
+                this.hardbreak();
+                this.word(format!("{} {};", record.kind.to_str(), record.name));
+            }
+            for record in &records {
+                this.hardbreak();
+                this.print_record_def(record);
+            }
+
+            for &decl in module.decls.borrow().iter() {
+                match decl {
+                    CDeclKind::Var { name, ty, linkage, .. } if linkage.is_exported() => {
+                        this.hardbreak();
+                        this.ibox(this.indent, |this| {
+                            this.word("extern ");
+                            this.print_ty_decl(*ty, Some(*name));
+                            this.word(";");
+                        });
+                    }
+                    _ => {}
+                }
+            }
+            for &func in module.funcs.borrow().iter() {
+                if is_header_func(func) {
+                    this.hardbreak();
+                    this.print_func_decl(func);
+                }
+            }
+
+            this.hardbreak();
+            this.hardbreak();
+            this.word(format!("#endif /* {guard} */"));
+            this.hardbreak();
+        });
+    }
+
+    fn print_includes(&mut self, module: &Module) {
+        for &include in module.includes.borrow().iter() {
+            self.print_include(include);
+        }
+    }
+
+    fn print_include(&mut self, include: &str) {
+        self.word("#include <");
+        self.word(include.to_string());
+        self.word(">");
+        self.hardbreak();
+    }
+
+    /// Print a static assertion, which is emulated before C11 by an array type whose size is
+    /// negative if the condition fails, named after the index of the assertion.
+    fn print_static_assert(&mut self, i: usize, cond: CExpr) {
+        match self.config().c_std {
+            CStd::C89 | CStd::C99 => {
+                self.word(format!("typedef char __rust_static_assert_{i}[("));
+                self.print_expr(cond, true);
+                self.word(") ? 1 : -1];");
+            }
+            CStd::C11 | CStd::C17 | CStd::C23 => {
+                self.word("_Static_assert(");
+                self.print_expr(cond, true);
+                self.word(", \"layout mismatch\");");
+            }
+        }
+    }
+}
+
+/// Whether a function is declared in the module header. The C program including the header has a
+/// `main` of its own, so neither the entry point nor a Rust function named `main` is declared.
+fn is_header_func(func: CFunc<'_>) -> bool {
+    func.0.linkage.is_exported() && !func.0.is_main() && func.0.name != "main"
 }
@@ -1,16 +1,115 @@
 //! Pretty printing
 
-// TODO: pretty printing test
-
 use std::borrow::Cow;
 
-use rustc_ast_pretty::pp;
+use rustc_hash::FxHashMap;
+
+use crate::expr::str_literal;
+use crate::stmt::{stmt_key, CStmt, CStmtKind, SourceLoc};
 
-pub const INDENT: isize = 2;
+mod pp;
+#[cfg(test)]
+mod tests;
+
+/// The layout of printed C.
+#[derive(Debug, Clone)]
+pub struct PrinterConfig {
+    /// The width that lines are broken to fit in where possible.
+    pub width: usize,
+    /// The number of columns of each level of indentation.
+    pub indent: usize,
+    pub brace_style: BraceStyle,
+    /// Whether binary operators other than assignment are surrounded by spaces, e.g. `a + b`
+    /// rather than `a+b`.
+    pub spaced_ops: bool,
+    pub source_locs: SourceLocs,
+    pub c_std: CStd,
+}
+
+impl Default for PrinterConfig {
+    fn default() -> Self {
+        Self {
+            width: 78,
+            indent: 2,
+            brace_style: BraceStyle::SameLine,
+            spaced_ops: true,
+            source_locs: SourceLocs::Off,
+            c_std: CStd::C11,
+        }
+    }
+}
+
+/// Where the opening brace of a function body or compound statement goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BraceStyle {
+    /// At the end of the line that starts the function or statement, e.g. `if (x) {`.
+    SameLine,
+    /// On a line of its own, at the indentation of the function or statement.
+    NextLine,
+}
+
+/// How the Rust source locations of statements are printed. A location is printed on the line
+/// before a statement when it differs from the location of the statement printed before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceLocs {
+    Off,
+    /// As comments, e.g. `/* src/main.rs:12 */`.
+    Comments,
+    /// As `#line` directives, e.g. `#line 12 "src/main.rs"`, which make the C compiler and
+    /// debuggers refer to the Rust source. The lines after a function are pointed back at the
+    /// C file by [`resolve_line_resets`].
+    Lines,
+}
+
+/// The C standard that the printed code conforms to, with the GNU extensions it relies on, e.g.
+/// `__attribute__` and `__int128`. Later standards compare greater.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CStd {
+    /// Declarations are hoisted to the start of functions, floating-point constants are printed
+    /// in decimal, and the keywords of C11 are replaced by GNU extensions, e.g. `__alignof__`.
+    C89,
+    C99,
+    C11,
+    C17,
+    /// Attributes in `[[...]]`. The C11 keywords such as `_Alignas` are kept, since compilers
+    /// before GCC 13 and Clang 16 lack their C23 spellings.
+    C23,
+}
+
+/// The line printed after a function with `#line` directives, in place of a directive back to the
+/// C file, whose name and line numbers are only known when the output is written.
+const LINE_RESET: &str = "#line __rustc_codegen_c_reset__";
+
+/// Replace the line resets of printed C with `#line` directives back to the C file `file`, whose
+/// line `first_line` is the first line of `source`.
+pub fn resolve_line_resets(source: &str, file: &str, first_line: usize) -> String {
+    let mut out = String::with_capacity(source.len());
+    for (i, line) in source.split_inclusive('\n').enumerate() {
+        match line.strip_prefix(LINE_RESET) {
+            Some(rest) => {
+                let next = first_line + i + 1;
+                out.push_str(&format!("#line {next} {}{rest}", str_literal(file.as_bytes())));
+            }
+            None => out.push_str(line),
+        }
+    }
+    out
+}
 
 /// Pretty printer
 pub struct Printer {
     pp: pp::Printer,
+    config: PrinterConfig,
+    /// The indentation of a nested box
+    pub(crate) indent: isize,
+    /// The source locations of statements, if they are printed
+    locs: FxHashMap<usize, SourceLoc>,
+    /// The source location printed last in the current function
+    last_loc: Option<SourceLoc>,
+    /// The prefix of the numbers of global variables
+    pub(crate) global_prefix: String,
+    /// The name hints of the locals of the function being printed
+    pub(crate) local_names: FxHashMap<usize, String>,
 }
 
 impl Default for Printer {
@@ -21,13 +120,83 @@ impl Default for Printer {
 
 impl Printer {
     pub fn new() -> Self {
-        Self { pp: pp::Printer::new() }
+        Self::with_config(PrinterConfig::default())
+    }
+
+    pub fn with_config(config: PrinterConfig) -> Self {
+        Self {
+            pp: pp::Printer::new(config.width),
+            indent: config.indent as isize,
+            config,
+            locs: FxHashMap::default(),
+            last_loc: None,
+            global_prefix: String::new(),
+            local_names: FxHashMap::default(),
+        }
+    }
+
+    pub(crate) fn config(&self) -> &PrinterConfig {
+        &self.config
+    }
+
+    /// An attribute specifier of GNU attributes, e.g. `__attribute__((packed, aligned(4)))`, or
+    /// `[[gnu::packed, gnu::aligned(4)]]` in C23.
+    pub(crate) fn attr_spec(&self, attrs: &[String]) -> String {
+        if self.config.c_std >= CStd::C23 {
+            let attrs: Vec<_> = attrs.iter().map(|attr| format!("gnu::{attr}")).collect();
+            format!("[[{}]]", attrs.join(", "))
+        } else {
+            format!("__attribute__(({}))", attrs.join(", "))
+        }
     }
 
     pub fn finish(self) -> String {
         self.pp.eof()
     }
 
+    pub(crate) fn set_locs(&mut self, locs: &FxHashMap<usize, SourceLoc>) {
+        if self.config.source_locs != SourceLocs::Off {
+            self.locs = locs.clone();
+        }
+    }
+
+    /// Print the source location of a statement followed by a line break, if it differs from the
+    /// last one. Labels are not code and are never preceded by a location.
+    pub(crate) fn print_loc(&mut self, stmt: CStmt) {
+        if let CStmtKind::Label(_) = stmt {
+            return;
+        }
+        let Some(loc) = self.locs.get(&stmt_key(stmt)) else { return };
+        if self.last_loc.as_ref() == Some(loc) {
+            return;
+        }
+        let loc = loc.clone();
+        match self.config.source_locs {
+            SourceLocs::Off => return,
+            SourceLocs::Comments => {
+                self.word(format!("/* {}:{} */", loc.file.replace("*/", "* /"), loc.line))
+            }
+            SourceLocs::Lines => {
+                self.word(format!("#line {} {}", loc.line, str_literal(loc.file.as_bytes())))
+            }
+        }
+        self.hardbreak();
+        self.last_loc = Some(loc);
+    }
+
+    /// Start the source locations of a function.
+    pub(crate) fn begin_func_locs(&mut self) {
+        self.last_loc = None;
+    }
+
+    /// End the source locations of a function, pointing the following lines back at the C file.
+    pub(crate) fn end_func_locs(&mut self) {
+        if self.last_loc.take().is_some() && self.config.source_locs == SourceLocs::Lines {
+            self.hardbreak();
+            self.word(LINE_RESET);
+        }
+    }
+
     pub(crate) fn seperated<T>(
         &mut self,
         sep: &'static str,
@@ -111,6 +280,19 @@ impl Printer {
         self.pp.hardbreak();
     }
 
+    /// Hard break with the indentation of the box adjusted by `offset`
+    pub(crate) fn hardbreak_offset(&mut self, offset: isize) {
+        self.pp.break_offset(pp::SIZE_INFINITY as usize, offset);
+    }
+
+    /// The break before the opening brace of a function body or compound statement
+    pub(crate) fn brace_break(&mut self) {
+        match self.config.brace_style {
+            BraceStyle::SameLine => self.nbsp(),
+            BraceStyle::NextLine => self.hardbreak(),
+        }
+    }
+
     /// Zero break: nothing if fits, otherwise newline
     pub(crate) fn zerobreak(&mut self) {
         self.pp.zerobreak();
@@ -0,0 +1,388 @@
+//! The pretty printing algorithm of Oppen, "Pretty Printing" (1979).
+//!
+//! This is the implementation of `rustc_ast_pretty::pp`, whose target line width is a constant,
+//! with the width made a parameter. Trailing commas, which C does not need, are left out.
+//!
+//! The SCAN process measures the size of boxes and breaks as tokens are added to a ring buffer,
+//! and the PRINT process decides where to break lines once the size of a token is known, or
+//! once it is known not to fit on the line.
+
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::{cmp, iter};
+
+/// The size of a token that never fits on a line.
+pub(crate) const SIZE_INFINITY: isize = 0xffff;
+
+/// How to break.
+#[derive(Clone, Copy, PartialEq)]
+enum Breaks {
+    /// Either all breaks of the box are newlines or none are.
+    Consistent,
+    /// Only the breaks needed to fit the line are newlines.
+    Inconsistent,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum IndentStyle {
+    /// Vertically aligned under the column the box begins at.
+    Visual,
+    /// Indented relative to the indentation of the enclosing box.
+    Block { offset: isize },
+}
+
+#[derive(Clone, Copy)]
+struct BreakToken {
+    offset: isize,
+    blank_space: isize,
+}
+
+#[derive(Clone, Copy)]
+struct BeginToken {
+    indent: IndentStyle,
+    breaks: Breaks,
+}
+
+enum Token {
+    String(Cow<'static, str>),
+    Break(BreakToken),
+    Begin(BeginToken),
+    End,
+}
+
+#[derive(Copy, Clone)]
+enum PrintFrame {
+    Fits,
+    Broken { indent: usize, breaks: Breaks },
+}
+
+struct BufEntry {
+    token: Token,
+    size: isize,
+}
+
+pub(crate) struct Printer {
+    out: String,
+    /// Target line width
+    margin: isize,
+    /// Every line is allowed at least this much space, even if highly indented
+    min_space: isize,
+    /// Number of spaces left on line
+    space: isize,
+    /// Tokens whose size is not known yet, indexed by their position in the whole stream
+    buf: RingBuffer<BufEntry>,
+    /// Running size of the stream printed so far
+    left_total: isize,
+    /// Running size of the stream scanned so far
+    right_total: isize,
+    /// Buffer indices of the unclosed `Begin` tokens and the most recent break after each
+    scan_stack: VecDeque<usize>,
+    /// Stack of boxes being printed
+    print_stack: Vec<PrintFrame>,
+    /// Level of indentation of the current line
+    indent: usize,
+    /// Buffered indentation to avoid writing trailing whitespace
+    pending_indentation: isize,
+}
+
+impl Printer {
+    pub(crate) fn new(margin: usize) -> Self {
+        let margin = margin as isize;
+        Printer {
+            out: String::new(),
+            margin,
+            min_space: cmp::min(60, margin),
+            space: margin,
+            buf: RingBuffer::new(),
+            left_total: 0,
+            right_total: 0,
+            scan_stack: VecDeque::new(),
+            print_stack: Vec::new(),
+            indent: 0,
+            pending_indentation: 0,
+        }
+    }
+
+    pub(crate) fn ibox(&mut self, indent: isize) {
+        self.scan_begin(BeginToken {
+            indent: IndentStyle::Block { offset: indent },
+            breaks: Breaks::Inconsistent,
+        })
+    }
+
+    pub(crate) fn cbox(&mut self, indent: isize) {
+        self.scan_begin(BeginToken {
+            indent: IndentStyle::Block { offset: indent },
+            breaks: Breaks::Consistent,
+        })
+    }
+
+    pub(crate) fn visual_align(&mut self) {
+        self.scan_begin(BeginToken { indent: IndentStyle::Visual, breaks: Breaks::Consistent });
+    }
+
+    pub(crate) fn break_offset(&mut self, n: usize, offset: isize) {
+        self.scan_break(BreakToken { offset, blank_space: n as isize });
+    }
+
+    pub(crate) fn end(&mut self) {
+        self.scan_end()
+    }
+
+    pub(crate) fn eof(mut self) -> String {
+        self.scan_eof();
+        self.out
+    }
+
+    pub(crate) fn word(&mut self, word: impl Into<Cow<'static, str>>) {
+        self.scan_string(word.into())
+    }
+
+    pub(crate) fn word_space(&mut self, word: impl Into<Cow<'static, str>>) {
+        self.word(word);
+        self.space();
+    }
+
+    pub(crate) fn nbsp(&mut self) {
+        self.word(" ")
+    }
+
+    pub(crate) fn zerobreak(&mut self) {
+        self.break_offset(0, 0)
+    }
+
+    pub(crate) fn space(&mut self) {
+        self.break_offset(1, 0)
+    }
+
+    pub(crate) fn hardbreak(&mut self) {
+        self.break_offset(SIZE_INFINITY as usize, 0)
+    }
+
+    fn scan_eof(&mut self) {
+        if !self.scan_stack.is_empty() {
+            self.check_stack(0);
+            self.advance_left();
+        }
+    }
+
+    fn scan_begin(&mut self, token: BeginToken) {
+        if self.scan_stack.is_empty() {
+            self.left_total = 1;
+            self.right_total = 1;
+            self.buf.clear();
+        }
+        let right = self.buf.push(BufEntry { token: Token::Begin(token), size: -self.right_total });
+        self.scan_stack.push_back(right);
+    }
+
+    fn scan_end(&mut self) {
+        if self.scan_stack.is_empty() {
+            self.print_end();
+        } else {
+            let right = self.buf.push(BufEntry { token: Token::End, size: -1 });
+            self.scan_stack.push_back(right);
+        }
+    }
+
+    fn scan_break(&mut self, token: BreakToken) {
+        if self.scan_stack.is_empty() {
+            self.left_total = 1;
+            self.right_total = 1;
+            self.buf.clear();
+        } else {
+            self.check_stack(0);
+        }
+        let right = self.buf.push(BufEntry { token: Token::Break(token), size: -self.right_total });
+        self.scan_stack.push_back(right);
+        self.right_total += token.blank_space;
+    }
+
+    fn scan_string(&mut self, string: Cow<'static, str>) {
+        if self.scan_stack.is_empty() {
+            self.print_string(&string);
+        } else {
+            let len = string.len() as isize;
+            self.buf.push(BufEntry { token: Token::String(string), size: len });
+            self.right_total += len;
+            self.check_stream();
+        }
+    }
+
+    fn check_stream(&mut self) {
+        while self.right_total - self.left_total > self.space {
+            if *self.scan_stack.front().unwrap() == self.buf.index_of_first() {
+                self.scan_stack.pop_front().unwrap();
+                self.buf.first_mut().unwrap().size = SIZE_INFINITY;
+            }
+            self.advance_left();
+            if self.buf.is_empty() {
+                break;
+            }
+        }
+    }
+
+    fn advance_left(&mut self) {
+        while self.buf.first().unwrap().size >= 0 {
+            let left = self.buf.pop_first().unwrap();
+
+            match &left.token {
+                Token::String(string) => {
+                    self.left_total += string.len() as isize;
+                    self.print_string(string);
+                }
+                Token::Break(token) => {
+                    self.left_total += token.blank_space;
+                    self.print_break(*token, left.size);
+                }
+                Token::Begin(token) => self.print_begin(*token, left.size),
+                Token::End => self.print_end(),
+            }
+
+            if self.buf.is_empty() {
+                break;
+            }
+        }
+    }
+
+    fn check_stack(&mut self, mut depth: usize) {
+        while let Some(&index) = self.scan_stack.back() {
+            let entry = &mut self.buf[index];
+            match entry.token {
+                Token::Begin(_) => {
+                    if depth == 0 {
+                        break;
+                    }
+                    self.scan_stack.pop_back().unwrap();
+                    entry.size += self.right_total;
+                    depth -= 1;
+                }
+                Token::End => {
+                    self.scan_stack.pop_back().unwrap();
+                    entry.size = 1;
+                    depth += 1;
+                }
+                _ => {
+                    self.scan_stack.pop_back().unwrap();
+                    entry.size += self.right_total;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    fn get_top(&self) -> PrintFrame {
+        *self
+            .print_stack
+            .last()
+            .unwrap_or(&PrintFrame::Broken { indent: 0, breaks: Breaks::Inconsistent })
+    }
+
+    fn print_begin(&mut self, token: BeginToken, size: isize) {
+        if size > self.space {
+            self.print_stack.push(PrintFrame::Broken { indent: self.indent, breaks: token.breaks });
+            self.indent = match token.indent {
+                IndentStyle::Block { offset } => {
+                    usize::try_from(self.indent as isize + offset).unwrap()
+                }
+                IndentStyle::Visual => (self.margin - self.space) as usize,
+            };
+        } else {
+            self.print_stack.push(PrintFrame::Fits);
+        }
+    }
+
+    fn print_end(&mut self) {
+        if let PrintFrame::Broken { indent, .. } = self.print_stack.pop().unwrap() {
+            self.indent = indent;
+        }
+    }
+
+    fn print_break(&mut self, token: BreakToken, size: isize) {
+        let fits = match self.get_top() {
+            PrintFrame::Fits => true,
+            PrintFrame::Broken { breaks: Breaks::Consistent, .. } => false,
+            PrintFrame::Broken { breaks: Breaks::Inconsistent, .. } => size <= self.space,
+        };
+        if fits {
+            self.pending_indentation += token.blank_space;
+            self.space -= token.blank_space;
+        } else {
+            self.out.push('\n');
+            let indent = self.indent as isize + token.offset;
+            self.pending_indentation = indent;
+            self.space = cmp::max(self.margin - indent, self.min_space);
+        }
+    }
+
+    fn print_string(&mut self, string: &str) {
+        self.out.reserve(self.pending_indentation as usize);
+        self.out.extend(iter::repeat(' ').take(self.pending_indentation as usize));
+        self.pending_indentation = 0;
+
+        self.out.push_str(string);
+        self.space -= string.len() as isize;
+    }
+}
+
+/// A view onto the range `left..right` of an infinitely long sequence, where both ends only move
+/// forward.
+struct RingBuffer<T> {
+    data: VecDeque<T>,
+    /// Index of `data[0]` in the sequence
+    offset: usize,
+}
+
+impl<T> RingBuffer<T> {
+    fn new() -> Self {
+        RingBuffer { data: VecDeque::new(), offset: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn push(&mut self, value: T) -> usize {
+        let index = self.offset + self.data.len();
+        self.data.push_back(value);
+        index
+    }
+
+    fn clear(&mut self) {
+        self.data.clear();
+    }
+
+    fn index_of_first(&self) -> usize {
+        self.offset
+    }
+
+    fn first(&self) -> Option<&T> {
+        self.data.front()
+    }
+
+    fn first_mut(&mut self) -> Option<&mut T> {
+        self.data.front_mut()
+    }
+
+    fn pop_first(&mut self) -> Option<T> {
+        let first = self.data.pop_front()?;
+        self.offset += 1;
+        Some(first)
+    }
+}
+
+impl<T> std::ops::Index<usize> for RingBuffer<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.data[index.checked_sub(self.offset).unwrap()]
+    }
+}
+
+impl<T> std::ops::IndexMut<usize> for RingBuffer<T> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        &mut self.data[index.checked_sub(self.offset).unwrap()]
+    }
+}
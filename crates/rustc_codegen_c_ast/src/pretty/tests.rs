@@ -0,0 +1,316 @@
+//! Golden tests of the printer and the JSON dump. The expected output is kept in `tests/`, next to this file, and
+//! is regenerated by running the tests with `BLESS=1`.
+
+use std::num::NonZeroUsize;
+use std::path::Path;
+use std::rc::Rc;
+
+use rustc_data_structures::intern::Interned;
+use rustc_target::abi::call::Conv;
+
+use crate::decl::{CAttr, CLinkage};
+use crate::dump::dump_module;
+use crate::expr::{CExprKind, CValue};
+use crate::func::{CFunc, CFuncKind};
+use crate::pretty::{resolve_line_resets, BraceStyle, CStd, Printer, PrinterConfig, SourceLocs};
+use crate::r#type::{CField, CPTy, CTy};
+use crate::stmt::{CStmt, SourceLoc};
+use crate::{ModuleArena, ModuleCtxt};
+
+fn check_golden(name: &str, actual: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/pretty/tests").join(name);
+    if std::env::var_os("BLESS").is_some() {
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path)
+        .unwrap_or_else(|err| panic!("cannot read `{}`: {err}", path.display()));
+    assert!(
+        expected == actual,
+        "output differs from `{}`, run with BLESS=1 to update it\n{actual}",
+        path.display()
+    );
+}
+
+/// A module with records, globals and functions with each kind of statement, some of which have
+/// source locations, and the constructs that depend on the C standard.
+fn build_module<'mx>(mcx: ModuleCtxt<'mx>) {
+    let i32_ty = CTy::primitive(CPTy::I32);
+    let u8_ty = CTy::primitive(CPTy::U8);
+    let u32_ty = CTy::primitive(CPTy::U32);
+    let binop_ty = mcx.fn_ptr(i32_ty, [i32_ty, i32_ty].into(), Conv::C);
+    let len = NonZeroUsize::new;
+    let float = |x: f64| mcx.value(CValue::F64(x.to_bits()));
+    let file: Rc<str> = "src/lib.rs".into();
+    let at = |line: u32, stmt: CStmt<'mx>| {
+        mcx.set_loc(stmt, SourceLoc { file: file.clone(), line });
+        stmt
+    };
+
+    mcx.module().push_includes(&["stdint.h"]);
+    mcx.module().set_global_prefix("m_".into());
+
+    let point = mcx.record("point", false, None);
+    point
+        .record()
+        .unwrap()
+        .set_fields([CField { name: "x", ty: i32_ty }, CField { name: "y", ty: i32_ty }]);
+    let header = mcx.record("header", true, Some(4));
+    header.record().unwrap().set_fields([
+        CField { name: "tag", ty: u8_ty },
+        CField { name: "len", ty: u32_ty },
+        CField { name: "origin", ty: point },
+        CField { name: "ops", ty: mcx.arr(mcx.ptr(binop_ty), len(2)) },
+    ]);
+    let origin = mcx.value(mcx.scalar(0));
+    mcx.module().push_static_assert(mcx.binary(
+        mcx.offset_of(header, "origin"),
+        mcx.value(mcx.scalar(5)),
+        "==",
+    ));
+
+    let new_func = |name: &'mx str, ty: CTy<'mx>| -> CFunc<'mx> {
+        let func = Interned::new_unchecked(mcx.create_func(CFuncKind::new(name, ty, false)));
+        mcx.module().push_func(func);
+        func
+    };
+    let add = new_func("add", binop_ty);
+    let sub = new_func("sub", binop_ty);
+
+    let ops = mcx.next_global_var();
+    let fn_value = |func: CFunc<'mx>| mcx.value(mcx.fn_ref(func.0.name));
+    mcx.module().push_decl(mcx.global_var(
+        ops,
+        mcx.arr(mcx.ptr(binop_ty), len(2)),
+        Some(mcx.init_list([fn_value(add), fn_value(sub)])),
+        CLinkage { internal: true, ..CLinkage::default() },
+        [],
+    ));
+    mcx.module().push_decl(mcx.global_var(
+        mcx.next_global_var(),
+        point,
+        Some(mcx.init_list([mcx.designated("x", origin), mcx.designated("y", origin)])),
+        CLinkage::default(),
+        [],
+    ));
+    mcx.module().push_decl(mcx.global_var(
+        CValue::Static("limit"),
+        u32_ty,
+        None,
+        CLinkage { external: true, ..CLinkage::default() },
+        [],
+    ));
+
+    let [a, b] = [0, 1].map(|i| mcx.value(CValue::Local(i)));
+    let bb = add.0.new_bb("start", &mcx);
+    bb.push_stmt(at(2, mcx.ret(Some(mcx.binary(a, b, "+")))));
+    let bb = sub.0.new_bb("start", &mcx);
+    bb.push_stmt(at(6, mcx.ret(Some(mcx.binary(a, b, "-")))));
+
+    // int32_t apply(struct header *h, int32_t n)
+    let apply = new_func("apply", mcx.fn_ptr(i32_ty, [mcx.ptr(header), i32_ty].into(), Conv::C));
+    let h = mcx.value(CValue::Local(0));
+    let n = mcx.value(CValue::Local(1));
+    let (acc_var, i_var) = (apply.0.next_local_var(), apply.0.next_local_var());
+    let (acc, i) = (mcx.value(acc_var), mcx.value(i_var));
+    for (var, name) in [(CValue::Local(0), "h"), (CValue::Local(1), "_n"), (acc_var, "acc.0")] {
+        apply.0.set_local_name(var, name);
+    }
+    let one = mcx.value(mcx.scalar(1));
+    let field = |field| mcx.create_expr(CExprKind::Member { expr: h, arrow: true, field });
+    let op = |index: i128| mcx.index(field("ops"), mcx.value(mcx.scalar(index)));
+    let bb = apply.0.new_bb("start", &mcx);
+    bb.push_stmt(at(10, mcx.decl(mcx.var(acc_var, i32_ty, Some(origin)))));
+    bb.push_stmt(at(10, mcx.decl(mcx.var(i_var, i32_ty, Some(origin)))));
+    bb.push_stmt(at(
+        11,
+        mcx.while_stmt(
+            mcx.binary(i, n, "<"),
+            mcx.block([
+                at(
+                    12,
+                    mcx.if_stmt(
+                        mcx.binary(mcx.binary(i, one, "&"), origin, "=="),
+                        mcx.block([at(
+                            13,
+                            mcx.expr(
+                                mcx.assign(acc, mcx.call(op(0), [acc, mcx.binary(i, i, "*")])),
+                            ),
+                        )]),
+                        Some(
+                            mcx.block([at(
+                                15,
+                                mcx.expr(mcx.assign(acc, mcx.call(op(1), [acc, i]))),
+                            )]),
+                        ),
+                    ),
+                ),
+                at(17, mcx.expr(mcx.assign(i, mcx.binary(i, one, "+")))),
+            ]),
+        ),
+    ));
+    bb.push_stmt(mcx.do_while(
+        mcx.block([mcx.expr(mcx.assign(acc, mcx.binary(acc, one, ">>")))]),
+        mcx.binary(acc, mcx.value(mcx.scalar(1000)), ">"),
+    ));
+    bb.push_stmt(mcx.if_stmt(mcx.binary(acc, origin, "<"), mcx.goto("negative"), None));
+    bb.push_stmt(mcx.switch(acc, [(origin, "zero"), (one, "negative")], "done"));
+    let bb = apply.0.new_bb("zero", &mcx);
+    bb.push_stmt(mcx.ret(Some(mcx.call(
+        fn_value(add),
+        [
+            mcx.call(fn_value(sub), [field("len"), mcx.value(mcx.scalar(123456789))]),
+            mcx.call(fn_value(sub), [mcx.value(mcx.scalar(987654321)), field("len")]),
+        ],
+    ))));
+    let bb = apply.0.new_bb("negative", &mcx);
+    bb.push_stmt(mcx.ret(Some(mcx.unary("-", acc))));
+    let bb = apply.0.new_bb("done", &mcx);
+    bb.push_stmt(at(20, mcx.ret(Some(acc))));
+
+    // static double scale(double x), with declarations after statements
+    let f64_ty = CTy::primitive(CPTy::F64);
+    let usize_ty = CTy::primitive(CPTy::Usize);
+    let scale = CFuncKind::new("scale", mcx.fn_ptr(f64_ty, [f64_ty].into(), Conv::C), false)
+        .with_linkage(CLinkage { internal: true, ..CLinkage::default() })
+        .with_attrs([CAttr::NoInline, CAttr::Cold]);
+    let scale = Interned::new_unchecked(mcx.create_func(scale));
+    mcx.module().push_func(scale);
+    let x = mcx.value(CValue::Local(0));
+    let buf = scale.0.new_pending_alloc(mcx.arr(CTy::primitive(CPTy::Char), len(32)), 16);
+    let (y_var, i_var, n_var) =
+        (scale.0.next_local_var(), scale.0.next_local_var(), scale.0.next_local_var());
+    let (y, i, n) = (mcx.value(y_var), mcx.value(i_var), mcx.value(n_var));
+    let (p_var, msg_var) = (scale.0.next_local_var(), scale.0.next_local_var());
+    let bb = scale.0.new_bb("start", &mcx);
+    bb.push_stmt(mcx.expr(mcx.assign(mcx.index(mcx.value(buf), origin), origin)));
+    bb.push_stmt(mcx.decl(mcx.var(y_var, f64_ty, Some(mcx.binary(x, float(1.5), "*")))));
+    // neither can be assigned, so they are not hoisted in C89
+    bb.push_stmt(mcx.decl(mcx.var(p_var, point, Some(mcx.init_list([origin, origin])))));
+    bb.push_stmt(mcx.decl(mcx.var(
+        msg_var,
+        mcx.ptr(CTy::primitive(CPTy::Char)).to_const_if(true),
+        Some(mcx.value(buf)),
+    )));
+    bb.push_stmt(mcx.decl(mcx.var(n_var, usize_ty, None)));
+    bb.push_stmt(mcx.expr(mcx.assign(n, mcx.align_of(header))));
+    bb.push_stmt(mcx.for_stmt(
+        mcx.decl(mcx.var(i_var, usize_ty, Some(mcx.value(mcx.scalar(0))))),
+        mcx.binary(i, n, "<"),
+        mcx.expr(mcx.unary("++", i)),
+        mcx.expr(mcx.assign(y, mcx.binary(y, float(0.1), "*"))),
+    ));
+    bb.push_stmt(mcx.expr(mcx.assign(y, mcx.binary(y, float(-0.5), "-"))));
+    bb.push_stmt(mcx.expr(mcx.assign(y, mcx.binary(y, mcx.value(mcx.scalar(-1)), "+"))));
+    bb.push_stmt(mcx.ret(Some(y)));
+}
+
+fn print_module(config: PrinterConfig) -> String {
+    let arena = ModuleArena::new();
+    let mcx = ModuleCtxt(&arena);
+    build_module(mcx);
+    let mut printer = Printer::with_config(config);
+    printer.print_module(mcx.module());
+    printer.finish()
+}
+
+#[test]
+fn module() {
+    check_golden("module.c", &print_module(PrinterConfig::default()));
+}
+
+#[test]
+fn module_with_config() {
+    let config = PrinterConfig {
+        width: 40,
+        indent: 4,
+        brace_style: BraceStyle::NextLine,
+        spaced_ops: false,
+        source_locs: SourceLocs::Comments,
+        c_std: CStd::C11,
+    };
+    check_golden("module_with_config.c", &print_module(config));
+}
+
+#[test]
+fn module_with_line_directives() {
+    let config = PrinterConfig { source_locs: SourceLocs::Lines, ..PrinterConfig::default() };
+    let source = print_module(config);
+    check_golden("module_with_line_directives.c", &resolve_line_resets(&source, "out.c", 1));
+}
+
+#[test]
+fn module_c89() {
+    let config = PrinterConfig { c_std: CStd::C89, ..PrinterConfig::default() };
+    check_golden("module_c89.c", &print_module(config));
+}
+
+#[test]
+fn module_c23() {
+    let config = PrinterConfig { c_std: CStd::C23, ..PrinterConfig::default() };
+    check_golden("module_c23.c", &print_module(config));
+}
+
+#[test]
+fn header() {
+    let arena = ModuleArena::new();
+    let mcx = ModuleCtxt(&arena);
+    build_module(mcx);
+    // the header includes the standard headers of its types instead of the module's includes
+    mcx.module().push_includes(&["rust_runtime.h"]);
+    let bool_ty = CTy::primitive(CPTy::Bool);
+    let is_even = mcx.fn_ptr(bool_ty, [CTy::primitive(CPTy::U128)].into(), Conv::C);
+    let is_even = CFuncKind::new("is_even", is_even, false);
+    mcx.module().push_func(Interned::new_unchecked(mcx.create_func(is_even)));
+    let mut printer = Printer::new();
+    printer.print_header(mcx.module(), "MODULE_H");
+    check_golden("module.h", &printer.finish());
+}
+
+#[test]
+fn dump() {
+    let arena = ModuleArena::new();
+    let mcx = ModuleCtxt(&arena);
+    build_module(mcx);
+    check_golden("module.json", &dump_module(mcx.module()));
+}
+
+#[test]
+fn ty_decl() {
+    let arena = ModuleArena::new();
+    let mcx = ModuleCtxt(&arena);
+    let i32_ty = CTy::primitive(CPTy::I32);
+    let char_ty = CTy::primitive(CPTy::Char);
+    let func = mcx.fn_ptr(i32_ty, [i32_ty, mcx.ptr(char_ty.to_const_if(true))].into(), Conv::C);
+    let fn_ptr = mcx.ptr(func);
+    let arr = |ty, n| mcx.arr(ty, NonZeroUsize::new(n));
+    let point = mcx.record("point", false, None);
+
+    let tys = [
+        i32_ty,
+        mcx.ptr(mcx.ptr(char_ty)).to_const_if(true),
+        arr(i32_ty, 4),
+        arr(arr(i32_ty, 3), 4),
+        mcx.ptr(arr(i32_ty, 4)),
+        arr(mcx.ptr(i32_ty), 4),
+        mcx.ptr(point).to_restrict_if(true),
+        func,
+        fn_ptr,
+        arr(fn_ptr, 8),
+        mcx.ptr(arr(fn_ptr, 8)),
+        // a function returning a pointer to a function
+        mcx.ptr(mcx.fn_ptr(fn_ptr, [i32_ty].into(), Conv::C)),
+        arr(i32_ty, 0),
+    ];
+
+    let mut out = String::new();
+    for ty in tys {
+        for ident in [None, Some("x")] {
+            let mut printer = Printer::new();
+            printer.print_ty_decl_ident(ty, ident.map(Into::into));
+            out.push_str(&printer.finish());
+            out.push('\n');
+        }
+    }
+    check_golden("ty_decl.txt", &out);
+}
@@ -0,0 +1,26 @@
+#ifndef MODULE_H
+#define MODULE_H
+
+#include <stdbool.h>
+#include <stdint.h>
+typedef unsigned __int128 __rust_u128;
+
+struct point;
+struct header;
+struct point {
+  int32_t x;
+  int32_t y;
+};
+struct header {
+  uint8_t tag;
+  uint32_t len;
+  struct point origin;
+  int32_t (*ops[2])(int32_t, int32_t);
+} __attribute__((packed, aligned(4)));
+extern struct point _gm_1;
+int32_t add(int32_t _0, int32_t _1);
+int32_t sub(int32_t _0, int32_t _1);
+int32_t apply(struct header *h_0, int32_t n_1);
+bool is_even(__rust_u128 _0);
+
+#endif /* MODULE_H */
@@ -0,0 +1,88 @@
+#include <stdint.h>
+
+/* rustc_codegen_c: interface */
+
+struct point;
+struct header;
+struct point {
+  int32_t x;
+  int32_t y;
+};
+struct header {
+  uint8_t tag;
+  uint32_t len;
+  struct point origin;
+  int32_t (*ops[2])(int32_t, int32_t);
+} __attribute__((packed, aligned(4)));
+_Static_assert(offsetof(struct header, origin) == 5, "layout mismatch");
+static int32_t (*_gm_0[2])(int32_t, int32_t) = {add, sub};
+struct point _gm_1 = {.x = 0, .y = 0};
+extern uint32_t limit;
+int32_t add(int32_t _0, int32_t _1);
+int32_t sub(int32_t _0, int32_t _1);
+int32_t apply(struct header *h_0, int32_t n_1);
+static __attribute__((noinline, cold)) double scale(double _0);
+
+/* rustc_codegen_c: implementation */
+
+int32_t add(int32_t _0, int32_t _1) {
+  #line 2 "src/lib.rs"
+  return _0 + _1;
+}
+#line 33 "out.c"
+
+int32_t sub(int32_t _0, int32_t _1) {
+  #line 6 "src/lib.rs"
+  return _0 - _1;
+}
+#line 39 "out.c"
+
+int32_t apply(struct header *h_0, int32_t n_1) {
+  #line 10 "src/lib.rs"
+  int32_t acc_0_2 = 0;
+  int32_t _3 = 0;
+  #line 11 "src/lib.rs"
+  while (_3 < n_1) {
+    #line 12 "src/lib.rs"
+    if ((_3 & 1) == 0) {
+      #line 13 "src/lib.rs"
+      acc_0_2 = h_0->ops[0](acc_0_2, _3 * _3);
+    } else {
+      #line 15 "src/lib.rs"
+      acc_0_2 = h_0->ops[1](acc_0_2, _3);
+    }
+    #line 17 "src/lib.rs"
+    _3 = _3 + 1;
+  }
+  do {
+    acc_0_2 = acc_0_2 >> 1;
+  } while (acc_0_2 > 1000);
+  if (acc_0_2 < 0) goto negative;
+  switch (acc_0_2) {
+    case 0: goto zero;
+    case 1: goto negative;
+    default: goto done;
+  }
+zero:;
+  return add(sub(h_0->len, 123456789), sub(987654321, h_0->len));
+negative:;
+  return -acc_0_2;
+done:;
+  #line 20 "src/lib.rs"
+  return acc_0_2;
+}
+#line 75 "out.c"
+
+static __attribute__((noinline, cold)) double scale(double _0) {
+  _Alignas(16) char _1[32];
+  _1[0] = 0;
+  double _2 = _0 * 0x1.8p+0;
+  struct point _5 = {0, 0};
+  char *const _6 = _1;
+  uintptr_t _4;
+  _4 = _Alignof(struct header);
+  for(uintptr_t _3 = 0; _3 < _4; ++_3) _2 = _2 * 0x1.999999999999ap-4;
+  _2 = _2 - -0x1p-1;
+  _2 = _2 + -1;
+  return _2;
+}
@@ -1,22 +1,79 @@
-use crate::decl::CDecl;
-use crate::expr::CExpr;
-use crate::pretty::{Printer, INDENT};
+use std::rc::Rc;
+
+use crate::decl::{CDecl, CDeclKind};
+use crate::expr::{CExpr, CExprKind};
+use crate::pretty::{BraceStyle, CStd, Printer};
+use crate::r#type::CTyQuals;
 use crate::ModuleCtxt;
 
 pub type CStmt<'mx> = &'mx CStmtKind<'mx>;
 
 #[derive(Debug, Clone)]
 pub enum CStmtKind<'mx> {
-    If { cond: CExpr<'mx>, then_br: CStmt<'mx>, else_br: Option<CStmt<'mx>> },
-    For { init: CStmt<'mx>, cond: CExpr<'mx>, next: CStmt<'mx>, body: CStmt<'mx> },
+    If {
+        cond: CExpr<'mx>,
+        then_br: CStmt<'mx>,
+        else_br: Option<CStmt<'mx>>,
+    },
+    For {
+        init: CStmt<'mx>,
+        cond: CExpr<'mx>,
+        next: CStmt<'mx>,
+        body: CStmt<'mx>,
+    },
     Return(Option<CExpr<'mx>>),
     Decl(CDecl<'mx>),
     Expr(CExpr<'mx>),
     Goto(&'mx str),
+    /// `switch` on an integer, jumping to the label of the matching case or the default label.
+    Switch {
+        value: CExpr<'mx>,
+        cases: Box<[(CExpr<'mx>, &'mx str)]>,
+        default: &'mx str,
+    },
+    /// A compound statement `{ ... }`.
+    Block(Box<[CStmt<'mx>]>),
+    While {
+        cond: CExpr<'mx>,
+        body: CStmt<'mx>,
+    },
+    DoWhile {
+        body: CStmt<'mx>,
+        cond: CExpr<'mx>,
+    },
+    Break,
+    Continue,
+    /// A label that is the target of `goto`, printed as a labeled null statement so that it can
+    /// precede a declaration.
+    Label(&'mx str),
+}
+
+/// The line of Rust source a statement is generated from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLoc {
+    /// The path of the file, as it is displayed in diagnostics and debug info.
+    pub file: Rc<str>,
+    pub line: u32,
 }
 
 impl<'mx> ModuleCtxt<'mx> {
-    fn create_stmt(self, stmt: CStmtKind<'mx>) -> CStmt<'mx> {
+    /// Record the source location of a statement.
+    pub fn set_loc(self, stmt: CStmt<'mx>, loc: SourceLoc) {
+        self.module().locs.borrow_mut().insert(stmt_key(stmt), loc);
+    }
+
+    pub fn loc(self, stmt: CStmt<'mx>) -> Option<SourceLoc> {
+        self.module().locs.borrow().get(&stmt_key(stmt)).cloned()
+    }
+
+    /// Give a statement the source location of the statement it replaces.
+    pub fn copy_loc(self, from: CStmt<'mx>, to: CStmt<'mx>) {
+        if let Some(loc) = self.loc(from) {
+            self.set_loc(to, loc);
+        }
+    }
+
+    pub fn create_stmt(self, stmt: CStmtKind<'mx>) -> CStmt<'mx> {
         self.arena().alloc(stmt)
     }
 
@@ -54,12 +111,55 @@ impl<'mx> ModuleCtxt<'mx> {
     pub fn goto(self, label: &'mx str) -> CStmt<'mx> {
         self.create_stmt(CStmtKind::Goto(label))
     }
+
+    pub fn switch(
+        self,
+        value: CExpr<'mx>,
+        cases: impl Into<Box<[(CExpr<'mx>, &'mx str)]>>,
+        default: &'mx str,
+    ) -> CStmt<'mx> {
+        self.create_stmt(CStmtKind::Switch { value, cases: cases.into(), default })
+    }
+
+    pub fn block(self, stmts: impl Into<Box<[CStmt<'mx>]>>) -> CStmt<'mx> {
+        self.create_stmt(CStmtKind::Block(stmts.into()))
+    }
+
+    pub fn while_stmt(self, cond: CExpr<'mx>, body: CStmt<'mx>) -> CStmt<'mx> {
+        self.create_stmt(CStmtKind::While { cond, body })
+    }
+
+    pub fn do_while(self, body: CStmt<'mx>, cond: CExpr<'mx>) -> CStmt<'mx> {
+        self.create_stmt(CStmtKind::DoWhile { body, cond })
+    }
+
+    pub fn break_stmt(self) -> CStmt<'mx> {
+        self.create_stmt(CStmtKind::Break)
+    }
+
+    pub fn continue_stmt(self) -> CStmt<'mx> {
+        self.create_stmt(CStmtKind::Continue)
+    }
+
+    pub fn label(self, label: &'mx str) -> CStmt<'mx> {
+        self.create_stmt(CStmtKind::Label(label))
+    }
 }
 
 impl Printer {
     pub fn print_stmt(&mut self, stmt: CStmt, trailing_semicolon: bool) {
         match stmt {
-            CStmtKind::If { cond, then_br, else_br } => self.ibox(INDENT, |this| {
+            CStmtKind::If { cond, then_br, else_br }
+                if is_compound(then_br) || else_br.is_some_and(is_compound) =>
+            {
+                self.print_cond_head("if", cond);
+                self.print_branch(then_br);
+                if let Some(else_br) = else_br {
+                    self.print_else(is_compound(then_br));
+                    self.print_branch(else_br);
+                }
+            }
+            CStmtKind::If { cond, then_br, else_br } => self.ibox(self.indent, |this| {
                 this.word("if");
                 this.softbreak();
                 this.word("(");
@@ -74,7 +174,7 @@ impl Printer {
                     this.print_stmt(else_br, true);
                 }
             }),
-            CStmtKind::For { init, cond, next, body } => self.ibox(INDENT, |this| {
+            CStmtKind::For { init, cond, next, body } => self.ibox(self.indent, |this| {
                 this.word("for");
                 this.word("(");
                 this.print_stmt(init, false);
@@ -89,17 +189,20 @@ impl Printer {
                 this.print_stmt(body, true);
             }),
             CStmtKind::Return(ret) => {
-                self.ibox(INDENT, |this| {
+                self.ibox(self.indent, |this| {
                     this.word("return");
                     if let Some(ret) = ret {
                         this.softbreak();
-                        this.print_expr(ret, false);
+                        this.print_expr(ret, true);
                     }
                     if trailing_semicolon {
                         this.word(";");
                     }
                 });
             }
+            CStmtKind::Decl(decl) if self.hoists_decls() && is_hoistable(decl) => {
+                self.print_hoisted_init(decl, trailing_semicolon)
+            }
             CStmtKind::Decl(decl) => self.print_decl(decl, trailing_semicolon),
             CStmtKind::Expr(expr) => {
                 self.print_expr(expr, true);
@@ -113,6 +216,177 @@ impl Printer {
                     self.word(";");
                 }
             }
+            CStmtKind::Switch { value, cases, default } => {
+                self.print_cond_head("switch", value);
+                self.brace_break();
+                self.cbox(self.indent, |this| {
+                    this.word("{");
+                    for (case, label) in cases.iter() {
+                        this.hardbreak();
+                        this.word("case ");
+                        this.print_expr(case, false);
+                        this.word(format!(": goto {};", label));
+                    }
+                    this.hardbreak();
+                    this.word(format!("default: goto {};", default));
+                    this.break_offset(0, -this.indent);
+                    this.word("}");
+                })
+            }
+            CStmtKind::Block(stmts) => self.print_block(stmts),
+            CStmtKind::While { cond, body } => {
+                self.print_cond_head("while", cond);
+                self.print_branch(body);
+            }
+            CStmtKind::DoWhile { body, cond } => {
+                self.word("do");
+                self.print_branch(body);
+                // `}` and `while` stay on one line, which tells the loop apart from a `while` loop
+                if is_compound(body) {
+                    self.nbsp();
+                } else {
+                    self.hardbreak();
+                }
+                self.print_cond_head("while", cond);
+                self.word(";");
+            }
+            CStmtKind::Break => self.word("break;"),
+            CStmtKind::Continue => self.word("continue;"),
+            CStmtKind::Label(label) => self.word(format!("{label}:;")),
+        }
+    }
+
+    /// Whether local variables are declared at the start of the function, as required by C89,
+    /// and initialized where they are declared in the AST.
+    pub(crate) fn hoists_decls(&self) -> bool {
+        self.config().c_std < CStd::C99
+    }
+
+    /// Whether a statement is the declaration of a local variable without initializer, which is
+    /// left out where it is declared in the AST if declarations are hoisted.
+    pub(crate) fn is_hoisted_decl(&self, stmt: CStmt) -> bool {
+        self.hoists_decls()
+            && matches!(stmt, CStmtKind::Decl(decl @ CDeclKind::Var { init: None, .. }) if is_hoistable(decl))
+    }
+
+    /// Print the initializer of a hoisted declaration as an assignment, e.g. `_1 = 0;`.
+    fn print_hoisted_init(&mut self, decl: CDecl, trailing_semicolon: bool) {
+        let CDeclKind::Var { name, init, .. } = decl else {
+            return self.print_decl(decl, trailing_semicolon);
+        };
+        if let Some(init) = *init {
+            self.ibox(self.indent, |this| {
+                this.print_value(*name);
+                this.word(" =");
+                this.softbreak();
+                this.print_expr(init, true);
+            });
+        }
+        if trailing_semicolon {
+            self.word(";");
+        }
+    }
+
+    /// Print the statements of a block, each on its own line. Labels are outdented.
+    pub(crate) fn print_block(&mut self, stmts: &[CStmt]) {
+        self.word("{");
+        let stmts: Vec<_> =
+            stmts.iter().copied().filter(|stmt| !self.is_hoisted_decl(stmt)).collect();
+        let Some((first, rest)) = stmts.split_first() else {
+            self.word("}");
+            return;
+        };
+        // the first line break is outside of the box, which ends the measurement of the lines
+        // before the block
+        self.hardbreak_offset(self.indent + self.line_offset(first));
+        self.cbox(self.indent, |this| {
+            this.print_loc(first);
+            this.print_stmt(first, true);
+            for stmt in rest {
+                this.hardbreak_offset(this.line_offset(stmt));
+                this.print_loc(stmt);
+                this.print_stmt(stmt, true);
+            }
+        });
+        self.hardbreak();
+        self.word("}");
+    }
+
+    /// The indentation of the line of a statement relative to its block.
+    pub(crate) fn line_offset(&self, stmt: CStmt) -> isize {
+        if let CStmtKind::Label(_) = stmt {
+            -self.indent
+        } else {
+            0
+        }
+    }
+
+    /// Print `if (cond)`, `while (cond)` or `switch (cond)`.
+    fn print_cond_head(&mut self, keyword: &'static str, cond: CExpr) {
+        self.ibox(self.indent, |this| {
+            this.word(keyword);
+            this.nbsp();
+            this.word("(");
+            this.print_expr(cond, true);
+            this.word(")");
+        });
+    }
+
+    /// Print the body of an `if`, `else` or loop. A block is placed according to the brace style,
+    /// and an `if` after `else` stays on the line of the `else`.
+    fn print_branch(&mut self, stmt: CStmt) {
+        if let CStmtKind::Block(_) = stmt {
+            self.brace_break();
+            self.print_stmt(stmt, true);
+        } else if is_compound(stmt) {
+            // `else if`
+            self.nbsp();
+            self.print_stmt(stmt, true);
+        } else {
+            self.cbox(self.indent, |this| {
+                this.softbreak();
+                this.print_stmt(stmt, true);
+            });
+        }
+    }
+
+    fn print_else(&mut self, after_block: bool) {
+        if after_block && self.config().brace_style == BraceStyle::SameLine {
+            self.nbsp();
+        } else {
+            self.hardbreak();
+        }
+        self.word("else");
+    }
+}
+
+/// The key of a statement in the table of source locations. Statements are compared by identity,
+/// as equal statements may come from different places.
+pub(crate) fn stmt_key(stmt: CStmt<'_>) -> usize {
+    stmt as *const CStmtKind<'_> as usize
+}
+
+/// Whether a statement is printed as a block or an `if` with blocks, which starts on the line of
+/// the preceding keyword.
+fn is_compound(stmt: CStmt) -> bool {
+    match stmt {
+        CStmtKind::Block(_) => true,
+        CStmtKind::If { then_br, else_br, .. } => {
+            is_compound(then_br) || else_br.is_some_and(is_compound)
+        }
+        _ => false,
+    }
+}
+
+/// Whether the declaration of a local variable can be hoisted, with its initializer printed as an
+/// assignment. Initializer lists, string literals and `const` variables cannot be assigned, so
+/// those declarations stay where they are, which C89 compilers such as GCC accept as an extension.
+pub(crate) fn is_hoistable(decl: CDecl) -> bool {
+    match decl {
+        CDeclKind::Var { ty, init, .. } => {
+            !ty.quals.contains(CTyQuals::CONST)
+                && !matches!(init, Some(CExprKind::InitList { .. } | CExprKind::Str(_)))
         }
+        _ => false,
     }
 }
@@ -0,0 +1,530 @@
+//! Recovery of structured control flow.
+//!
+//! The builder lowers every branch to a `goto`. [`structure_cfg`] rewrites the body of each
+//! function into nested `if`, `while (1)`, `break` and `continue` statements, following the
+//! dominator-based translation of Ramsey, "Beyond Relooper" (ICFP 2022):
+//!
+//! - a block with a single incoming forward edge is nested where it is jumped to,
+//! - any other block is placed after the code of its immediate dominator, where it is reached by
+//!   falling through or with `goto`,
+//! - a loop header becomes a loop, where jumps back to the header are `continue`, and the blocks
+//!   it dominates outside of the loop follow the loop, where they are reached with `break`.
+//!
+//! Jumps that cannot be expressed this way, e.g. into an irreducible loop, remain `goto`, so any
+//! control flow graph is translated. Since a block is placed within the code of its dominators,
+//! the locals it uses are always declared in an enclosing scope.
+
+use std::mem;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::cfg::{is_jump, targets};
+use crate::decl::CDeclKind;
+use crate::expr::{CExpr, CExprKind, CValue};
+use crate::func::{CBasicBlock, CFunc};
+use crate::stmt::{CStmt, CStmtKind};
+use crate::visit::{walk_stmt, Visitor};
+use crate::ModuleCtxt;
+
+/// Structure the control flow of the functions of a module. Each function is left with a single
+/// basic block.
+pub fn structure_cfg(mcx: ModuleCtxt<'_>) {
+    for &func in mcx.module().funcs.borrow().iter() {
+        let Some(cfg) = Cfg::new(func) else { continue };
+        let stmts = Emitter::emit(mcx, &cfg);
+        let entry = cfg.blocks[0];
+        *entry.stmts.borrow_mut() = stmts;
+        func.0.retain_basic_blocks(|bb| bb.label == entry.label);
+    }
+}
+
+/// The jump at the end of a block.
+enum Term<'mx> {
+    Jump(usize),
+    /// An `if` with its condition, jumping to one of two blocks.
+    Cond(CStmt<'mx>, CExpr<'mx>, usize, usize),
+    /// A `switch`, whose targets are jumped to with `goto`.
+    Switch(CStmt<'mx>, Vec<usize>),
+    /// `return` or any other statement that leaves the function.
+    Exit,
+}
+
+impl Term<'_> {
+    fn successors(&self) -> Vec<usize> {
+        match *self {
+            Term::Jump(target) => vec![target],
+            Term::Cond(_, _, then_bb, else_bb) => vec![then_bb, else_bb],
+            Term::Switch(_, ref targets) => targets.clone(),
+            Term::Exit => vec![],
+        }
+    }
+}
+
+/// The control flow graph of a function, with the blocks numbered in reverse postorder.
+struct Cfg<'mx> {
+    blocks: Vec<&'mx CBasicBlock<'mx>>,
+    /// The statements of each block without the jump at its end.
+    stmts: Vec<Vec<CStmt<'mx>>>,
+    terms: Vec<Term<'mx>>,
+    idom: Vec<usize>,
+    header: Vec<bool>,
+    /// Whether a block is placed where it is jumped to.
+    nested: Vec<bool>,
+    /// The blocks placed after the code of each block.
+    merges: Vec<Vec<usize>>,
+    /// The blocks placed after the loop of each loop header.
+    exits: Vec<Vec<usize>>,
+}
+
+impl<'mx> Cfg<'mx> {
+    /// Build the graph, or return `None` if the function has nothing to structure or jumps in
+    /// the middle of a block.
+    fn new(func: CFunc<'mx>) -> Option<Self> {
+        let blocks = func.0.basic_blocks();
+        if blocks.len() < 2 {
+            return None;
+        }
+        let index: FxHashMap<_, _> =
+            blocks.iter().enumerate().map(|(i, bb)| (bb.label, i)).collect();
+        let mut stmts = Vec::with_capacity(blocks.len());
+        let mut terms = Vec::with_capacity(blocks.len());
+        for (i, bb) in blocks.iter().enumerate() {
+            let mut body = bb.stmts.borrow().clone();
+            let term = match body.last().copied() {
+                Some(CStmtKind::Goto(target)) => Term::Jump(*index.get(target)?),
+                Some(
+                    stmt @ CStmtKind::If {
+                        cond,
+                        then_br: CStmtKind::Goto(then_bb),
+                        else_br: Some(CStmtKind::Goto(else_bb)),
+                    },
+                ) => Term::Cond(stmt, cond, *index.get(then_bb)?, *index.get(else_bb)?),
+                Some(stmt @ CStmtKind::Switch { cases, default, .. }) => {
+                    let labels = cases.iter().map(|&(_, label)| label).chain([*default]);
+                    let targets = labels.map(|label| index.get(label).copied());
+                    Term::Switch(stmt, targets.collect::<Option<_>>()?)
+                }
+                Some(stmt) if is_jump(stmt) => Term::Exit,
+                _ if i + 1 < blocks.len() => Term::Jump(i + 1),
+                _ => Term::Exit,
+            };
+            if !matches!(term, Term::Exit) && body.last().is_some_and(|&stmt| is_jump(stmt)) {
+                body.pop();
+            }
+            if !targets(&body).is_empty() {
+                return None;
+            }
+            stmts.push(body);
+            terms.push(term);
+        }
+
+        // number the blocks in reverse postorder
+        let mut postorder = Vec::with_capacity(blocks.len());
+        let mut visited = vec![false; blocks.len()];
+        let mut stack = vec![(0, terms[0].successors())];
+        visited[0] = true;
+        while let Some((bb, succs)) = stack.last_mut() {
+            if let Some(succ) = succs.pop() {
+                if !mem::replace(&mut visited[succ], true) {
+                    stack.push((succ, terms[succ].successors()));
+                }
+            } else {
+                postorder.push(*bb);
+                stack.pop();
+            }
+        }
+        if postorder.len() != blocks.len() {
+            return None;
+        }
+        let mut rpo = vec![0; blocks.len()];
+        for (i, &bb) in postorder.iter().rev().enumerate() {
+            rpo[bb] = i;
+        }
+        let renumber = |term: Term<'mx>| match term {
+            Term::Jump(target) => Term::Jump(rpo[target]),
+            Term::Cond(stmt, cond, then_bb, else_bb) => {
+                Term::Cond(stmt, cond, rpo[then_bb], rpo[else_bb])
+            }
+            Term::Switch(stmt, targets) => {
+                Term::Switch(stmt, targets.into_iter().map(|bb| rpo[bb]).collect())
+            }
+            Term::Exit => Term::Exit,
+        };
+        let order = postorder.into_iter().rev();
+        let blocks: Vec<_> = order.clone().map(|bb| blocks[bb]).collect();
+        let mut stmts: Vec<_> = stmts.into_iter().map(Some).collect();
+        let stmts: Vec<_> = order.clone().map(|bb| stmts[bb].take().unwrap()).collect();
+        let mut terms: Vec<_> = terms.into_iter().map(Some).collect();
+        let terms: Vec<_> = order.map(|bb| renumber(terms[bb].take().unwrap())).collect();
+
+        let n = blocks.len();
+        let mut cfg = Cfg {
+            blocks,
+            stmts,
+            terms,
+            idom: vec![usize::MAX; n],
+            header: vec![false; n],
+            nested: vec![false; n],
+            merges: vec![vec![]; n],
+            exits: vec![vec![]; n],
+        };
+        cfg.analyze();
+        Some(cfg)
+    }
+
+    fn analyze(&mut self) {
+        let n = self.blocks.len();
+        let mut preds = vec![vec![]; n];
+        for (bb, term) in self.terms.iter().enumerate() {
+            for succ in term.successors() {
+                preds[succ].push(bb);
+            }
+        }
+
+        // dominators, following Cooper, Harvey and Kennedy, "A Simple, Fast Dominance Algorithm"
+        self.idom[0] = 0;
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (bb, preds) in preds.iter().enumerate().skip(1) {
+                let mut processed = preds.iter().copied().filter(|&p| self.idom[p] != usize::MAX);
+                let first = processed.next().unwrap();
+                let idom = processed.fold(first, |a, b| self.intersect(a, b));
+                if self.idom[bb] != idom {
+                    self.idom[bb] = idom;
+                    changed = true;
+                }
+            }
+        }
+
+        // edges to a dominator close a loop, any other edge enters a block
+        let mut latches = vec![vec![]; n];
+        let mut entries = vec![0; n];
+        let mut switched = vec![false; n];
+        for (bb, term) in self.terms.iter().enumerate() {
+            for succ in term.successors() {
+                if self.dominates(succ, bb) {
+                    self.header[succ] = true;
+                    latches[succ].push(bb);
+                } else {
+                    entries[succ] += 1;
+                }
+                switched[succ] |= matches!(term, Term::Switch(..));
+            }
+        }
+
+        let mut children = vec![vec![]; n];
+        for bb in 1..n {
+            children[self.idom[bb]].push(bb);
+        }
+        let locals: Vec<_> = (0..n).map(|bb| self.locals(bb)).collect();
+
+        let mut exit = vec![false; n];
+        for header in (0..n).filter(|&bb| self.header[bb]) {
+            // the natural loop, of blocks that reach a latch without passing the header
+            let mut body = FxHashSet::from_iter([header]);
+            let mut stack = latches[header].clone();
+            while let Some(bb) = stack.pop() {
+                if body.insert(bb) {
+                    stack.extend(preds[bb].iter().copied());
+                }
+            }
+            let declared: FxHashSet<_> =
+                body.iter().flat_map(|&bb| locals[bb].declared.iter().copied()).collect();
+            for &child in children[header].iter().filter(|child| !body.contains(child)) {
+                // a block can only leave the loop if it does not use locals declared in it
+                let mut subtree = vec![child];
+                let mut uses_loop = false;
+                while let Some(bb) = subtree.pop() {
+                    uses_loop |= locals[bb].used.iter().any(|local| declared.contains(local));
+                    subtree.extend(children[bb].iter().copied());
+                }
+                if !uses_loop {
+                    exit[child] = true;
+                    self.exits[header].push(child);
+                }
+            }
+        }
+
+        for bb in 1..n {
+            self.nested[bb] = entries[bb] == 1 && !switched[bb] && !exit[bb];
+            if !self.nested[bb] && !exit[bb] {
+                self.merges[self.idom[bb]].push(bb);
+            }
+        }
+    }
+
+    fn intersect(&self, mut a: usize, mut b: usize) -> usize {
+        while a != b {
+            while a > b {
+                a = self.idom[a];
+            }
+            while b > a {
+                b = self.idom[b];
+            }
+        }
+        a
+    }
+
+    fn dominates(&self, a: usize, mut b: usize) -> bool {
+        while b != a {
+            if b == 0 {
+                return false;
+            }
+            b = self.idom[b];
+        }
+        true
+    }
+
+    fn locals(&self, bb: usize) -> Locals<'mx> {
+        let mut locals = Locals::default();
+        for &stmt in &self.stmts[bb] {
+            locals.visit_stmt(stmt);
+        }
+        match self.terms[bb] {
+            Term::Cond(_, cond, ..) => locals.visit_expr(cond),
+            Term::Switch(stmt, _) => locals.visit_stmt(stmt),
+            Term::Jump(_) | Term::Exit => {}
+        }
+        locals
+    }
+}
+
+/// The locals declared and used by statements.
+#[derive(Default)]
+struct Locals<'mx> {
+    declared: FxHashSet<CValue<'mx>>,
+    used: FxHashSet<CValue<'mx>>,
+}
+
+impl<'mx> Visitor<'mx> for Locals<'mx> {
+    fn visit_stmt(&mut self, stmt: CStmt<'mx>) {
+        if let CStmtKind::Decl(&CDeclKind::Var { name, .. }) = stmt {
+            self.declared.insert(name);
+        }
+        walk_stmt(self, stmt)
+    }
+
+    fn visit_value(&mut self, value: CValue<'mx>) {
+        if let CValue::Local(_) = value {
+            self.used.insert(value);
+        }
+    }
+}
+
+/// Whether statements continue the loop they are in.
+#[derive(Default)]
+struct Continues(bool);
+
+impl<'mx> Visitor<'mx> for Continues {
+    fn visit_stmt(&mut self, stmt: CStmt<'mx>) {
+        match stmt {
+            CStmtKind::Continue => self.0 = true,
+            // `continue` in a nested loop continues that loop
+            CStmtKind::While { .. } | CStmtKind::DoWhile { .. } | CStmtKind::For { .. } => {}
+            _ => walk_stmt(self, stmt),
+        }
+    }
+}
+
+/// Where control goes after the statements being emitted.
+#[derive(Clone, Copy, Default)]
+struct Ctx {
+    /// The block reached by falling off the end of the statements.
+    follow: Option<usize>,
+    /// The header of the innermost loop, and the block reached by `break`.
+    innermost: Option<(usize, Option<usize>)>,
+}
+
+struct Emitter<'a, 'mx> {
+    mcx: ModuleCtxt<'mx>,
+    cfg: &'a Cfg<'mx>,
+    /// The blocks that are labeled.
+    labeled: FxHashSet<usize>,
+    /// The blocks jumped to with `goto`.
+    gotos: FxHashSet<usize>,
+}
+
+impl<'a, 'mx> Emitter<'a, 'mx> {
+    fn emit(mcx: ModuleCtxt<'mx>, cfg: &'a Cfg<'mx>) -> Vec<CStmt<'mx>> {
+        let mut emitter =
+            Emitter { mcx, cfg, labeled: FxHashSet::default(), gotos: FxHashSet::default() };
+        let mut stmts = vec![];
+        emitter.node(0, Ctx::default(), &mut stmts);
+        // the labels are only known after the translation, which is repeated to place them
+        if !emitter.gotos.is_empty() {
+            emitter.labeled = mem::take(&mut emitter.gotos);
+            stmts.clear();
+            emitter.node(0, Ctx::default(), &mut stmts);
+        }
+        stmts
+    }
+
+    /// Emit the code of a block and the blocks it dominates.
+    fn node(&mut self, bb: usize, cx: Ctx, out: &mut Vec<CStmt<'mx>>) {
+        // the entry block keeps the label of the basic block it is emitted in
+        if bb != 0 && self.labeled.contains(&bb) {
+            out.push(self.mcx.label(self.cfg.blocks[bb].label));
+        }
+        if self.cfg.header[bb] {
+            let exits = &self.cfg.exits[bb];
+            let innermost = Some((bb, exits.first().copied().or(cx.follow)));
+            let mut body = vec![];
+            self.within(bb, Ctx { follow: Some(bb), innermost }, &mut body);
+            out.push(self.loop_stmt(body));
+            self.sequence(exits, cx, out);
+        } else {
+            self.within(bb, cx, out);
+        }
+    }
+
+    fn within(&mut self, bb: usize, cx: Ctx, out: &mut Vec<CStmt<'mx>>) {
+        out.extend(self.cfg.stmts[bb].iter().copied());
+        let merges = &self.cfg.merges[bb];
+        let follow = merges.first().copied().or(cx.follow);
+        self.term(bb, Ctx { follow, ..cx }, out);
+        self.sequence(merges, cx, out);
+    }
+
+    /// Emit blocks one after another, each falling through to the next.
+    fn sequence(&mut self, bbs: &[usize], cx: Ctx, out: &mut Vec<CStmt<'mx>>) {
+        for (i, &bb) in bbs.iter().enumerate() {
+            let follow = bbs.get(i + 1).copied().or(cx.follow);
+            self.node(bb, Ctx { follow, ..cx }, out);
+        }
+    }
+
+    fn term(&mut self, bb: usize, cx: Ctx, out: &mut Vec<CStmt<'mx>>) {
+        match self.cfg.terms[bb] {
+            Term::Jump(target) => self.branch(bb, target, cx, out),
+            Term::Cond(stmt, cond, then_bb, else_bb) => {
+                let mut then_br = vec![];
+                self.branch(bb, then_bb, cx, &mut then_br);
+                let mut else_br = vec![];
+                self.branch(bb, else_bb, cx, &mut else_br);
+                self.if_stmt(stmt, cond, then_br, else_br, out);
+            }
+            Term::Switch(stmt, ref targets) => {
+                self.gotos.extend(targets);
+                out.push(stmt);
+            }
+            Term::Exit => {}
+        }
+    }
+
+    /// Emit the transfer of control from a block to another.
+    fn branch(&mut self, from: usize, to: usize, cx: Ctx, out: &mut Vec<CStmt<'mx>>) {
+        let mcx = self.mcx;
+        if cx.follow == Some(to) {
+            return;
+        }
+        if let Some((header, follow)) = cx.innermost {
+            if follow == Some(to) {
+                out.push(mcx.break_stmt());
+                return;
+            }
+            if header == to {
+                out.push(mcx.continue_stmt());
+                return;
+            }
+        }
+        if self.cfg.nested[to] && !self.cfg.dominates(to, from) {
+            self.node(to, cx, out);
+        } else {
+            self.gotos.insert(to);
+            out.push(mcx.goto(self.cfg.blocks[to].label));
+        }
+    }
+
+    /// A loop with the given body. A test that leaves the loop at the start of the body becomes
+    /// the condition of a `while`, and at its end the condition of a `do`-`while`, unless the
+    /// body continues the loop, which would skip the test, or declares a local used in the test.
+    fn loop_stmt(&self, mut body: Vec<CStmt<'mx>>) -> CStmt<'mx> {
+        let mcx = self.mcx;
+        if let [test @ CStmtKind::If { cond, then_br: CStmtKind::Break, else_br: None }, ..] =
+            body[..]
+        {
+            body.remove(0);
+            let stmt = mcx.while_stmt(self.negate(cond), mcx.block(body));
+            mcx.copy_loc(test, stmt);
+            return stmt;
+        }
+        if let [ref rest @ .., test @ CStmtKind::If { cond, then_br: CStmtKind::Break, else_br: None }] =
+            body[..]
+        {
+            let mut locals = Locals::default();
+            locals.visit_expr(cond);
+            let mut continues = Continues::default();
+            for &stmt in rest {
+                continues.visit_stmt(stmt);
+            }
+            let declared = rest.iter().any(|stmt| {
+                matches!(stmt, CStmtKind::Decl(CDeclKind::Var { name, .. }) if locals.used.contains(name))
+            });
+            if !continues.0 && !declared {
+                body.pop();
+                let stmt = mcx.do_while(mcx.block(body), self.negate(cond));
+                mcx.copy_loc(test, stmt);
+                return stmt;
+            }
+        }
+        mcx.while_stmt(mcx.value(CValue::Scalar(1)), mcx.block(body))
+    }
+
+    /// Emit a conditional that replaces the `if` statement `from`. If a branch always jumps, it
+    /// becomes a guard followed by the other branch, which saves a level of nesting.
+    fn if_stmt(
+        &self,
+        from: CStmt<'mx>,
+        cond: CExpr<'mx>,
+        then_br: Vec<CStmt<'mx>>,
+        else_br: Vec<CStmt<'mx>>,
+        out: &mut Vec<CStmt<'mx>>,
+    ) {
+        let mcx = self.mcx;
+        let jumps = |stmts: &[CStmt<'_>]| stmts.last().is_some_and(|&stmt| is_jump(stmt));
+        let (cond, guard, rest) = match (then_br.is_empty(), else_br.is_empty()) {
+            (true, true) => (cond, vec![], vec![]),
+            (false, true) => (cond, then_br, vec![]),
+            (true, false) => (self.negate(cond), else_br, vec![]),
+            _ if jumps(&then_br) && (!jumps(&else_br) || then_br.len() <= else_br.len()) => {
+                (cond, then_br, else_br)
+            }
+            _ if jumps(&else_br) => (self.negate(cond), else_br, then_br),
+            _ => {
+                let else_br = match else_br[..] {
+                    [stmt @ CStmtKind::If { .. }] => stmt,
+                    _ => self.branch_stmt(else_br),
+                };
+                let stmt = mcx.if_stmt(cond, self.branch_stmt(then_br), Some(else_br));
+                mcx.copy_loc(from, stmt);
+                out.push(stmt);
+                return;
+            }
+        };
+        let stmt = mcx.if_stmt(cond, self.branch_stmt(guard), None);
+        mcx.copy_loc(from, stmt);
+        out.push(stmt);
+        out.extend(rest);
+    }
+
+    /// The body of a branch, braced unless it is a single jump.
+    fn branch_stmt(&self, stmts: Vec<CStmt<'mx>>) -> CStmt<'mx> {
+        match stmts[..] {
+            [stmt @ (CStmtKind::Goto(_)
+            | CStmtKind::Break
+            | CStmtKind::Continue
+            | CStmtKind::Return(_))] => stmt,
+            _ => self.mcx.block(stmts),
+        }
+    }
+
+    fn negate(&self, cond: CExpr<'mx>) -> CExpr<'mx> {
+        match *cond {
+            CExprKind::Unary { op: "!", expr } => expr,
+            CExprKind::Binary { lhs, rhs, op: "==" } => self.mcx.binary(lhs, rhs, "!="),
+            CExprKind::Binary { lhs, rhs, op: "!=" } => self.mcx.binary(lhs, rhs, "=="),
+            _ => self.mcx.unary("!", cond),
+        }
+    }
+}
@@ -1,12 +1,15 @@
+use std::borrow::Cow;
+use std::cell::OnceCell;
 use std::collections::VecDeque;
+use std::hash::{Hash, Hasher};
 use std::num::NonZeroUsize;
 
 use rustc_data_structures::intern::Interned;
 use rustc_target::abi::call::Conv;
-use rustc_type_ir::{IntTy, UintTy};
+use rustc_type_ir::{FloatTy, IntTy, UintTy};
 
 use crate::expr::CValue;
-use crate::pretty::{Printer, INDENT};
+use crate::pretty::{CStd, Printer};
 use crate::ModuleCtxt;
 
 /// C types with qualifiers.
@@ -35,6 +38,24 @@ impl<'mx> CTy<'mx> {
         }
     }
 
+    /// Whether the type is a 128-bit integer.
+    pub fn is_int128(self) -> bool {
+        if let CTyBase::Primitive(ty) = self.base {
+            ty.is_int128()
+        } else {
+            false
+        }
+    }
+
+    /// Whether the type is a floating-point type.
+    pub fn is_float(self) -> bool {
+        if let CTyBase::Primitive(ty) = self.base {
+            ty.is_float()
+        } else {
+            false
+        }
+    }
+
     /// Whether the type is a pointer.
     pub fn is_ptr(self) -> bool {
         if let CTyBase::Ref(ty) = self.base {
@@ -44,6 +65,26 @@ impl<'mx> CTy<'mx> {
         }
     }
 
+    /// Gets the type pointed to by a pointer, or the element type of an array.
+    pub fn pointee(self) -> Option<CTy<'mx>> {
+        if let CTyBase::Ref(ty) = self.base {
+            if let CTyKind::Pointer(ty) | CTyKind::Array(ty, _) = ty.0 {
+                return Some(*ty);
+            }
+        }
+        None
+    }
+
+    /// Gets the record type if this is a record.
+    pub fn record(self) -> Option<&'mx CRecord<'mx>> {
+        if let CTyBase::Ref(ty) = self.base {
+            if let CTyKind::Record(record) = ty.0 {
+                return Some(record);
+            }
+        }
+        None
+    }
+
     /// Gets the function pointer type if this is a function pointer.
     pub fn fn_ptr(self) -> Option<&'mx CFnPtr<'mx>> {
         if let CTyBase::Ref(ty) = self.base {
@@ -54,22 +95,25 @@ impl<'mx> CTy<'mx> {
         None
     }
 
+    /// Adds the `const` qualifier if `cond` holds.
     pub fn to_const_if(self, cond: bool) -> Self {
-        if cond {
+        if !cond {
             return self;
         }
         Self { base: self.base, quals: self.quals | CTyQuals::CONST }
     }
 
+    /// Adds the `volatile` qualifier if `cond` holds.
     pub fn to_volatile_if(self, cond: bool) -> Self {
-        if cond {
+        if !cond {
             return self;
         }
         Self { base: self.base, quals: self.quals | CTyQuals::VOLATILE }
     }
 
+    /// Adds the `restrict` qualifier if `cond` holds.
     pub fn to_restrict_if(self, cond: bool) -> Self {
-        if cond {
+        if !cond {
             return self;
         }
         Self { base: self.base, quals: self.quals | CTyQuals::RESTRICT }
@@ -118,18 +162,34 @@ pub enum CPTy {
     I16,
     I32,
     I64,
+    I128,
 
     Usize,
     U8,
     U16,
     U32,
     U64,
+    U128,
+
+    F32,
+    F64,
 }
 
 impl CPTy {
     /// Whether the type is a signed integer.
     pub fn is_signed(self) -> bool {
-        matches!(self, CPTy::Isize | CPTy::I8 | CPTy::I16 | CPTy::I32 | CPTy::I64)
+        matches!(self, CPTy::Isize | CPTy::I8 | CPTy::I16 | CPTy::I32 | CPTy::I64 | CPTy::I128)
+    }
+
+    /// Whether the type is a 128-bit integer. These are provided by `rust_runtime.h` and may
+    /// be structs, so they can only be operated on through the runtime helpers.
+    pub fn is_int128(self) -> bool {
+        matches!(self, CPTy::I128 | CPTy::U128)
+    }
+
+    /// Whether the type is a floating-point type.
+    pub fn is_float(self) -> bool {
+        matches!(self, CPTy::F32 | CPTy::F64)
     }
 
     /// The unsigned version of this type.
@@ -144,6 +204,7 @@ impl CPTy {
             CPTy::I16 => CPTy::U16,
             CPTy::I32 => CPTy::U32,
             CPTy::I64 => CPTy::U64,
+            CPTy::I128 => CPTy::U128,
             _ => unreachable!(),
         }
     }
@@ -159,12 +220,17 @@ impl CPTy {
             CPTy::I16 => "int16_t",
             CPTy::I32 => "int32_t",
             CPTy::I64 => "int64_t",
+            CPTy::I128 => "__rust_i128",
 
             CPTy::Usize => "uintptr_t",
             CPTy::U8 => "uint8_t",
             CPTy::U16 => "uint16_t",
             CPTy::U32 => "uint32_t",
             CPTy::U64 => "uint64_t",
+            CPTy::U128 => "__rust_u128",
+
+            CPTy::F32 => "float",
+            CPTy::F64 => "double",
         }
     }
 
@@ -193,11 +259,98 @@ impl CPTy {
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum CTyKind<'mx> {
     Pointer(CTy<'mx>),
-    // Record(String),
+    Record(CRecord<'mx>),
     Array(CTy<'mx>, Option<NonZeroUsize>),
     FnPtr(CFnPtr<'mx>),
 }
 
+/// A named C struct or union.
+///
+/// Records are identified by their name, so that a record can be referred to (e.g. through a
+/// pointer) before its fields are known. The fields are filled in once by
+/// [`CRecord::set_fields`].
+#[derive(Clone)]
+pub struct CRecord<'mx> {
+    pub kind: CRecordKind,
+    pub name: &'mx str,
+    /// Whether the record is declared with `__attribute__((packed))`.
+    pub packed: bool,
+    /// The alignment declared with `__attribute__((aligned(N)))`, if the record needs more than
+    /// the alignment of its fields.
+    pub align: Option<u64>,
+    fields: OnceCell<Box<[CField<'mx>]>>,
+}
+
+/// The keyword of a record.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CRecordKind {
+    Struct,
+    Union,
+}
+
+impl CRecordKind {
+    pub fn to_str(self) -> &'static str {
+        match self {
+            CRecordKind::Struct => "struct",
+            CRecordKind::Union => "union",
+        }
+    }
+}
+
+impl<'mx> CRecord<'mx> {
+    /// The fields of the record, in memory order.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if the fields are not set yet.
+    pub fn fields(&self) -> &[CField<'mx>] {
+        self.fields.get().unwrap_or_else(|| panic!("fields of `struct {}` not set", self.name))
+    }
+
+    /// Set the fields of the record.
+    ///
+    /// ## Panic
+    ///
+    /// Panics if the fields are already set.
+    pub fn set_fields(&self, fields: impl Into<Box<[CField<'mx>]>>) {
+        if self.fields.set(fields.into()).is_err() {
+            panic!("fields of `struct {}` set twice", self.name);
+        }
+    }
+
+    /// Whether the fields of the record are set.
+    pub fn is_complete(&self) -> bool {
+        self.fields.get().is_some()
+    }
+}
+
+impl PartialEq for CRecord<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+
+impl Eq for CRecord<'_> {}
+
+impl Hash for CRecord<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+impl std::fmt::Debug for CRecord<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.kind.to_str(), self.name)
+    }
+}
+
+/// A field of a record. Padding is represented as `uint8_t[N]` fields.
+#[derive(Clone, Debug)]
+pub struct CField<'mx> {
+    pub name: &'mx str,
+    pub ty: CTy<'mx>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub struct CFnPtr<'mx> {
     pub ret: CTy<'mx>,
@@ -213,7 +366,7 @@ impl<'mx> ModuleCtxt<'mx> {
 
     /// Get the bool type
     pub const fn bool(&self) -> CTy<'mx> {
-        CTy { base: CTyBase::Primitive(CPTy::U8), quals: CTyQuals::empty() }
+        CTy { base: CTyBase::Primitive(CPTy::Bool), quals: CTyQuals::empty() }
     }
 
     /// Get the char type
@@ -229,7 +382,7 @@ impl<'mx> ModuleCtxt<'mx> {
             IntTy::I16 => CTyBase::Primitive(CPTy::I16),
             IntTy::I32 => CTyBase::Primitive(CPTy::I32),
             IntTy::I64 => CTyBase::Primitive(CPTy::I64),
-            IntTy::I128 => unimplemented!("i128 not supported yet"),
+            IntTy::I128 => CTyBase::Primitive(CPTy::I128),
         }
         .into()
     }
@@ -242,7 +395,18 @@ impl<'mx> ModuleCtxt<'mx> {
             UintTy::U16 => CTyBase::Primitive(CPTy::U16),
             UintTy::U32 => CTyBase::Primitive(CPTy::U32),
             UintTy::U64 => CTyBase::Primitive(CPTy::U64),
-            UintTy::U128 => unimplemented!("u128 not supported yet"),
+            UintTy::U128 => CTyBase::Primitive(CPTy::U128),
+        }
+        .into()
+    }
+
+    /// Get the type of a floating-point number
+    pub fn float(&self, float: FloatTy) -> CTy<'mx> {
+        match float {
+            FloatTy::F32 => CTyBase::Primitive(CPTy::F32),
+            FloatTy::F64 => CTyBase::Primitive(CPTy::F64),
+            FloatTy::F16 => unimplemented!("f16 not supported yet"),
+            FloatTy::F128 => unimplemented!("f128 not supported yet"),
         }
         .into()
     }
@@ -252,11 +416,50 @@ impl<'mx> ModuleCtxt<'mx> {
         self.intern_ty(CTyKind::Pointer(ty)).into()
     }
 
+    /// Create a new struct type without fields. The fields should be set by
+    /// [`CRecord::set_fields`] before the module is printed.
+    ///
+    /// The name must be unique among the records of the module.
+    pub fn record(&self, name: &str, packed: bool, align: Option<u64>) -> CTy<'mx> {
+        self.create_record(CRecordKind::Struct, name, packed, align)
+    }
+
+    /// Create a new union type without fields, like [`ModuleCtxt::record`].
+    pub fn union(&self, name: &str) -> CTy<'mx> {
+        self.create_record(CRecordKind::Union, name, false, None)
+    }
+
+    fn create_record(
+        &self,
+        kind: CRecordKind,
+        name: &str,
+        packed: bool,
+        align: Option<u64>,
+    ) -> CTy<'mx> {
+        let name = self.alloc_str(name);
+        let record = CRecord { kind, name, packed, align, fields: OnceCell::new() };
+        let ty: CTy<'mx> = self.intern_ty(CTyKind::Record(record)).into();
+        assert!(ty.record().is_some_and(|r| !r.is_complete()), "duplicate record `{}`", name);
+        self.module().push_record(ty);
+        ty
+    }
+
     /// Get the array type
     pub fn arr(&self, ty: CTy<'mx>, n: Option<NonZeroUsize>) -> CTy<'mx> {
         self.intern_ty(CTyKind::Array(ty, n)).into()
     }
 
+    /// Get the `const` type of an object. Arrays get `const` elements instead, since qualifiers
+    /// of an array type itself are only valid on parameters.
+    pub fn const_obj(&self, ty: CTy<'mx>) -> CTy<'mx> {
+        if let CTyBase::Ref(kind) = ty.base {
+            if let CTyKind::Array(elem, n) = kind.0 {
+                return self.arr(self.const_obj(*elem), *n);
+            }
+        }
+        ty.to_const_if(true)
+    }
+
     /// Get the function type
     pub fn fn_ptr(&self, ret: CTy<'mx>, args: Box<[CTy<'mx>]>, abi: Conv) -> CTy<'mx> {
         self.intern_ty(CTyKind::FnPtr(CFnPtr { ret, args, abi })).into()
@@ -264,9 +467,15 @@ impl<'mx> ModuleCtxt<'mx> {
 }
 
 impl Printer {
-    pub fn print_ty_decl(&mut self, mut ty: CTy, val: Option<CValue>) {
+    pub fn print_ty_decl(&mut self, ty: CTy, val: Option<CValue>) {
+        self.print_ty_decl_ident(ty, val.map(|val| self.value_name(val)))
+    }
+
+    /// Print a declaration of `ident` with type `ty`, or an abstract declarator if `ident` is
+    /// `None`.
+    pub fn print_ty_decl_ident(&mut self, mut ty: CTy, ident: Option<Cow<'static, str>>) {
         enum TyDeclPart<'mx> {
-            Ident(Option<CValue<'mx>>),
+            Ident(Option<Cow<'static, str>>),
             Ptr(CTyQuals),
             Array(Option<NonZeroUsize>, CTyQuals),
             FnArgs(Box<[CTy<'mx>]>),
@@ -277,9 +486,9 @@ impl Printer {
         impl<'mx> TyDeclPart<'mx> {
             fn print(&self, printer: &mut Printer) {
                 match self {
-                    TyDeclPart::Ident(val) => {
-                        if let &Some(val) = val {
-                            printer.print_value(val);
+                    TyDeclPart::Ident(ident) => {
+                        if let Some(ident) = ident {
+                            printer.word(ident.clone());
                         }
                     }
                     TyDeclPart::Ptr(quals) => {
@@ -294,38 +503,54 @@ impl Printer {
                         }
                         printer.word("]");
                     }
-                    TyDeclPart::FnArgs(args) => printer.ibox_delim(INDENT, ("(", ")"), |p| {
-                        p.seperated(",", args, |p, arg| p.print_ty_decl(*arg, None))
-                    }),
+                    TyDeclPart::FnArgs(args) => {
+                        printer.ibox_delim(printer.indent, ("(", ")"), |p| {
+                            p.seperated(",", args, |p, arg| p.print_ty_decl(*arg, None))
+                        })
+                    }
                     TyDeclPart::LParen => printer.word("("),
                     TyDeclPart::RParen => printer.word(")"),
                 }
             }
         }
 
+        let has_ident = ident.is_some();
         let mut decl_parts = VecDeque::new();
-        decl_parts.push_front(TyDeclPart::Ident(val));
+        decl_parts.push_front(TyDeclPart::Ident(ident));
+        // whether the declarator starts with a pointer, which binds looser than the array and
+        // function suffixes, e.g. `int (*x)[4]` is a pointer to an array
+        let mut in_ptr = false;
         while let CTyBase::Ref(kind) = ty.base {
+            if in_ptr && matches!(kind.0, CTyKind::Array(..) | CTyKind::FnPtr(_)) {
+                decl_parts.push_front(TyDeclPart::LParen);
+                decl_parts.push_back(TyDeclPart::RParen);
+            }
+            in_ptr = matches!(kind.0, CTyKind::Pointer(_));
             match kind.0 {
+                CTyKind::Record(_) => break,
                 CTyKind::Pointer(_) => decl_parts.push_front(TyDeclPart::Ptr(ty.quals)),
                 CTyKind::Array(_, n) => decl_parts.push_back(TyDeclPart::Array(*n, ty.quals)),
                 CTyKind::FnPtr(CFnPtr { args, .. }) => {
-                    decl_parts.push_front(TyDeclPart::LParen);
-                    decl_parts.push_front(TyDeclPart::RParen);
                     decl_parts.push_back(TyDeclPart::FnArgs(args.clone()));
                 }
             }
             ty = match kind.0 {
+                CTyKind::Record(_) => unreachable!(),
                 CTyKind::Pointer(ty) => *ty,
                 CTyKind::Array(ty, _) => *ty,
                 CTyKind::FnPtr(CFnPtr { ret, .. }) => *ret,
             };
         }
 
-        let CTyBase::Primitive(base) = ty.base else { unreachable!() };
         self.print_ty_quals(ty.quals);
-        self.word(base.to_str());
-        if val.is_some() {
+        match ty.base {
+            CTyBase::Primitive(base) => self.word(base.to_str()),
+            CTyBase::Ref(kind) => {
+                let CTyKind::Record(record) = kind.0 else { unreachable!() };
+                self.word(format!("{} {}", record.kind.to_str(), record.name));
+            }
+        }
+        if has_ident || matches!(decl_parts.front(), Some(TyDeclPart::LParen)) {
             self.nbsp();
         }
         for part in decl_parts {
@@ -333,6 +558,39 @@ impl Printer {
         }
     }
 
+    /// Print the definition of a record, e.g. `struct foo { int32_t f0; };`
+    pub fn print_record_def(&mut self, record: &CRecord) {
+        let mut attrs = Vec::new();
+        if record.packed {
+            attrs.push("packed".to_string());
+        }
+        if let Some(align) = record.align {
+            attrs.push(format!("aligned({align})"));
+        }
+        // C23 attributes of a record go after the keyword, GNU attributes after the fields
+        let c23 = self.config().c_std >= CStd::C23;
+        self.word(record.kind.to_str());
+        if c23 && !attrs.is_empty() {
+            self.word(format!(" {}", self.attr_spec(&attrs)));
+        }
+        self.word(format!(" {}", record.name));
+        self.brace_break();
+        self.cbox(self.indent, |this| {
+            this.word("{");
+            for field in record.fields() {
+                this.hardbreak();
+                this.print_ty_decl_ident(field.ty, Some(field.name.to_string().into()));
+                this.word(";");
+            }
+            this.break_offset(0, -this.indent);
+            this.word("}");
+            if !c23 && !attrs.is_empty() {
+                this.word(format!(" {}", this.attr_spec(&attrs)));
+            }
+            this.word(";");
+        });
+    }
+
     fn print_ty_quals(&mut self, quals: CTyQuals) {
         if quals.contains(CTyQuals::CONST) {
             self.word("const");
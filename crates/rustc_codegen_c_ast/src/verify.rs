@@ -0,0 +1,237 @@
+//! Well-formedness checks of generated C.
+//!
+//! The verifier catches mistakes of the code generator that would otherwise surface as errors
+//! of the C compiler, or worse, as silently accepted implicit conversions. It checks that
+//!
+//! - locals are declared before they are used,
+//! - `goto` and `switch` targets are labels of the same function,
+//! - assigned values and call arguments have types compatible with their destination, and
+//! - the allocations of a function have complete types, and allocations whose type is never
+//!   resolved keep their `char[N]` placeholder, since they are only accessed through casted
+//!   pointers.
+//!
+//! Types of expressions are only inferred where they are evident, see [`crate::infer`], e.g.
+//! integer literals and raw expressions are compatible with anything.
+
+use std::fmt::{self, Display};
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::decl::{CDecl, CDeclKind};
+use crate::expr::{CExpr, CExprKind, CValue};
+use crate::func::{CBasicBlock, CFunc};
+use crate::infer::GlobalTypes;
+use crate::r#type::{CPTy, CTy, CTyBase, CTyKind};
+use crate::stmt::{CStmt, CStmtKind};
+use crate::visit::{walk_expr, walk_stmt, Visitor};
+use crate::ModuleCtxt;
+
+#[cfg(test)]
+mod tests;
+
+/// An ill-formed construct in a function of the module.
+#[derive(Debug, Clone)]
+pub struct VerifyError {
+    pub func: String,
+    pub msg: String,
+}
+
+impl Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ill-formed C in function `{}`: {}", self.func, self.msg)
+    }
+}
+
+/// Check the functions of a module, returning the first error found.
+pub fn verify_module(mcx: ModuleCtxt<'_>) -> Result<(), VerifyError> {
+    let module = mcx.module();
+    let globals = GlobalTypes::new(module);
+
+    for &func in module.funcs.borrow().iter() {
+        let mut verifier = FuncVerifier {
+            globals: &globals,
+            locals: FxHashMap::default(),
+            labels: labels(func),
+            error: None,
+        };
+        verifier.verify(func);
+        if let Some(msg) = verifier.error {
+            return Err(VerifyError { func: func.0.name.to_string(), msg });
+        }
+    }
+    Ok(())
+}
+
+struct FuncVerifier<'a, 'mx> {
+    globals: &'a GlobalTypes<'mx>,
+    locals: FxHashMap<CValue<'mx>, CTy<'mx>>,
+    labels: FxHashSet<&'mx str>,
+    error: Option<String>,
+}
+
+impl<'a, 'mx> FuncVerifier<'a, 'mx> {
+    fn verify(&mut self, func: CFunc<'mx>) {
+        let fn_ptr = func.fn_ptr();
+        for (&param, &ty) in func.0.params.iter().zip(fn_ptr.args.iter()) {
+            self.locals.insert(param, ty);
+        }
+        for (val, alloc) in func.0.allocs() {
+            match alloc.ty {
+                Some(ty) if !is_complete(ty) => {
+                    self.fail(format!("allocation `{val:?}` has incomplete type `{ty:?}`"));
+                }
+                None if !is_char_array(alloc.fallback) => self.fail(format!(
+                    "unresolved allocation `{val:?}` has placeholder type `{:?}` instead of `char[N]`",
+                    alloc.fallback
+                )),
+                _ => {}
+            }
+            self.locals.insert(val, alloc.ty());
+        }
+        for bb in func.0.basic_blocks() {
+            self.visit_bb(bb);
+        }
+    }
+
+    fn fail(&mut self, msg: String) {
+        self.error.get_or_insert(msg);
+    }
+
+    fn check_compatible(&mut self, what: &str, dst: Option<CTy<'mx>>, src: CExpr<'mx>) {
+        let (Some(dst), Some(src_ty)) = (dst, self.type_of(src)) else { return };
+        if !is_compatible(dst, src_ty) {
+            self.fail(format!("{what} of type `{dst:?}` from `{src_ty:?}`"));
+        }
+    }
+
+    fn type_of(&self, expr: CExpr<'mx>) -> Option<CTy<'mx>> {
+        self.globals.type_of(&self.locals, expr)
+    }
+}
+
+impl<'a, 'mx> Visitor<'mx> for FuncVerifier<'a, 'mx> {
+    fn visit_bb(&mut self, bb: &'mx CBasicBlock<'mx>) {
+        let stmts = bb.stmts.borrow().clone();
+        for stmt in stmts {
+            self.visit_stmt(stmt);
+        }
+    }
+
+    fn visit_decl(&mut self, decl: CDecl<'mx>) {
+        match *decl {
+            CDeclKind::Var { name, ty, init, .. } => {
+                self.visit_ty(ty);
+                if let Some(init) = init {
+                    self.visit_expr(init);
+                    if !matches!(init, CExprKind::InitList { .. } | CExprKind::Str(_)) {
+                        self.check_compatible(&format!("`{name:?}` initialized"), Some(ty), init);
+                    }
+                }
+                self.locals.insert(name, ty);
+            }
+            CDeclKind::Func { .. } => self.fail("function declared in a function body".into()),
+        }
+    }
+
+    fn visit_expr(&mut self, expr: CExpr<'mx>) {
+        match *expr {
+            CExprKind::Binary { lhs, rhs, op: "=" } => {
+                self.check_compatible("assignment", self.type_of(lhs), rhs);
+            }
+            CExprKind::Call { callee: CExprKind::Value(CValue::Func(name)), ref args } => {
+                if let Some(fn_ptr) = self.globals.funcs.get(name) {
+                    if fn_ptr.args.len() != args.len() {
+                        self.fail(format!(
+                            "call of `{name}` with {} arguments instead of {}",
+                            args.len(),
+                            fn_ptr.args.len()
+                        ));
+                    }
+                    for (i, (&param, &arg)) in fn_ptr.args.iter().zip(args.iter()).enumerate() {
+                        self.check_compatible(
+                            &format!("argument {i} of `{name}`"),
+                            Some(param),
+                            arg,
+                        );
+                    }
+                }
+            }
+            _ => {}
+        }
+        walk_expr(self, expr)
+    }
+
+    fn visit_value(&mut self, value: CValue<'mx>) {
+        if let CValue::Local(_) = value {
+            if !self.locals.contains_key(&value) {
+                self.fail(format!("`{value:?}` is used before its declaration"));
+            }
+        }
+    }
+
+    fn visit_label(&mut self, label: &'mx str) {
+        if !self.labels.contains(label) {
+            self.fail(format!("jump to undefined label `{label}`"));
+        }
+    }
+}
+
+/// The labels of the basic blocks and labeled statements of a function.
+fn labels(func: CFunc<'_>) -> FxHashSet<&str> {
+    struct Labels<'mx>(FxHashSet<&'mx str>);
+
+    impl<'mx> Visitor<'mx> for Labels<'mx> {
+        fn visit_stmt(&mut self, stmt: CStmt<'mx>) {
+            if let CStmtKind::Label(label) = stmt {
+                self.0.insert(label);
+            }
+            walk_stmt(self, stmt)
+        }
+    }
+
+    let mut labels = Labels(func.0.basic_blocks().iter().map(|bb| bb.label).collect());
+    for bb in func.0.basic_blocks() {
+        labels.visit_bb(bb);
+    }
+    labels.0
+}
+
+/// Whether a value of type `src` can be assigned to `dst` without a cast.
+///
+/// Arithmetic types convert implicitly to each other, and pointers to and from `void*`.
+fn is_compatible<'mx>(dst: CTy<'mx>, src: CTy<'mx>) -> bool {
+    let is_arith = |ty: CTy<'_>| matches!(ty.base, CTyBase::Primitive(ty) if ty != CPTy::Void);
+    if dst.base == src.base || is_arith(dst) && is_arith(src) {
+        return true;
+    }
+    if !dst.is_ptr() {
+        return false;
+    }
+    match (dst.pointee(), src.pointee()) {
+        (Some(dst), Some(src)) => dst.is_void() || src.is_void() || dst.base == src.base,
+        _ => false,
+    }
+}
+
+/// Whether the type is `char[N]`, the placeholder type of an allocation.
+fn is_char_array(ty: CTy<'_>) -> bool {
+    match ty.base {
+        CTyBase::Ref(kind) => matches!(
+            kind.0,
+            CTyKind::Array(elem, Some(_)) if elem.base == CTyBase::Primitive(CPTy::Char)
+        ),
+        CTyBase::Primitive(_) => false,
+    }
+}
+
+/// Whether an object of the type can be defined.
+fn is_complete(ty: CTy<'_>) -> bool {
+    match ty.base {
+        CTyBase::Primitive(ty) => ty != CPTy::Void,
+        CTyBase::Ref(kind) => match kind.0 {
+            CTyKind::Record(record) => record.is_complete(),
+            CTyKind::Array(elem, len) => len.is_some() && is_complete(*elem),
+            CTyKind::Pointer(_) | CTyKind::FnPtr(_) => true,
+        },
+    }
+}
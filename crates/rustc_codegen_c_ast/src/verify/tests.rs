@@ -0,0 +1,94 @@
+use std::num::NonZeroUsize;
+
+use rustc_data_structures::intern::Interned;
+use rustc_target::abi::call::Conv;
+
+use crate::expr::CValue;
+use crate::func::{CFunc, CFuncKind};
+use crate::r#type::{CPTy, CTy};
+use crate::verify::verify_module;
+use crate::{ModuleArena, ModuleCtxt};
+
+/// A function `int32_t name(int32_t*)` of the module.
+fn new_func<'mx>(mcx: ModuleCtxt<'mx>, name: &'mx str) -> CFunc<'mx> {
+    let i32_ty = CTy::primitive(CPTy::I32);
+    let ty = mcx.fn_ptr(i32_ty, [mcx.ptr(i32_ty)].into(), Conv::C);
+    let func = Interned::new_unchecked(mcx.create_func(CFuncKind::new(name, ty, false)));
+    mcx.module().push_func(func);
+    func
+}
+
+/// Build a module with `build`, returning the message of the verifier error.
+fn verify(build: impl for<'mx> FnOnce(ModuleCtxt<'mx>)) -> Result<(), String> {
+    let arena = ModuleArena::new();
+    let mcx = ModuleCtxt(&arena);
+    build(mcx);
+    verify_module(mcx).map_err(|err| err.msg)
+}
+
+#[test]
+fn well_formed() {
+    let result = verify(|mcx| {
+        let f = new_func(mcx, "f");
+        let p = mcx.value(CValue::Local(0));
+        let buf = f.0.new_pending_alloc(mcx.arr(mcx.char(), NonZeroUsize::new(8)), 8);
+        let x = f.0.next_local_var();
+        let bb = f.0.new_bb("start", &mcx);
+        bb.push_stmt(mcx.decl(mcx.var(x, CTy::primitive(CPTy::I64), Some(mcx.unary("*", p)))));
+        let i32_ptr = mcx.ptr(CTy::primitive(CPTy::I32));
+        let buf_i32 = mcx.unary("*", mcx.cast(i32_ptr, mcx.value(buf)));
+        bb.push_stmt(mcx.expr(mcx.assign(buf_i32, mcx.value(x))));
+        bb.push_stmt(mcx.goto("done"));
+        let bb = f.0.new_bb("done", &mcx);
+        bb.push_stmt(mcx.ret(Some(mcx.call(mcx.value(CValue::Func("f")), [p]))));
+    });
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn undeclared_local() {
+    let result = verify(|mcx| {
+        let f = new_func(mcx, "f");
+        let x = f.0.next_local_var();
+        let bb = f.0.new_bb("start", &mcx);
+        bb.push_stmt(mcx.ret(Some(mcx.value(x))));
+        bb.push_stmt(mcx.decl(mcx.var(x, CTy::primitive(CPTy::I32), None)));
+    });
+    assert_eq!(result, Err("`_1` is used before its declaration".into()));
+}
+
+#[test]
+fn missing_label() {
+    let result = verify(|mcx| {
+        let f = new_func(mcx, "f");
+        let bb = f.0.new_bb("start", &mcx);
+        bb.push_stmt(mcx.goto("done"));
+    });
+    assert_eq!(result, Err("jump to undefined label `done`".into()));
+}
+
+#[test]
+fn argument_type_mismatch() {
+    let result = verify(|mcx| {
+        let f = new_func(mcx, "f");
+        let x = f.0.next_local_var();
+        let f64_ptr = mcx.ptr(CTy::primitive(CPTy::F64));
+        let bb = f.0.new_bb("start", &mcx);
+        bb.push_stmt(mcx.decl(mcx.var(x, f64_ptr, None)));
+        bb.push_stmt(mcx.ret(Some(mcx.call(mcx.value(CValue::Func("f")), [mcx.value(x)]))));
+    });
+    assert_eq!(result, Err("argument 0 of `f` of type `int32_t*` from `double*`".into()));
+}
+
+#[test]
+fn unresolved_alloc() {
+    let result = verify(|mcx| {
+        let f = new_func(mcx, "f");
+        f.0.new_pending_alloc(mcx.arr(mcx.char(), None), 1);
+        f.0.new_bb("start", &mcx);
+    });
+    assert_eq!(
+        result,
+        Err("unresolved allocation `_1` has placeholder type `char[]` instead of `char[N]`".into())
+    );
+}
@@ -0,0 +1,458 @@
+//! Traversal of the C AST.
+//!
+//! A [`Visitor`] walks the AST by shared reference. Each `visit_*` method defaults to the
+//! matching `walk_*` function, so an implementation overrides the nodes it is interested in and
+//! calls `walk_*` to continue into their children.
+//!
+//! A [`Folder`] rewrites the AST. Expressions, statements and declarations are immutable arena
+//! nodes, so a changed node is rebuilt in the module arena, while unchanged nodes are returned
+//! as is. The statements of a basic block and the static assertions and declarations of a module
+//! are replaced in place. The fields of a record are set once, so records are not folded.
+
+use std::ptr;
+
+use crate::decl::{CDecl, CDeclKind};
+use crate::expr::{CExpr, CExprKind, CValue};
+use crate::func::{CBasicBlock, CFunc};
+use crate::module::Module;
+use crate::r#type::{CTy, CTyBase, CTyKind};
+use crate::stmt::{CStmt, CStmtKind};
+use crate::ModuleCtxt;
+
+#[cfg(test)]
+mod tests;
+
+pub trait Visitor<'mx>: Sized {
+    fn visit_module(&mut self, module: &Module<'mx>) {
+        walk_module(self, module)
+    }
+
+    fn visit_func(&mut self, func: CFunc<'mx>) {
+        walk_func(self, func)
+    }
+
+    fn visit_bb(&mut self, bb: &'mx CBasicBlock<'mx>) {
+        walk_bb(self, bb)
+    }
+
+    fn visit_stmt(&mut self, stmt: CStmt<'mx>) {
+        walk_stmt(self, stmt)
+    }
+
+    fn visit_decl(&mut self, decl: CDecl<'mx>) {
+        walk_decl(self, decl)
+    }
+
+    fn visit_expr(&mut self, expr: CExpr<'mx>) {
+        walk_expr(self, expr)
+    }
+
+    fn visit_value(&mut self, _value: CValue<'mx>) {}
+
+    /// Visit a type. Records are not entered, since they may be recursive; their fields are
+    /// visited with the module.
+    fn visit_ty(&mut self, ty: CTy<'mx>) {
+        walk_ty(self, ty)
+    }
+
+    /// Visit a label, either of a basic block or labeled statement, or as the target of a jump.
+    fn visit_label(&mut self, _label: &'mx str) {}
+}
+
+pub fn walk_module<'mx, V: Visitor<'mx>>(visitor: &mut V, module: &Module<'mx>) {
+    for &record in module.records.borrow().iter() {
+        visitor.visit_ty(record);
+        if let Some(record) = record.record().filter(|record| record.is_complete()) {
+            for field in record.fields() {
+                visitor.visit_ty(field.ty);
+            }
+        }
+    }
+    for &cond in module.static_asserts.borrow().iter() {
+        visitor.visit_expr(cond);
+    }
+    for &decl in module.decls.borrow().iter() {
+        visitor.visit_decl(decl);
+    }
+    for &func in module.funcs.borrow().iter() {
+        visitor.visit_func(func);
+    }
+}
+
+pub fn walk_func<'mx, V: Visitor<'mx>>(visitor: &mut V, func: CFunc<'mx>) {
+    visitor.visit_ty(func.0.ty);
+    for &param in func.0.params.iter() {
+        visitor.visit_value(param);
+    }
+    for (val, alloc) in func.0.allocs() {
+        visitor.visit_value(val);
+        visitor.visit_ty(alloc.ty());
+    }
+    for bb in func.0.basic_blocks() {
+        visitor.visit_bb(bb);
+    }
+}
+
+pub fn walk_bb<'mx, V: Visitor<'mx>>(visitor: &mut V, bb: &'mx CBasicBlock<'mx>) {
+    visitor.visit_label(bb.label);
+    let stmts = bb.stmts.borrow().clone();
+    for stmt in stmts {
+        visitor.visit_stmt(stmt);
+    }
+}
+
+pub fn walk_stmt<'mx, V: Visitor<'mx>>(visitor: &mut V, stmt: CStmt<'mx>) {
+    match stmt {
+        CStmtKind::If { cond, then_br, else_br } => {
+            visitor.visit_expr(cond);
+            visitor.visit_stmt(then_br);
+            if let Some(else_br) = else_br {
+                visitor.visit_stmt(else_br);
+            }
+        }
+        CStmtKind::For { init, cond, next, body } => {
+            visitor.visit_stmt(init);
+            visitor.visit_expr(cond);
+            visitor.visit_stmt(next);
+            visitor.visit_stmt(body);
+        }
+        CStmtKind::Return(ret) => {
+            if let Some(ret) = ret {
+                visitor.visit_expr(ret);
+            }
+        }
+        CStmtKind::Decl(decl) => visitor.visit_decl(decl),
+        CStmtKind::Expr(expr) => visitor.visit_expr(expr),
+        CStmtKind::Goto(label) => visitor.visit_label(label),
+        CStmtKind::Switch { value, cases, default } => {
+            visitor.visit_expr(value);
+            for &(case, label) in cases.iter() {
+                visitor.visit_expr(case);
+                visitor.visit_label(label);
+            }
+            visitor.visit_label(default);
+        }
+        CStmtKind::Block(stmts) => {
+            for stmt in stmts.iter() {
+                visitor.visit_stmt(stmt);
+            }
+        }
+        CStmtKind::While { cond, body } => {
+            visitor.visit_expr(cond);
+            visitor.visit_stmt(body);
+        }
+        CStmtKind::DoWhile { body, cond } => {
+            visitor.visit_stmt(body);
+            visitor.visit_expr(cond);
+        }
+        CStmtKind::Break | CStmtKind::Continue => {}
+        CStmtKind::Label(label) => visitor.visit_label(label),
+    }
+}
+
+pub fn walk_decl<'mx, V: Visitor<'mx>>(visitor: &mut V, decl: CDecl<'mx>) {
+    match decl {
+        CDeclKind::Var { name, ty, init, .. } => {
+            visitor.visit_value(*name);
+            visitor.visit_ty(*ty);
+            if let Some(init) = init {
+                visitor.visit_expr(init);
+            }
+        }
+        CDeclKind::Func { name, fn_ptr, .. } => {
+            visitor.visit_value(*name);
+            visitor.visit_ty(fn_ptr.ret);
+            for &arg in fn_ptr.args.iter() {
+                visitor.visit_ty(arg);
+            }
+        }
+    }
+}
+
+pub fn walk_expr<'mx, V: Visitor<'mx>>(visitor: &mut V, expr: CExpr<'mx>) {
+    match expr {
+        CExprKind::Raw(_) | CExprKind::Str(_) => {}
+        CExprKind::Value(value) => visitor.visit_value(*value),
+        CExprKind::Unary { expr, .. }
+        | CExprKind::Member { expr, .. }
+        | CExprKind::Designated { expr, .. } => visitor.visit_expr(expr),
+        CExprKind::Binary { lhs, rhs, .. } => {
+            visitor.visit_expr(lhs);
+            visitor.visit_expr(rhs);
+        }
+        CExprKind::Ternary { cond, then_expr, else_expr } => {
+            visitor.visit_expr(cond);
+            visitor.visit_expr(then_expr);
+            visitor.visit_expr(else_expr);
+        }
+        CExprKind::Index { expr, index } => {
+            visitor.visit_expr(expr);
+            visitor.visit_expr(index);
+        }
+        CExprKind::Cast { ty, expr } => {
+            visitor.visit_ty(*ty);
+            visitor.visit_expr(expr);
+        }
+        CExprKind::Call { callee, args } => {
+            visitor.visit_expr(callee);
+            for arg in args.iter() {
+                visitor.visit_expr(arg);
+            }
+        }
+        CExprKind::InitList { exprs } => {
+            for expr in exprs.iter() {
+                visitor.visit_expr(expr);
+            }
+        }
+        CExprKind::SizeOf(ty) | CExprKind::AlignOf(ty) | CExprKind::OffsetOf { ty, .. } => {
+            visitor.visit_ty(*ty)
+        }
+    }
+}
+
+pub fn walk_ty<'mx, V: Visitor<'mx>>(visitor: &mut V, ty: CTy<'mx>) {
+    let CTyBase::Ref(kind) = ty.base else { return };
+    match kind.0 {
+        CTyKind::Pointer(ty) | CTyKind::Array(ty, _) => visitor.visit_ty(*ty),
+        CTyKind::Record(_) => {}
+        CTyKind::FnPtr(fn_ptr) => {
+            visitor.visit_ty(fn_ptr.ret);
+            for &arg in fn_ptr.args.iter() {
+                visitor.visit_ty(arg);
+            }
+        }
+    }
+}
+
+pub trait Folder<'mx>: Sized {
+    /// The module context whose arena rebuilt nodes are allocated in.
+    fn mcx(&self) -> ModuleCtxt<'mx>;
+
+    /// Fold the static assertions, declarations and functions of a module, replacing the static
+    /// assertions and declarations in place.
+    fn fold_module(&mut self, module: &Module<'mx>) {
+        walk_module_mut(self, module)
+    }
+
+    fn fold_func(&mut self, func: CFunc<'mx>) {
+        walk_func_mut(self, func)
+    }
+
+    /// Fold the statements of a basic block, replacing them in place.
+    fn fold_bb(&mut self, bb: &'mx CBasicBlock<'mx>) {
+        walk_bb_mut(self, bb)
+    }
+
+    /// Fold a list of statements. Statements can be removed or added by overriding this.
+    fn fold_stmts(&mut self, stmts: Vec<CStmt<'mx>>) -> Vec<CStmt<'mx>> {
+        stmts.into_iter().map(|stmt| self.fold_stmt(stmt)).collect()
+    }
+
+    fn fold_stmt(&mut self, stmt: CStmt<'mx>) -> CStmt<'mx> {
+        walk_stmt_mut(self, stmt)
+    }
+
+    fn fold_decl(&mut self, decl: CDecl<'mx>) -> CDecl<'mx> {
+        walk_decl_mut(self, decl)
+    }
+
+    fn fold_expr(&mut self, expr: CExpr<'mx>) -> CExpr<'mx> {
+        walk_expr_mut(self, expr)
+    }
+
+    fn fold_value(&mut self, value: CValue<'mx>) -> CValue<'mx> {
+        value
+    }
+
+    fn fold_ty(&mut self, ty: CTy<'mx>) -> CTy<'mx> {
+        ty
+    }
+
+    /// Fold the target of a jump.
+    fn fold_label(&mut self, label: &'mx str) -> &'mx str {
+        label
+    }
+}
+
+pub fn walk_module_mut<'mx, F: Folder<'mx>>(folder: &mut F, module: &Module<'mx>) {
+    let asserts = module.static_asserts.take();
+    let asserts = asserts.into_iter().map(|cond| folder.fold_expr(cond)).collect();
+    *module.static_asserts.borrow_mut() = asserts;
+
+    let decls = module.decls.take();
+    let decls = decls.into_iter().map(|decl| folder.fold_decl(decl)).collect();
+    *module.decls.borrow_mut() = decls;
+
+    let funcs = module.funcs.borrow().clone();
+    for func in funcs {
+        folder.fold_func(func);
+    }
+}
+
+pub fn walk_func_mut<'mx, F: Folder<'mx>>(folder: &mut F, func: CFunc<'mx>) {
+    for bb in func.0.basic_blocks() {
+        folder.fold_bb(bb);
+    }
+}
+
+/// The statements are taken out of the block while they are folded.
+pub fn walk_bb_mut<'mx, F: Folder<'mx>>(folder: &mut F, bb: &'mx CBasicBlock<'mx>) {
+    let stmts = bb.stmts.take();
+    let stmts = folder.fold_stmts(stmts);
+    *bb.stmts.borrow_mut() = stmts;
+}
+
+pub fn walk_stmt_mut<'mx, F: Folder<'mx>>(folder: &mut F, stmt: CStmt<'mx>) -> CStmt<'mx> {
+    let mut f = Rebuild { folder, changed: false };
+    let kind = match stmt {
+        CStmtKind::If { cond, then_br, else_br } => CStmtKind::If {
+            cond: f.expr(cond),
+            then_br: f.stmt(then_br),
+            else_br: else_br.map(|else_br| f.stmt(else_br)),
+        },
+        CStmtKind::For { init, cond, next, body } => CStmtKind::For {
+            init: f.stmt(init),
+            cond: f.expr(cond),
+            next: f.stmt(next),
+            body: f.stmt(body),
+        },
+        CStmtKind::Return(ret) => CStmtKind::Return(ret.map(|ret| f.expr(ret))),
+        CStmtKind::Decl(decl) => CStmtKind::Decl(f.decl(decl)),
+        CStmtKind::Expr(expr) => CStmtKind::Expr(f.expr(expr)),
+        CStmtKind::Goto(label) => CStmtKind::Goto(f.label(label)),
+        CStmtKind::Switch { value, cases, default } => CStmtKind::Switch {
+            value: f.expr(value),
+            cases: cases.iter().map(|&(case, label)| (f.expr(case), f.label(label))).collect(),
+            default: f.label(default),
+        },
+        CStmtKind::Block(stmts) => {
+            CStmtKind::Block(stmts.iter().map(|&stmt| f.stmt(stmt)).collect())
+        }
+        CStmtKind::While { cond, body } => {
+            CStmtKind::While { cond: f.expr(cond), body: f.stmt(body) }
+        }
+        CStmtKind::DoWhile { body, cond } => {
+            CStmtKind::DoWhile { body: f.stmt(body), cond: f.expr(cond) }
+        }
+        // a label is not a jump target, and its statement has no children
+        CStmtKind::Break | CStmtKind::Continue | CStmtKind::Label(_) => return stmt,
+    };
+    if f.changed {
+        let mcx = f.folder.mcx();
+        let new = mcx.create_stmt(kind);
+        mcx.copy_loc(stmt, new);
+        new
+    } else {
+        stmt
+    }
+}
+
+pub fn walk_decl_mut<'mx, F: Folder<'mx>>(folder: &mut F, decl: CDecl<'mx>) -> CDecl<'mx> {
+    let mut f = Rebuild { folder, changed: false };
+    let kind = match decl {
+        CDeclKind::Var { name, ty, init, linkage, attrs } => CDeclKind::Var {
+            name: f.value(*name),
+            ty: f.ty(*ty),
+            init: init.map(|init| f.expr(init)),
+            linkage: *linkage,
+            attrs: attrs.clone(),
+        },
+        CDeclKind::Func { name, fn_ptr, linkage, attrs } => CDeclKind::Func {
+            name: f.value(*name),
+            fn_ptr,
+            linkage: *linkage,
+            attrs: attrs.clone(),
+        },
+    };
+    if f.changed {
+        f.folder.mcx().create_decl(kind)
+    } else {
+        decl
+    }
+}
+
+pub fn walk_expr_mut<'mx, F: Folder<'mx>>(folder: &mut F, expr: CExpr<'mx>) -> CExpr<'mx> {
+    let mut f = Rebuild { folder, changed: false };
+    let kind = match *expr {
+        CExprKind::Raw(_) | CExprKind::Str(_) => return expr,
+        CExprKind::Value(value) => CExprKind::Value(f.value(value)),
+        CExprKind::Unary { op, expr } => CExprKind::Unary { op, expr: f.expr(expr) },
+        CExprKind::Binary { lhs, rhs, op } => {
+            CExprKind::Binary { lhs: f.expr(lhs), rhs: f.expr(rhs), op }
+        }
+        CExprKind::Ternary { cond, then_expr, else_expr } => CExprKind::Ternary {
+            cond: f.expr(cond),
+            then_expr: f.expr(then_expr),
+            else_expr: f.expr(else_expr),
+        },
+        CExprKind::Index { expr, index } => {
+            CExprKind::Index { expr: f.expr(expr), index: f.expr(index) }
+        }
+        CExprKind::Cast { ty, expr } => CExprKind::Cast { ty: f.ty(ty), expr: f.expr(expr) },
+        CExprKind::Call { callee, ref args } => CExprKind::Call {
+            callee: f.expr(callee),
+            args: args.iter().map(|&arg| f.expr(arg)).collect(),
+        },
+        CExprKind::Member { expr, arrow, field } => {
+            CExprKind::Member { expr: f.expr(expr), arrow, field }
+        }
+        CExprKind::InitList { ref exprs } => {
+            CExprKind::InitList { exprs: exprs.iter().map(|&expr| f.expr(expr)).collect() }
+        }
+        CExprKind::Designated { field, expr } => {
+            CExprKind::Designated { field, expr: f.expr(expr) }
+        }
+        CExprKind::SizeOf(ty) => CExprKind::SizeOf(f.ty(ty)),
+        CExprKind::AlignOf(ty) => CExprKind::AlignOf(f.ty(ty)),
+        CExprKind::OffsetOf { ty, field } => CExprKind::OffsetOf { ty: f.ty(ty), field },
+    };
+    if f.changed {
+        f.folder.mcx().create_expr(kind)
+    } else {
+        expr
+    }
+}
+
+/// Folds the children of a node, recording whether any of them changed.
+struct Rebuild<'a, F> {
+    folder: &'a mut F,
+    changed: bool,
+}
+
+impl<'a, 'mx, F: Folder<'mx>> Rebuild<'a, F> {
+    fn stmt(&mut self, stmt: CStmt<'mx>) -> CStmt<'mx> {
+        let folded = self.folder.fold_stmt(stmt);
+        self.changed |= !ptr::eq(folded, stmt);
+        folded
+    }
+
+    fn decl(&mut self, decl: CDecl<'mx>) -> CDecl<'mx> {
+        let folded = self.folder.fold_decl(decl);
+        self.changed |= !ptr::eq(folded, decl);
+        folded
+    }
+
+    fn expr(&mut self, expr: CExpr<'mx>) -> CExpr<'mx> {
+        let folded = self.folder.fold_expr(expr);
+        self.changed |= !ptr::eq(folded, expr);
+        folded
+    }
+
+    fn value(&mut self, value: CValue<'mx>) -> CValue<'mx> {
+        let folded = self.folder.fold_value(value);
+        self.changed |= folded != value;
+        folded
+    }
+
+    fn ty(&mut self, ty: CTy<'mx>) -> CTy<'mx> {
+        let folded = self.folder.fold_ty(ty);
+        self.changed |= folded != ty;
+        folded
+    }
+
+    fn label(&mut self, label: &'mx str) -> &'mx str {
+        let folded = self.folder.fold_label(label);
+        self.changed |= folded != label;
+        folded
+    }
+}
@@ -0,0 +1,136 @@
+use rustc_data_structures::intern::Interned;
+use rustc_target::abi::call::Conv;
+
+use crate::decl::{CDeclKind, CLinkage};
+use crate::expr::{CExpr, CExprKind, CValue};
+use crate::func::{CFunc, CFuncKind};
+use crate::r#type::{CPTy, CTy};
+use crate::stmt::{CStmt, CStmtKind};
+use crate::visit::{Folder, Visitor};
+use crate::{ModuleArena, ModuleCtxt};
+
+/// A module with a static assertion, a global and a function `f(a)` which jumps between two
+/// basic blocks.
+fn build_module(mcx: ModuleCtxt<'_>) -> CFunc<'_> {
+    let i32_ty = CTy::primitive(CPTy::I32);
+    mcx.module().push_static_assert(mcx.binary(
+        mcx.size_of(i32_ty),
+        mcx.value(mcx.scalar(4)),
+        "==",
+    ));
+    let global = mcx.next_global_var();
+    mcx.module().push_decl(mcx.global_var(
+        global,
+        i32_ty,
+        Some(mcx.value(mcx.scalar(1))),
+        CLinkage::default(),
+        [],
+    ));
+
+    let ty = mcx.fn_ptr(i32_ty, [i32_ty].into(), Conv::C);
+    let func = Interned::new_unchecked(mcx.create_func(CFuncKind::new("f", ty, false)));
+    mcx.module().push_func(func);
+    let a = mcx.value(CValue::Local(0));
+    let bb = func.0.new_bb("start", &mcx);
+    bb.push_stmt(mcx.if_stmt(mcx.binary(a, mcx.value(global), "<"), mcx.goto("done"), None));
+    bb.push_stmt(mcx.expr(mcx.assign(a, mcx.binary(a, mcx.value(mcx.scalar(2)), "*"))));
+    let bb = func.0.new_bb("done", &mcx);
+    bb.push_stmt(mcx.ret(Some(a)));
+    func
+}
+
+#[derive(Default)]
+struct Collect<'mx> {
+    values: Vec<CValue<'mx>>,
+    labels: Vec<&'mx str>,
+}
+
+impl<'mx> Visitor<'mx> for Collect<'mx> {
+    fn visit_value(&mut self, value: CValue<'mx>) {
+        self.values.push(value);
+    }
+
+    fn visit_label(&mut self, label: &'mx str) {
+        self.labels.push(label);
+    }
+}
+
+#[test]
+fn visitor_walks_module() {
+    let arena = ModuleArena::new();
+    let mcx = ModuleCtxt(&arena);
+    build_module(mcx);
+
+    let mut collect = Collect::default();
+    collect.visit_module(mcx.module());
+    let (global, a) = (CValue::Global(0), CValue::Local(0));
+    let scalar = CValue::Scalar;
+    assert_eq!(collect.values, [scalar(4), global, scalar(1), a, a, global, a, a, scalar(2), a]);
+    assert_eq!(collect.labels, ["start", "done", "done"]);
+}
+
+/// Replaces `from` with `to` and removes assignments.
+struct Replace<'mx> {
+    mcx: ModuleCtxt<'mx>,
+    from: CValue<'mx>,
+    to: CValue<'mx>,
+}
+
+impl<'mx> Folder<'mx> for Replace<'mx> {
+    fn mcx(&self) -> ModuleCtxt<'mx> {
+        self.mcx
+    }
+
+    fn fold_stmts(&mut self, stmts: Vec<CStmt<'mx>>) -> Vec<CStmt<'mx>> {
+        stmts
+            .into_iter()
+            .filter(|stmt| !matches!(stmt, CStmtKind::Expr(CExprKind::Binary { op: "=", .. })))
+            .map(|stmt| self.fold_stmt(stmt))
+            .collect()
+    }
+
+    fn fold_value(&mut self, value: CValue<'mx>) -> CValue<'mx> {
+        if value == self.from {
+            self.to
+        } else {
+            value
+        }
+    }
+}
+
+fn contains_value(expr: CExpr<'_>, value: CValue<'_>) -> bool {
+    let mut collect = Collect::default();
+    collect.visit_expr(expr);
+    collect.values.contains(&value)
+}
+
+#[test]
+fn folder_rewrites_module() {
+    let arena = ModuleArena::new();
+    let mcx = ModuleCtxt(&arena);
+    let func = build_module(mcx);
+    let assert = mcx.module().static_asserts.borrow()[0];
+    let start = func.0.basic_blocks()[0];
+    let ret = func.0.basic_blocks()[1].stmts.borrow()[0];
+
+    let (old, new) = (CValue::Global(0), CValue::Global(1));
+    Replace { mcx, from: old, to: new }.fold_module(mcx.module());
+
+    // unchanged nodes are kept as is
+    assert!(std::ptr::eq(mcx.module().static_asserts.borrow()[0], assert));
+    assert!(std::ptr::eq(func.0.basic_blocks()[1].stmts.borrow()[0], ret));
+
+    let decl = mcx.module().decls.borrow()[0];
+    let CDeclKind::Var { name, .. } = decl else { panic!() };
+    assert_eq!(*name, new);
+    let stmts = start.stmts.borrow().clone();
+    assert_eq!(stmts.len(), 1);
+    let CStmtKind::If { cond, .. } = stmts[0] else { panic!() };
+    assert!(contains_value(cond, new) && !contains_value(cond, old));
+
+    Replace { mcx, from: CValue::Scalar(4), to: CValue::Scalar(8) }.fold_module(mcx.module());
+    let assert = mcx.module().static_asserts.borrow()[0];
+    assert!(
+        contains_value(assert, CValue::Scalar(8)) && !contains_value(assert, CValue::Scalar(4))
+    );
+}
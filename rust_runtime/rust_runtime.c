@@ -1,5 +1,11 @@
 #include "rust_runtime.h"
 
+#include <math.h>
+
+double __rust_fmod(double x, double y) { return fmod(x, y); }
+
+float __rust_fmodf(float x, float y) { return fmodf(x, y); }
+
 bool __rust_ckd_add_i8(int8_t x, int8_t y, int8_t *result) {
   if ((x > 0 && y > INT8_MAX - x) || (x < 0 && y < INT8_MIN - x))
     return true;
@@ -382,4 +388,261 @@ bool __rust_ckd_rem_uintptr(uintptr_t x, uintptr_t y, uintptr_t *result) {
     return true;
   *result = x % y;
   return false;
-}
\ No newline at end of file
+}
+
+#ifndef __RUST_INT128_NATIVE
+__rust_u128 __rust_u128_from_u64(uint64_t x) {
+  __rust_u128 r = {x, 0};
+  return r;
+}
+
+__rust_u128 __rust_u128_from_i64(int64_t x) {
+  __rust_u128 r = {(uint64_t)x, x < 0 ? UINT64_MAX : 0};
+  return r;
+}
+
+uint64_t __rust_u64_from_u128(__rust_u128 x) { return x.lo; }
+
+__rust_u128 __rust_u128_from_i128(__rust_i128 x) {
+  __rust_u128 r = {x.lo, (uint64_t)x.hi};
+  return r;
+}
+
+__rust_i128 __rust_i128_from_u128(__rust_u128 x) {
+  __rust_i128 r = {x.lo, __rust_utos(uint64_t, int64_t, x.hi, INT64_MAX)};
+  return r;
+}
+
+static bool __rust_is_neg_i128(__rust_i128 x) { return x.hi < 0; }
+
+__rust_u128 __rust_add_u128(__rust_u128 x, __rust_u128 y) {
+  __rust_u128 r;
+  r.lo = x.lo + y.lo;
+  r.hi = x.hi + y.hi + (r.lo < x.lo);
+  return r;
+}
+
+__rust_u128 __rust_sub_u128(__rust_u128 x, __rust_u128 y) {
+  __rust_u128 r;
+  r.lo = x.lo - y.lo;
+  r.hi = x.hi - y.hi - (x.lo < y.lo);
+  return r;
+}
+
+__rust_u128 __rust_mul_u128(__rust_u128 x, __rust_u128 y) {
+  uint64_t x0 = x.lo & UINT32_MAX, x1 = x.lo >> 32;
+  uint64_t y0 = y.lo & UINT32_MAX, y1 = y.lo >> 32;
+  uint64_t p00 = x0 * y0, p01 = x0 * y1, p10 = x1 * y0, p11 = x1 * y1;
+  uint64_t mid = (p00 >> 32) + (p01 & UINT32_MAX) + (p10 & UINT32_MAX);
+  __rust_u128 r;
+  r.lo = (mid << 32) | (p00 & UINT32_MAX);
+  r.hi = p11 + (p01 >> 32) + (p10 >> 32) + (mid >> 32);
+  r.hi += x.lo * y.hi + x.hi * y.lo;
+  return r;
+}
+
+/* long division, one bit at a time */
+static __rust_u128 __rust_divrem_u128(__rust_u128 x, __rust_u128 y,
+                                      __rust_u128 *rem) {
+  __rust_u128 q = {0, 0}, r = {0, 0};
+  int i;
+  for (i = 127; i >= 0; i--) {
+    uint64_t bit = i >= 64 ? (x.hi >> (i - 64)) & 1 : (x.lo >> i) & 1;
+    r.hi = (r.hi << 1) | (r.lo >> 63);
+    r.lo = (r.lo << 1) | bit;
+    if (__rust_cmp_u128(r, y) >= 0) {
+      r = __rust_sub_u128(r, y);
+      if (i >= 64)
+        q.hi |= (uint64_t)1 << (i - 64);
+      else
+        q.lo |= (uint64_t)1 << i;
+    }
+  }
+  *rem = r;
+  return q;
+}
+
+__rust_u128 __rust_div_u128(__rust_u128 x, __rust_u128 y) {
+  __rust_u128 rem;
+  return __rust_divrem_u128(x, y, &rem);
+}
+
+__rust_u128 __rust_rem_u128(__rust_u128 x, __rust_u128 y) {
+  __rust_u128 rem;
+  __rust_divrem_u128(x, y, &rem);
+  return rem;
+}
+
+__rust_u128 __rust_and_u128(__rust_u128 x, __rust_u128 y) {
+  __rust_u128 r = {x.lo & y.lo, x.hi & y.hi};
+  return r;
+}
+
+__rust_u128 __rust_or_u128(__rust_u128 x, __rust_u128 y) {
+  __rust_u128 r = {x.lo | y.lo, x.hi | y.hi};
+  return r;
+}
+
+__rust_u128 __rust_xor_u128(__rust_u128 x, __rust_u128 y) {
+  __rust_u128 r = {x.lo ^ y.lo, x.hi ^ y.hi};
+  return r;
+}
+
+__rust_u128 __rust_shl_u128(__rust_u128 x, __rust_u128 y) {
+  unsigned n = y.lo & 127;
+  __rust_u128 r;
+  if (n == 0)
+    return x;
+  if (n >= 64) {
+    r.hi = x.lo << (n - 64);
+    r.lo = 0;
+  } else {
+    r.hi = (x.hi << n) | (x.lo >> (64 - n));
+    r.lo = x.lo << n;
+  }
+  return r;
+}
+
+__rust_u128 __rust_shr_u128(__rust_u128 x, __rust_u128 y) {
+  unsigned n = y.lo & 127;
+  __rust_u128 r;
+  if (n == 0)
+    return x;
+  if (n >= 64) {
+    r.lo = x.hi >> (n - 64);
+    r.hi = 0;
+  } else {
+    r.lo = (x.lo >> n) | (x.hi << (64 - n));
+    r.hi = x.hi >> n;
+  }
+  return r;
+}
+
+__rust_u128 __rust_neg_u128(__rust_u128 x) {
+  __rust_u128 zero = {0, 0};
+  return __rust_sub_u128(zero, x);
+}
+
+__rust_u128 __rust_not_u128(__rust_u128 x) {
+  __rust_u128 r = {~x.lo, ~x.hi};
+  return r;
+}
+
+int __rust_cmp_u128(__rust_u128 x, __rust_u128 y) {
+  if (x.hi != y.hi)
+    return x.hi < y.hi ? -1 : 1;
+  if (x.lo != y.lo)
+    return x.lo < y.lo ? -1 : 1;
+  return 0;
+}
+
+#define __RUST_I128_VIA_U128(op)                                               \
+  __rust_i128 __rust_##op##_i128(__rust_i128 x, __rust_i128 y) {               \
+    return __rust_i128_from_u128(__rust_##op##_u128(                           \
+        __rust_u128_from_i128(x), __rust_u128_from_i128(y)));                  \
+  }
+
+__RUST_I128_VIA_U128(add)
+__RUST_I128_VIA_U128(sub)
+__RUST_I128_VIA_U128(mul)
+__RUST_I128_VIA_U128(and)
+__RUST_I128_VIA_U128(or)
+__RUST_I128_VIA_U128(xor)
+__RUST_I128_VIA_U128(shl)
+
+/* the quotient is rounded towards zero, the remainder has the sign of x */
+__rust_i128 __rust_div_i128(__rust_i128 x, __rust_i128 y) {
+  __rust_u128 ux = __rust_u128_from_i128(x), uy = __rust_u128_from_i128(y);
+  __rust_u128 q;
+  if (__rust_is_neg_i128(x))
+    ux = __rust_neg_u128(ux);
+  if (__rust_is_neg_i128(y))
+    uy = __rust_neg_u128(uy);
+  q = __rust_div_u128(ux, uy);
+  if (__rust_is_neg_i128(x) != __rust_is_neg_i128(y))
+    q = __rust_neg_u128(q);
+  return __rust_i128_from_u128(q);
+}
+
+__rust_i128 __rust_rem_i128(__rust_i128 x, __rust_i128 y) {
+  __rust_u128 ux = __rust_u128_from_i128(x), uy = __rust_u128_from_i128(y);
+  __rust_u128 r;
+  if (__rust_is_neg_i128(x))
+    ux = __rust_neg_u128(ux);
+  if (__rust_is_neg_i128(y))
+    uy = __rust_neg_u128(uy);
+  r = __rust_rem_u128(ux, uy);
+  if (__rust_is_neg_i128(x))
+    r = __rust_neg_u128(r);
+  return __rust_i128_from_u128(r);
+}
+
+/* arithmetic shift: shift the complement of negative numbers logically */
+__rust_i128 __rust_shr_i128(__rust_i128 x, __rust_i128 y) {
+  __rust_u128 ux = __rust_u128_from_i128(x), uy = __rust_u128_from_i128(y);
+  if (__rust_is_neg_i128(x))
+    return __rust_i128_from_u128(
+        __rust_not_u128(__rust_shr_u128(__rust_not_u128(ux), uy)));
+  return __rust_i128_from_u128(__rust_shr_u128(ux, uy));
+}
+
+__rust_i128 __rust_neg_i128(__rust_i128 x) {
+  return __rust_i128_from_u128(__rust_neg_u128(__rust_u128_from_i128(x)));
+}
+
+__rust_i128 __rust_not_i128(__rust_i128 x) {
+  return __rust_i128_from_u128(__rust_not_u128(__rust_u128_from_i128(x)));
+}
+
+int __rust_cmp_i128(__rust_i128 x, __rust_i128 y) {
+  if (x.hi != y.hi)
+    return x.hi < y.hi ? -1 : 1;
+  if (x.lo != y.lo)
+    return x.lo < y.lo ? -1 : 1;
+  return 0;
+}
+
+bool __rust_ckd_add_i128(__rust_i128 x, __rust_i128 y, __rust_i128 *result) {
+  *result = __rust_add_i128(x, y);
+  return __rust_is_neg_i128(x) == __rust_is_neg_i128(y) &&
+         __rust_is_neg_i128(*result) != __rust_is_neg_i128(x);
+}
+
+bool __rust_ckd_add_u128(__rust_u128 x, __rust_u128 y, __rust_u128 *result) {
+  *result = __rust_add_u128(x, y);
+  return __rust_cmp_u128(*result, x) < 0;
+}
+
+bool __rust_ckd_sub_i128(__rust_i128 x, __rust_i128 y, __rust_i128 *result) {
+  *result = __rust_sub_i128(x, y);
+  return __rust_is_neg_i128(x) != __rust_is_neg_i128(y) &&
+         __rust_is_neg_i128(*result) != __rust_is_neg_i128(x);
+}
+
+bool __rust_ckd_sub_u128(__rust_u128 x, __rust_u128 y, __rust_u128 *result) {
+  *result = __rust_sub_u128(x, y);
+  return __rust_cmp_u128(x, y) < 0;
+}
+
+bool __rust_ckd_mul_i128(__rust_i128 x, __rust_i128 y, __rust_i128 *result) {
+  __rust_i128 min = __rust_i128_const(0x8000000000000000, 0);
+  __rust_i128 minus_one = __rust_i128_const(UINT64_MAX, UINT64_MAX);
+  __rust_i128 zero = __rust_i128_const(0, 0);
+  *result = __rust_mul_i128(x, y);
+  if (__rust_cmp_i128(x, zero) == 0 || __rust_cmp_i128(y, zero) == 0)
+    return false;
+  if (__rust_cmp_i128(x, minus_one) == 0)
+    return __rust_cmp_i128(y, min) == 0;
+  if (__rust_cmp_i128(y, minus_one) == 0)
+    return __rust_cmp_i128(x, min) == 0;
+  return __rust_cmp_i128(__rust_div_i128(*result, x), y) != 0;
+}
+
+bool __rust_ckd_mul_u128(__rust_u128 x, __rust_u128 y, __rust_u128 *result) {
+  __rust_u128 zero = {0, 0};
+  *result = __rust_mul_u128(x, y);
+  if (__rust_cmp_u128(x, zero) == 0)
+    return false;
+  return __rust_cmp_u128(__rust_div_u128(*result, x), y) != 0;
+}
+#endif
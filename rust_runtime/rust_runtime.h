@@ -5,7 +5,177 @@
  * example: `__rust_utos(uint32_t, int32_t, x, INT32_MAX)`
  */
 #define __rust_utos(u, s, v, m)                                                \
-  ((v) <= (m) ? ((s)v) : ((s)((u)(v) - (u)(m) - 1)))
+  ((v) <= (m) ? ((s)(v)) : ((s)((u)(v) - (u)(m) - 1) - (m) - 1))
+
+/** infinity and NaN constants
+ * these are provided here so that generated code does not need `<math.h>`,
+ * whose declarations may conflict with Rust functions of the same name
+ * `__rust_qnan` and `__rust_snan` are quiet and signaling NaNs with the given
+ * payload, e.g. `__rust_qnanf("0x1")` has the bits `0x7fc00001`; the payload
+ * is lost without the GNU builtins
+ */
+#if defined(__GNUC__)
+#define __rust_inf __builtin_inf()
+#define __rust_inff __builtin_inff()
+#define __rust_nan __builtin_nan("")
+#define __rust_nanf __builtin_nanf("")
+#define __rust_qnan(payload) __builtin_nan(payload)
+#define __rust_qnanf(payload) __builtin_nanf(payload)
+#define __rust_snan(payload) __builtin_nans(payload)
+#define __rust_snanf(payload) __builtin_nansf(payload)
+#else
+#define __rust_inf (1.0 / 0.0)
+#define __rust_inff (1.0f / 0.0f)
+#define __rust_nan (0.0 / 0.0)
+#define __rust_nanf (0.0f / 0.0f)
+#define __rust_qnan(payload) __rust_nan
+#define __rust_qnanf(payload) __rust_nanf
+#define __rust_snan(payload) __rust_nan
+#define __rust_snanf(payload) __rust_nanf
+#endif
+
+/** memory operations
+ * the builtins avoid including `<string.h>` for the same reason as above
+ */
+#if defined(__GNUC__)
+#define __rust_memcpy __builtin_memcpy
+#define __rust_memmove __builtin_memmove
+#define __rust_memset __builtin_memset
+#else
+#include <string.h>
+#define __rust_memcpy memcpy
+#define __rust_memmove memmove
+#define __rust_memset memset
+#endif
+
+/** 128-bit integers
+ * `__int128` is used if the compiler supports it, or else the `_BitInt(128)`
+ * of C23. Otherwise, or if `RUST_RUNTIME_NO_INT128` is defined, they are
+ * emulated by structs of two words, least significant first, with the helpers
+ * in `rust_runtime.c`. Generated code only operates on them through the
+ * helpers below.
+ */
+#if !defined(RUST_RUNTIME_NO_INT128)
+#if defined(__SIZEOF_INT128__)
+#define __RUST_INT128_NATIVE 1
+typedef __int128 __rust_i128;
+typedef unsigned __int128 __rust_u128;
+#elif defined(__BITINT_MAXWIDTH__) && __BITINT_MAXWIDTH__ >= 128
+#define __RUST_INT128_NATIVE 1
+typedef _BitInt(128) __rust_i128;
+typedef unsigned _BitInt(128) __rust_u128;
+#endif
+#endif
+
+#if defined(__RUST_INT128_NATIVE)
+
+#define __rust_u128_const(hi, lo)                                              \
+  (((__rust_u128)(uint64_t)(hi) << 64) | (__rust_u128)(uint64_t)(lo))
+#define __rust_i128_const(hi, lo) ((__rust_i128)__rust_u128_const(hi, lo))
+
+#define __rust_u128_from_u64(x) ((__rust_u128)(uint64_t)(x))
+#define __rust_u128_from_i64(x) ((__rust_u128)(__rust_i128)(int64_t)(x))
+#define __rust_u64_from_u128(x) ((uint64_t)(x))
+#define __rust_u128_from_i128(x) ((__rust_u128)(x))
+#define __rust_i128_from_u128(x) ((__rust_i128)(x))
+
+#define __rust_add_u128(x, y) ((x) + (y))
+#define __rust_sub_u128(x, y) ((x) - (y))
+#define __rust_mul_u128(x, y) ((x) * (y))
+#define __rust_div_u128(x, y) ((x) / (y))
+#define __rust_rem_u128(x, y) ((x) % (y))
+#define __rust_and_u128(x, y) ((x) & (y))
+#define __rust_or_u128(x, y) ((x) | (y))
+#define __rust_xor_u128(x, y) ((x) ^ (y))
+#define __rust_shl_u128(x, y) ((x) << (y))
+#define __rust_shr_u128(x, y) ((x) >> (y))
+#define __rust_neg_u128(x) (-(x))
+#define __rust_not_u128(x) (~(x))
+#define __rust_cmp_u128(x, y) (((x) > (y)) - ((x) < (y)))
+
+/* wrapping signed arithmetic is done on the unsigned type */
+#define __rust_add_i128(x, y)                                                  \
+  ((__rust_i128)((__rust_u128)(x) + (__rust_u128)(y)))
+#define __rust_sub_i128(x, y)                                                  \
+  ((__rust_i128)((__rust_u128)(x) - (__rust_u128)(y)))
+#define __rust_mul_i128(x, y)                                                  \
+  ((__rust_i128)((__rust_u128)(x) * (__rust_u128)(y)))
+#define __rust_div_i128(x, y) ((x) / (y))
+#define __rust_rem_i128(x, y) ((x) % (y))
+#define __rust_and_i128(x, y) ((x) & (y))
+#define __rust_or_i128(x, y) ((x) | (y))
+#define __rust_xor_i128(x, y) ((x) ^ (y))
+#define __rust_shl_i128(x, y) ((__rust_i128)((__rust_u128)(x) << (y)))
+#define __rust_shr_i128(x, y) ((x) >> (y))
+#define __rust_neg_i128(x) ((__rust_i128)(-(__rust_u128)(x)))
+#define __rust_not_i128(x) (~(x))
+#define __rust_cmp_i128(x, y) (((x) > (y)) - ((x) < (y)))
+
+#define __rust_ckd_add_i128(x, y, r) __builtin_add_overflow(x, y, r)
+#define __rust_ckd_add_u128(x, y, r) __builtin_add_overflow(x, y, r)
+#define __rust_ckd_sub_i128(x, y, r) __builtin_sub_overflow(x, y, r)
+#define __rust_ckd_sub_u128(x, y, r) __builtin_sub_overflow(x, y, r)
+#define __rust_ckd_mul_i128(x, y, r) __builtin_mul_overflow(x, y, r)
+#define __rust_ckd_mul_u128(x, y, r) __builtin_mul_overflow(x, y, r)
+#else
+typedef struct {
+  uint64_t lo;
+  uint64_t hi;
+} __rust_u128;
+typedef struct {
+  uint64_t lo;
+  int64_t hi;
+} __rust_i128;
+
+#define __rust_u128_const(hi, lo) ((__rust_u128){(lo), (hi)})
+#define __rust_i128_const(hi, lo)                                              \
+  ((__rust_i128){(lo), __rust_utos(uint64_t, int64_t, (hi), INT64_MAX)})
+
+__rust_u128 __rust_u128_from_u64(uint64_t x);
+__rust_u128 __rust_u128_from_i64(int64_t x);
+uint64_t __rust_u64_from_u128(__rust_u128 x);
+__rust_u128 __rust_u128_from_i128(__rust_i128 x);
+__rust_i128 __rust_i128_from_u128(__rust_u128 x);
+
+__rust_u128 __rust_add_u128(__rust_u128 x, __rust_u128 y);
+__rust_u128 __rust_sub_u128(__rust_u128 x, __rust_u128 y);
+__rust_u128 __rust_mul_u128(__rust_u128 x, __rust_u128 y);
+__rust_u128 __rust_div_u128(__rust_u128 x, __rust_u128 y);
+__rust_u128 __rust_rem_u128(__rust_u128 x, __rust_u128 y);
+__rust_u128 __rust_and_u128(__rust_u128 x, __rust_u128 y);
+__rust_u128 __rust_or_u128(__rust_u128 x, __rust_u128 y);
+__rust_u128 __rust_xor_u128(__rust_u128 x, __rust_u128 y);
+__rust_u128 __rust_shl_u128(__rust_u128 x, __rust_u128 y);
+__rust_u128 __rust_shr_u128(__rust_u128 x, __rust_u128 y);
+__rust_u128 __rust_neg_u128(__rust_u128 x);
+__rust_u128 __rust_not_u128(__rust_u128 x);
+int __rust_cmp_u128(__rust_u128 x, __rust_u128 y);
+
+__rust_i128 __rust_add_i128(__rust_i128 x, __rust_i128 y);
+__rust_i128 __rust_sub_i128(__rust_i128 x, __rust_i128 y);
+__rust_i128 __rust_mul_i128(__rust_i128 x, __rust_i128 y);
+__rust_i128 __rust_div_i128(__rust_i128 x, __rust_i128 y);
+__rust_i128 __rust_rem_i128(__rust_i128 x, __rust_i128 y);
+__rust_i128 __rust_and_i128(__rust_i128 x, __rust_i128 y);
+__rust_i128 __rust_or_i128(__rust_i128 x, __rust_i128 y);
+__rust_i128 __rust_xor_i128(__rust_i128 x, __rust_i128 y);
+__rust_i128 __rust_shl_i128(__rust_i128 x, __rust_i128 y);
+__rust_i128 __rust_shr_i128(__rust_i128 x, __rust_i128 y);
+__rust_i128 __rust_neg_i128(__rust_i128 x);
+__rust_i128 __rust_not_i128(__rust_i128 x);
+int __rust_cmp_i128(__rust_i128 x, __rust_i128 y);
+
+bool __rust_ckd_add_i128(__rust_i128 x, __rust_i128 y, __rust_i128 *result);
+bool __rust_ckd_add_u128(__rust_u128 x, __rust_u128 y, __rust_u128 *result);
+bool __rust_ckd_sub_i128(__rust_i128 x, __rust_i128 y, __rust_i128 *result);
+bool __rust_ckd_sub_u128(__rust_u128 x, __rust_u128 y, __rust_u128 *result);
+bool __rust_ckd_mul_i128(__rust_i128 x, __rust_i128 y, __rust_i128 *result);
+bool __rust_ckd_mul_u128(__rust_u128 x, __rust_u128 y, __rust_u128 *result);
+#endif
+
+/** floating-point remainder, same as `fmod` in `<math.h>` */
+double __rust_fmod(double x, double y);
+float __rust_fmodf(float x, float y);
 
 bool __rust_ckd_add_i8(int8_t x, int8_t y, int8_t *result);
 bool __rust_ckd_add_i16(int16_t x, int16_t y, int16_t *result);
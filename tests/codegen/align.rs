@@ -0,0 +1,84 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: interface
+
+// CHECK: struct [[WIDE:Wide_[0-9]+]] {
+// CHECK: } __attribute__((aligned(64)));
+#[repr(align(64))]
+pub struct Wide {
+    pub x: u32,
+}
+
+// CHECK: struct [[PACKED:Packed_[0-9]+]] {
+// CHECK: } __attribute__((packed, aligned(2)));
+// CHECK: _Static_assert(_Alignof(struct [[WIDE]]) == 64, "layout mismatch");
+// CHECK: _Static_assert(_Alignof(struct [[PACKED]]) == 2, "layout mismatch");
+#[repr(packed(2))]
+pub struct Packed {
+    pub a: u8,
+    pub b: u32,
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// CHECK-LABEL: make_wide
+// CHECK: _Alignas(64) char {{_[0-9]+}}[64];
+#[no_mangle]
+#[inline(never)]
+pub fn make_wide(x: u32) -> u32 {
+    let w = Wide { x };
+    let p = &w as *const Wide as usize;
+    if p % 64 != 0 {
+        return 0;
+    }
+    wide_x(&w)
+}
+
+#[no_mangle]
+#[inline(never)]
+pub fn wide_x(w: &Wide) -> u32 {
+    w.x
+}
+
+// CHECK-LABEL: get_b
+// CHECK: uint32_t [[B:_[0-9]+]];
+// CHECK-NEXT: __rust_memcpy(&[[B]], p_0, sizeof(uint32_t));
+#[no_mangle]
+#[inline(never)]
+pub fn get_b(p: &Packed) -> u32 {
+    p.b
+}
+
+// CHECK-LABEL: set_b
+// CHECK: __rust_memcpy(p_0, &{{_[0-9]+}}, sizeof(uint32_t));
+#[no_mangle]
+#[inline(never)]
+pub fn set_b(p: &mut Packed, b: u32) {
+    p.b = b;
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    if make_wide(7) != 7 {
+        return 1;
+    }
+    let mut p = Packed { a: 1, b: 2 };
+    set_b(&mut p, 0x1234_5678);
+    if get_b(&p) != 0x1234_5678 || p.a != 1 {
+        return 2;
+    }
+    0
+}
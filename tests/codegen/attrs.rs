@@ -0,0 +1,56 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics, fn_align)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// CHECK: __attribute__((noinline)) int32_t never(int32_t x_0)
+#[no_mangle]
+#[inline(never)]
+pub fn never(x: i32) -> i32 {
+    x + 1
+}
+
+// CHECK: __attribute__((cold, section(".text.unlikely_path"))) int32_t
+// CHECK-NEXT: unlikely(int32_t x_0)
+#[no_mangle]
+#[cold]
+#[link_section = ".text.unlikely_path"]
+pub fn unlikely(x: i32) -> i32 {
+    x - 1
+}
+
+// CHECK: __attribute__((aligned(64))) int32_t aligned(int32_t x_0)
+#[no_mangle]
+#[repr(align(64))]
+pub fn aligned(x: i32) -> i32 {
+    x * 3
+}
+
+// CHECK: __attribute__({{.*}}noreturn{{.*}})) void stop()
+#[no_mangle]
+pub fn stop() -> ! {
+    core::intrinsics::abort()
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    if never(1) != 2 || unlikely(1) != 0 || aligned(2) != 6 {
+        stop();
+    }
+    if aligned as usize % 64 != 0 {
+        return 1;
+    }
+    0
+}
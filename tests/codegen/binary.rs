@@ -16,24 +16,21 @@ pub mod libc {
 // CHECK-LABEL: rustc_codegen_c: implementation
 
 // CHECK-LABEL: test_add
-// CHECK: +
-// CHECK-NEXT: +
+// CHECK: return (x_0 + y_1) + z_2;
 #[no_mangle]
 pub fn test_add(x: i32, y: i32, z: i32) -> i32 {
     x + y + z
 }
 
 // CHECK-LABEL: test_sub
-// CHECK: -
-// CHECK-NEXT: -
+// CHECK: return (x_0 - y_1) - z_2;
 #[no_mangle]
 pub fn test_sub(x: i32, y: i32, z: i32) -> i32 {
     x - y - z
 }
 
 // CHECK-LABEL: test_mul
-// CHECK: *
-// CHECK-NEXT: *
+// CHECK: return (x_0 * y_1) * z_2;
 #[no_mangle]
 pub fn test_mul(x: i32, y: i32, z: i32) -> i32 {
     x * y * z
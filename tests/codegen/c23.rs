@@ -0,0 +1,44 @@
+//@ compile-flags: -C llvm-args=c-std=c23
+
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+#[repr(C, align(16))]
+pub struct Aligned {
+    value: u32,
+}
+
+// attributes are standard attributes, which go before the declaration, or after the tag of a
+// struct
+// CHECK-LABEL: rustc_codegen_c: interface
+// CHECK: struct {{\[\[}}gnu::aligned(16)]] [[ALIGNED:Aligned[_0-9]*]] {
+// CHECK: _Static_assert(sizeof(struct [[ALIGNED]]) == 16, "layout mismatch");
+// CHECK: {{\[\[}}gnu::noinline, gnu::cold]] uint32_t unlikely(struct [[ALIGNED]] *aligned_0);
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// CHECK: {{\[\[}}gnu::noinline, gnu::cold]] uint32_t unlikely(struct [[ALIGNED]] *aligned_0) {
+#[no_mangle]
+#[inline(never)]
+#[cold]
+pub fn unlikely(aligned: &Aligned) -> u32 {
+    aligned.value + 1
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    let aligned = Aligned { value: 1 };
+    if unlikely(&aligned) == 2 && &aligned as *const Aligned as usize % 16 == 0 { 0 } else { 1 }
+}
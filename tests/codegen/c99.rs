@@ -0,0 +1,45 @@
+//@ compile-flags: -C llvm-args=c-std=c99
+
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+#[repr(C)]
+pub struct Pair {
+    tag: u8,
+    value: u32,
+}
+
+// C99 has no `_Static_assert`, the layout is checked by arrays of negative size instead
+// CHECK-LABEL: rustc_codegen_c: interface
+// CHECK-NOT: _Static_assert
+// CHECK: typedef char __rust_static_assert_{{[0-9]+}}[(sizeof(struct [[PAIR:Pair[_0-9]*]]) == 8) ? 1 : -1];
+// CHECK: typedef char __rust_static_assert_{{[0-9]+}}[(__alignof__(struct [[PAIR]])
+// CHECK-NEXT: == 4) ? 1 : -1];
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// floating-point constants are hexadecimal
+// CHECK: uint32_t total(struct [[PAIR]] *pair_0) {
+// CHECK: 0x1.8p+1
+#[no_mangle]
+#[inline(never)]
+pub fn total(pair: &Pair) -> u32 {
+    pair.tag as u32 + (pair.value as f64 * 3.0) as u32
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    if total(&Pair { tag: 1, value: 2 }) == 7 { 0 } else { 1 }
+}
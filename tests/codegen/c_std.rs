@@ -0,0 +1,51 @@
+//@ compile-flags: -C llvm-args=c-std=c89
+
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// floating-point constants are decimal in C89
+// CHECK-LABEL: double scale(double x_0) {
+// CHECK-NEXT: return x_0 * 2.5e-1;
+#[no_mangle]
+#[inline(never)]
+pub fn scale(x: f64) -> f64 {
+    x * 0.25
+}
+
+// declarations are hoisted to the start of the function in C89
+// CHECK-LABEL: double sum_scaled(double x_0, uint32_t n_1) {
+// CHECK-NEXT: double _{{[0-9]+}};
+// CHECK-NEXT: uint32_t [[I:_[0-9]+]];
+// CHECK-NEXT: double [[X:_[0-9]+]];
+// CHECK: = 0e0;
+// CHECK: while ([[I]] < n_1) {
+// CHECK-NEXT: [[X]] = scale(x_0);
+#[no_mangle]
+pub fn sum_scaled(x: f64, n: u32) -> f64 {
+    let mut sum = 0.0;
+    let mut i = 0;
+    while i < n {
+        sum += scale(x);
+        i += 1;
+    }
+    sum
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    if sum_scaled(2.0, 4) == 2.0 { 0 } else { 1 }
+}
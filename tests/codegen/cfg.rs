@@ -0,0 +1,44 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// the branch not taken after monomorphization is removed, and so is the label of the entry block
+// CHECK-LABEL: 5scale17h{{.*}}(int32_t x_0) {
+// CHECK-NOT: start:;
+// CHECK-NOT: * 3
+// CHECK: * 2
+// CHECK: }
+#[inline(never)]
+fn scale<T>(x: i32) -> i32 {
+    if core::mem::size_of::<T>() > 4 { x.wrapping_mul(2) } else { x.wrapping_mul(3) }
+}
+
+// CHECK-LABEL: int32_t both(int32_t x_0)
+// CHECK: if ({{.*}} == 2) {
+// CHECK-NEXT: if ({{.*}} == 4) {
+// CHECK-NEXT: = 1;
+// CHECK-NEXT: goto [[RET:bb[0-9]+]];
+// CHECK: = 0;
+// CHECK-NEXT: [[RET]]:;
+#[no_mangle]
+pub fn both(x: i32) -> i32 {
+    if scale::<u64>(x) == 2 && scale::<u64>(x + 1) == 4 { 1 } else { 0 }
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    if both(1) == 1 && both(2) == 0 { 0 } else { 1 }
+}
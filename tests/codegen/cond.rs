@@ -16,8 +16,11 @@ pub mod libc {
 // CHECK-LABEL: rustc_codegen_c: implementation
 
 // CHECK-LABEL: test_cond
-// CHECK: ==
-// CHECK-NEXT: if
+// CHECK: if (x_0 == y_1) {
+// CHECK-NEXT: = (x_0 + y_1) + z_2;
+// CHECK-NEXT: } else {
+// CHECK-NEXT: = (x_0 - y_1) - z_2;
+// CHECK-NEXT: }
 #[no_mangle]
 pub fn test_cond(x: i32, y: i32, z: i32) -> i32 {
     if x == y {
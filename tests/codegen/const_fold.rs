@@ -0,0 +1,36 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// The size and alignment are constants after monomorphization, so the arithmetic on them is
+// folded.
+
+// CHECK-LABEL: 6scaled17h{{.*}}(uintptr_t n_0) {
+// CHECK-NEXT: return (12 + n_0) << 3;
+#[inline(never)]
+fn scaled<T>(n: usize) -> usize {
+    core::mem::size_of::<T>().wrapping_mul(3).wrapping_add(n) << (core::mem::align_of::<T>() - 1)
+}
+
+#[no_mangle]
+pub fn scaled_u32(n: usize) -> usize {
+    scaled::<u32>(n)
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    0
+}
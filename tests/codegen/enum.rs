@@ -0,0 +1,73 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: interface
+
+// the tag is padded to the size of the whole enum
+// CHECK: struct [[SHAPE:Shape_[0-9]+]]_tag {
+// CHECK-NEXT: uint32_t tag;
+// CHECK-NEXT: uint8_t _pad0[12];
+// CHECK-NEXT: };
+
+// variant fields are placed after the tag
+// CHECK: struct [[SHAPE]]_v0 {
+// CHECK-NEXT: uint8_t _pad0[4];
+// CHECK-NEXT: uint32_t f0;
+// CHECK-NEXT: uint8_t _pad1[8];
+// CHECK-NEXT: };
+// CHECK: struct [[SHAPE]]_v1 {
+// CHECK-NEXT: uint8_t _pad0[4];
+// CHECK-NEXT: uint32_t f0;
+// CHECK-NEXT: uint64_t f1;
+// CHECK-NEXT: };
+
+// variants without fields have no member
+// CHECK: union [[SHAPE]]_u {
+// CHECK-NEXT: struct [[SHAPE]]_tag tag;
+// CHECK-NEXT: struct [[SHAPE]]_v0 v0;
+// CHECK-NEXT: struct [[SHAPE]]_v1 v1;
+// CHECK-NEXT: };
+// CHECK: struct [[SHAPE]] {
+// CHECK-NEXT: union [[SHAPE]]_u u;
+// CHECK-NEXT: };
+pub enum Shape {
+    Circle(u32),
+    Rect(u32, u64),
+    Empty,
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// CHECK-LABEL: is_rect
+// CHECK: return
+// CHECK-NEXT: __rust_utos({{.*}}(*(uint32_t*) s_0)
+// CHECK-NEXT: == 1;
+#[no_mangle]
+pub fn is_rect(s: &Shape) -> bool {
+    matches!(s, Shape::Rect(..))
+}
+
+// CHECK-LABEL: make_rect
+// CHECK: struct [[SHAPE]] [[TMP:_[0-9]+]];
+// CHECK: (*(uint32_t*) (&[[TMP]])) = 1;
+#[no_mangle]
+pub fn make_rect(s: &mut Shape, w: u32, h: u64) {
+    *s = Shape::Rect(w, h);
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    0
+}
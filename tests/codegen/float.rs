@@ -0,0 +1,123 @@
+//@ ignore-O0: `bitcast` is not implemented
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// CHECK-LABEL: double arith(double x_0, double y_1)
+// CHECK: (((x_0 + y_1) * (x_0 - y_1)) / 0x1.8p+1) + __rust_fmod(x_0, y_1)
+// CHECK: - 0x1.999999999999ap-4;
+#[no_mangle]
+pub fn arith(x: f64, y: f64) -> f64 {
+    (x + y) * (x - y) / 3.0 + x % y - 0.1
+}
+
+// CHECK-LABEL: float arith32(float x_0, float y_1)
+// CHECK: __rust_fmodf(-((x_0 * y_1) + 0x1.8p+0f), 0x1p+1f)
+#[no_mangle]
+pub fn arith32(x: f32, y: f32) -> f32 {
+    -(x * y + 1.5) % 2.0
+}
+
+// CHECK-LABEL: lt
+// CHECK: x_0 < y_1
+#[no_mangle]
+pub fn lt(x: f64, y: f64) -> bool {
+    x < y
+}
+
+// CHECK-LABEL: ne
+// CHECK: x_0 != y_1
+#[no_mangle]
+pub fn ne(x: f32, y: f32) -> bool {
+    x != y
+}
+
+// CHECK-LABEL: constants
+// CHECK: arith(-__rust_inf, 0x0.0000000000001p-1022)
+// CHECK: arith32(__rust_nanf, -0x0p+0f)
+#[no_mangle]
+pub fn constants() -> f64 {
+    arith(f64::NEG_INFINITY, f64::from_bits(1)) + arith32(f32::NAN, -0.0) as f64
+}
+
+// CHECK-LABEL: to_f64
+// CHECK: (double) x_0
+#[no_mangle]
+pub fn to_f64(x: i32) -> f64 {
+    x as f64
+}
+
+// `as` casts saturate, and NaN becomes zero
+// CHECK-LABEL: int32_t to_int(double x_0)
+// CHECK: (int32_t) ((x_0 != x_0) ? 0
+// CHECK-NEXT: : ((x_0 >= 0x1p+31) ? 2147483647
+// CHECK-NEXT: : ((x_0 <= -0x1p+31) ? -2147483648 : (int32_t) x_0)));
+#[no_mangle]
+pub fn to_int(x: f64) -> i32 {
+    x as i32
+}
+
+// CHECK-LABEL: uint8_t to_byte(float x_0)
+// CHECK: (x_0 != x_0) ? 0
+// CHECK-NEXT: : ((x_0 >= 0x1p+8f) ? 255 : ((x_0 <= -0x1p+0f) ? 0 : (uint8_t) x_0));
+#[no_mangle]
+pub fn to_byte(x: f32) -> u8 {
+    x as u8
+}
+
+// CHECK-LABEL: uint64_t to_u64(double x_0)
+// CHECK: (x_0 != x_0) ? 0
+// CHECK-NEXT: : ((x_0 >= 0x1p+64) ? 18446744073709551615u
+// CHECK-NEXT: : ((x_0 <= -0x1p+0) ? 0 : (uint64_t) x_0));
+#[no_mangle]
+pub fn to_u64(x: f64) -> u64 {
+    x as u64
+}
+
+// NaNs other than the canonical one keep their payload
+// CHECK-LABEL: float quiet_nan()
+// CHECK: return __rust_qnanf("0x1");
+#[no_mangle]
+pub fn quiet_nan() -> f32 {
+    f32::from_bits(0x7fc0_0001)
+}
+
+// CHECK-LABEL: double signaling_nan()
+// CHECK: return -__rust_snan("0x2a");
+#[no_mangle]
+pub fn signaling_nan() -> f64 {
+    f64::from_bits(0xfff0_0000_0000_002a)
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    if to_int(f64::NAN) != 0 || to_int(1e10) != i32::MAX || to_int(-1e10) != i32::MIN {
+        return 1;
+    }
+    if to_int(-3.7) != -3 || to_byte(-0.5) != 0 || to_byte(-7.0) != 0 || to_byte(300.0) != 255 {
+        return 2;
+    }
+    if to_u64(f64::INFINITY) != u64::MAX || to_u64(-f64::INFINITY) != 0 {
+        return 3;
+    }
+    let (quiet, signaling) = (quiet_nan(), signaling_nan());
+    let quiet = unsafe { *(&quiet as *const f32 as *const u32) };
+    let signaling = unsafe { *(&signaling as *const f64 as *const u64) };
+    if quiet != 0x7fc0_0001 || signaling != 0xfff0_0000_0000_002a {
+        return 4;
+    }
+    0
+}
@@ -0,0 +1,103 @@
+//@ compile-flags: -C opt-level=0 -C codegen-units=2 -C llvm-args=header=true
+
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// each codegen unit is written to a C file of its own, which uses the runtime
+// CHECK: #include <rust_runtime.h>
+
+// the header of the crate declares the items of both codegen units, with only the standard
+// headers of their types
+// HEADER: #ifndef HEADER_H
+// HEADER-NEXT: #define HEADER_H
+// HEADER-NOT: rust_runtime
+// HEADER: #include <stdbool.h>
+// HEADER-NEXT: #include <stdint.h>
+
+// HEADER: struct Point {
+// HEADER-NEXT: int32_t x;
+// HEADER-NEXT: int32_t y;
+// HEADER-NEXT: };
+// HEADER-NOT: struct Point {
+#[repr(C)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+pub mod statics {
+    use crate::Point;
+
+    // HEADER-DAG: extern const struct Point ORIGIN;
+    #[no_mangle]
+    pub static ORIGIN: Point = Point { x: 0, y: 0 };
+
+    // HEADER-DAG: extern uint32_t CALLS;
+    #[no_mangle]
+    pub static mut CALLS: u32 = 0;
+
+    // statics are declared from their types, whatever their initializers hold
+    // HEADER-DAG: extern const struct {{str_[0-9]+}} NAME;
+    #[no_mangle]
+    pub static NAME: &str = "header";
+
+    // HEADER-DAG: extern const struct {{Option_[0-9]+}} LIMIT;
+    #[no_mangle]
+    pub static LIMIT: Option<u32> = Some(3);
+}
+
+pub mod norm {
+    use crate::Point;
+
+    // HEADER-DAG: int32_t point_norm1(struct Point *_0);
+    #[no_mangle]
+    pub extern "C" fn point_norm1(p: &Point) -> i32 {
+        abs(p.x).wrapping_add(abs(p.y))
+    }
+
+    // HEADER-DAG: bool point_is_origin(struct Point *_0);
+    #[no_mangle]
+    pub extern "C" fn point_is_origin(p: &Point) -> bool {
+        p.x == 0 && p.y == 0
+    }
+
+    #[inline(never)]
+    fn abs(x: i32) -> i32 {
+        if x < 0 { x.wrapping_neg() } else { x }
+    }
+}
+
+pub mod scale {
+    use crate::Point;
+
+    // HEADER-DAG: void point_scale(struct Point *_0, int32_t _1);
+    #[no_mangle]
+    pub extern "C" fn point_scale(p: &mut Point, k: i32) {
+        p.x = p.x.wrapping_mul(k);
+        p.y = p.y.wrapping_mul(k);
+    }
+}
+
+// private functions are not declared, and the C program including the header has a `main` of
+// its own
+// HEADER-NOT: abs
+// HEADER-NOT: main
+// HEADER: #endif /* HEADER_H */
+#[no_mangle]
+pub fn main() -> i32 {
+    let mut p = Point { x: 1, y: -2 };
+    scale::point_scale(&mut p, 2);
+    if norm::point_norm1(&p) == 6 && norm::point_is_origin(&statics::ORIGIN) { 0 } else { 1 }
+}
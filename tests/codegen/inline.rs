@@ -0,0 +1,50 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// CHECK-LABEL: int32_t poly(int32_t x_0, int32_t y_1)
+// CHECK-NEXT: return ((x_0 * x_0) + (x_0 * y_1)) - y_1;
+#[no_mangle]
+pub fn poly(x: i32, y: i32) -> i32 {
+    x * x + x * y - y
+}
+
+// the load happens before the store
+// CHECK-LABEL: int32_t swap(int32_t *p_0, int32_t x_1)
+// CHECK: int32_t [[OLD:_[0-9]+]] = *
+// CHECK-NEXT: = x_1;
+// CHECK-NEXT: return [[OLD]] + 1;
+#[no_mangle]
+pub fn swap(p: &mut i32, x: i32) -> i32 {
+    let old = *p;
+    *p = x;
+    old.wrapping_add(1)
+}
+
+// calls keep their order
+// CHECK-LABEL: int32_t twice()
+// CHECK: int32_t [[FIRST:_[0-9]+]] = poly(1, 2);
+// CHECK-NEXT: return [[FIRST]] - poly(3, 4);
+#[no_mangle]
+pub fn twice() -> i32 {
+    poly(1, 2).wrapping_sub(poly(3, 4))
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    let mut x = 1;
+    if swap(&mut x, poly(2, 3)) == 2 && x == 7 && twice() == -16 { 0 } else { 1 }
+}
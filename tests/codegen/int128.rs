@@ -0,0 +1,88 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// CHECK-LABEL: __rust_u128 mul_add(__rust_u128 a_0, __rust_u128 b_1, __rust_u128 c_2)
+// CHECK: return __rust_add_u128(__rust_mul_u128(a_0, b_1), c_2);
+#[no_mangle]
+pub fn mul_add(a: u128, b: u128, c: u128) -> u128 {
+    a.wrapping_mul(b).wrapping_add(c)
+}
+
+// CHECK-LABEL: sar
+// CHECK: __rust_shr_i128(a_0,
+#[no_mangle]
+pub fn sar(a: i128) -> i128 {
+    a >> 100
+}
+
+// CHECK-LABEL: widen
+// CHECK: __rust_mul_i128(
+// CHECK: __rust_u128_from_i64
+// CHECK: __rust_u128_from_u64
+#[no_mangle]
+pub fn widen(a: i64, b: u32) -> i128 {
+    (a as i128) * (b as i128)
+}
+
+// CHECK-LABEL: narrow
+// CHECK: __rust_utos(
+// CHECK-NEXT: uint32_t, int32_t, (uint32_t) __rust_u64_from_u128(a_0), INT32_MAX);
+#[no_mangle]
+pub fn narrow(a: u128) -> i32 {
+    a as i32
+}
+
+// CHECK-LABEL: less
+// CHECK: __rust_cmp_i128(a_0, b_1) < 0
+#[no_mangle]
+pub fn less(a: i128, b: i128) -> bool {
+    a < b
+}
+
+// CHECK-LABEL: big
+// CHECK: return __rust_u128_const(0x123456789abcdef, 0xfedcba9876543210);
+#[no_mangle]
+pub fn big() -> u128 {
+    0x0123_4567_89ab_cdef_fedc_ba98_7654_3210
+}
+
+// CHECK-LABEL: minus
+// CHECK: return __rust_i128_const(0xffffffffffffffff, 0xfffffffffffffffb);
+#[no_mangle]
+pub fn minus() -> i128 {
+    -5
+}
+
+// large 64-bit constants
+// CHECK-LABEL: big_u64
+// CHECK: return 18446744073709551614u;
+#[no_mangle]
+pub fn big_u64() -> u64 {
+    u64::MAX - 1
+}
+
+// CHECK-LABEL: min_i64
+// CHECK: return (-9223372036854775807 - 1);
+#[no_mangle]
+pub fn min_i64() -> i64 {
+    i64::MIN
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    0
+}
@@ -0,0 +1,50 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: interface
+
+// CHECK: struct [[BIG:Big_[0-9]+]] {
+// CHECK: _Static_assert(sizeof(struct [[BIG]]) == 32, "layout mismatch");
+// CHECK-NEXT: _Static_assert(_Alignof(struct [[BIG]]) == 8, "layout mismatch");
+// CHECK-DAG: _Static_assert(offsetof(struct [[BIG]], f0) == 0, "layout mismatch");
+// CHECK-DAG: _Static_assert(offsetof(struct [[BIG]], f1) == 8, "layout mismatch");
+// CHECK-DAG: _Static_assert(offsetof(struct [[BIG]], f2) == 12, "layout mismatch");
+#[derive(Clone, Copy)]
+pub struct Big {
+    pub a: u64,
+    pub b: u32,
+    pub c: [u8; 20],
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// CHECK-LABEL: copy_big
+// CHECK: __rust_memcpy(&[[TMP:_[0-9]+]], src_0, sizeof(struct [[BIG]]));
+// CHECK: __rust_memcpy(dst_1, &[[TMP]], sizeof(struct [[BIG]]));
+#[no_mangle]
+pub fn copy_big(src: &Big, dst: &mut Big) {
+    *dst = *src;
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    let a = Big { a: 1, b: 2, c: [3; 20] };
+    let mut b = Big { a: 0, b: 0, c: [0; 20] };
+    copy_big(&a, &mut b);
+    if b.a != 1 || b.b != 2 {
+        return 1;
+    }
+    0
+}
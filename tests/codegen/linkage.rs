@@ -0,0 +1,51 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics, linkage)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: interface
+// CHECK: __attribute__((visibility("hidden"), noreturn)) void
+// CHECK-NEXT: rust_begin_unwind(
+// CHECK: static __attribute__((noinline)) int32_t
+// CHECK-NEXT: {{.*}}helper{{.*}}(int32_t x_0);
+// CHECK: __attribute__((weak)) int32_t fallback(int32_t x_0);
+// CHECK: int32_t exported(int32_t x_0);
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+// CHECK: static __attribute__((noinline)) int32_t
+// CHECK-NEXT: {{.*}}helper{{.*}}(int32_t x_0)
+#[inline(never)]
+fn helper(x: i32) -> i32 {
+    x + 1
+}
+
+// CHECK: __attribute__((weak)) int32_t fallback(int32_t x_0)
+#[no_mangle]
+#[linkage = "weak"]
+pub fn fallback(x: i32) -> i32 {
+    x * 2
+}
+
+// CHECK: {{^}}int32_t exported(int32_t x_0)
+#[no_mangle]
+pub fn exported(x: i32) -> i32 {
+    helper(x)
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    if exported(1) != 2 || fallback(3) != 6 {
+        return 1;
+    }
+    0
+}
@@ -0,0 +1,109 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// CHECK-LABEL: uint32_t sum_to(uint32_t n_0)
+// CHECK: while (_2 < n_0) {
+// CHECK: }
+// CHECK-NEXT: return _1;
+#[no_mangle]
+pub fn sum_to(n: u32) -> u32 {
+    let mut sum = 0u32;
+    let mut i = 0;
+    while i < n {
+        sum = sum.wrapping_add(i);
+        i += 1;
+    }
+    sum
+}
+
+// CHECK-LABEL: uint32_t find(uint32_t n_0, uint32_t key_1)
+// CHECK: while (_3 != n_0) {
+// CHECK-NEXT: if ((_3 * _3) == key_1) {
+// CHECK: goto [[RET:bb[0-9]+]];
+// CHECK: }
+// CHECK: = 4294967295;
+// CHECK-NEXT: [[RET]]:;
+// CHECK-NEXT: return _2;
+#[no_mangle]
+pub fn find(n: u32, key: u32) -> u32 {
+    let mut i = 0;
+    loop {
+        if i == n {
+            return u32::MAX;
+        }
+        if i.wrapping_mul(i) == key {
+            break i;
+        }
+        i += 1;
+    }
+}
+
+// CHECK-LABEL: uint32_t pairs(uint32_t n_0)
+// CHECK-NOT: goto
+// CHECK: while (_2 < n_0) {
+// CHECK: while (_3 < n_0) {
+// CHECK: if (((_2 ^ _3) & 1) != 0) {
+// CHECK: }
+// CHECK: return _1;
+#[no_mangle]
+pub fn pairs(n: u32) -> u32 {
+    let mut count = 0;
+    let mut i = 0;
+    while i < n {
+        let mut j = 0;
+        while j < n {
+            j += 1;
+            if (i ^ j) & 1 == 0 {
+                continue;
+            }
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+// CHECK-LABEL: uint32_t digits(uint32_t x_0)
+// CHECK: do {
+// CHECK: } while ({{.*}} != 0);
+// CHECK-NEXT: return
+#[no_mangle]
+pub fn digits(mut x: u32) -> u32 {
+    let mut n = 0;
+    loop {
+        n += 1;
+        x /= 10;
+        if x == 0 {
+            break n;
+        }
+    }
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    if sum_to(5) == 10
+        && find(10, 49) == 7
+        && find(3, 49) == u32::MAX
+        && pairs(3) == 5
+        && digits(0) == 1
+        && digits(1234) == 4
+    {
+        0
+    } else {
+        1
+    }
+}
@@ -0,0 +1,45 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+use core::cell::Cell;
+
+// pointers are never `const` qualified, as shared references may point to mutable data
+
+// CHECK-LABEL: void set(int32_t *cell_0, int32_t x_1) {
+// CHECK-NEXT: (*(int32_t*) (intptr_t) cell_0) = x_1;
+#[no_mangle]
+#[inline(never)]
+pub fn set(cell: &Cell<i32>, x: i32) {
+    cell.set(x);
+}
+
+// CHECK-LABEL: int32_t get(int32_t *x_0) {
+// CHECK-NEXT: return *x_0;
+#[no_mangle]
+#[inline(never)]
+pub fn get(x: &i32) -> i32 {
+    *x
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    let cell = Cell::new(1);
+    set(&cell, 2);
+    if get(&cell.get()) != 2 {
+        return 1;
+    }
+    0
+}
@@ -1,3 +1,4 @@
+//@ ignore-run: `main` returns 42 to test the return value
 #![allow(internal_features)]
 #![feature(core_intrinsics)]
 #![no_std]
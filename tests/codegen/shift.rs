@@ -0,0 +1,48 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+// the shift amount keeps its own type, e.g. an `i32` literal shifting a `u32`
+
+// CHECK-LABEL: uint32_t shr(uint32_t x_0)
+// CHECK: return x_0 >> 1;
+#[no_mangle]
+pub fn shr(x: u32) -> u32 {
+    x >> 1
+}
+
+// CHECK-LABEL: int64_t shl(int64_t x_0)
+// CHECK: (uint64_t) x_0 << 3
+#[no_mangle]
+pub fn shl(x: i64) -> i64 {
+    x << 3u8
+}
+
+// CHECK-LABEL: int32_t sar(int32_t x_0, uint8_t n_1)
+// CHECK: x_0
+// CHECK-NEXT: >> __rust_utos(
+// CHECK-NEXT: uint32_t, int32_t, (uint32_t) (uint8_t) (n_1 & 31), INT32_MAX);
+#[no_mangle]
+pub fn sar(x: i32, n: u8) -> i32 {
+    x >> n
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    if shr(7) != 3 || shl(-1) != -8 || sar(-16, 2) != -4 {
+        return 1;
+    }
+    0
+}
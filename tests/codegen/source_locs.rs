@@ -0,0 +1,51 @@
+//@ compile-flags: -g --remap-path-prefix=tests/codegen=src
+
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// CHECK-LABEL: int32_t add_one(int32_t x_0) {
+// CHECK: #line [[#@LINE+5]] "src/source_locs.rs"
+// CHECK-NEXT: return x_0 + 1;
+#[no_mangle]
+pub fn add_one(x: i32) -> i32 {
+    x.wrapping_add(1)
+}
+
+// the lines after a function are C again
+// CHECK-NEXT: }
+// CHECK-NEXT: #line [[#]] "{{.*}}source_locs.{{.*}}.c"
+
+// CHECK-LABEL: uint32_t count(uint32_t n_0)
+// CHECK: #line [[#@LINE+8]] "src/source_locs.rs"
+// CHECK-NEXT: = 0;
+// CHECK-NEXT: #line [[#@LINE+7]] "src/source_locs.rs"
+// CHECK-NEXT: while (i_1 < n_0) {
+// CHECK-NEXT: #line [[#@LINE+6]] "src/source_locs.rs"
+// CHECK-NEXT: i_1 = i_1 + 1;
+#[no_mangle]
+pub fn count(n: u32) -> u32 {
+    let mut i = 0;
+    while i < n {
+        i += 1;
+    }
+    i
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    if count(3) == 3 { add_one(-1) } else { 1 }
+}
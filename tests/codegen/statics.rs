@@ -0,0 +1,130 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: interface
+
+// fields named after C keywords are named after their index instead
+// CHECK: struct Limits {
+// CHECK-NEXT: uint32_t f0;
+// CHECK-NEXT: uint32_t f1;
+#[repr(C)]
+pub struct Limits {
+    pub long: u32,
+    pub short: u32,
+}
+
+// CHECK: struct Config {
+#[repr(C)]
+pub struct Config {
+    pub flag: u8,
+    pub limit: u32,
+    pub scale: f64,
+}
+
+// immutable statics are `const`
+// CHECK: const struct Limits LIMITS = {1, 2};
+#[no_mangle]
+pub static LIMITS: Limits = Limits { long: 1, short: 2 };
+
+// the padding between `flag` and `limit` is initialized explicitly
+// CHECK: const struct Config CONFIG = {1, {0}, 100, 0x1p-1};
+#[no_mangle]
+pub static CONFIG: Config = Config { flag: 1, limit: 100, scale: 0.5 };
+
+// CHECK: uint64_t COUNTER = 7;
+#[no_mangle]
+pub static mut COUNTER: u64 = 7;
+
+// CHECK: static __attribute__((section(".rodata.table"), used)) const
+// CHECK-NEXT: uint16_t {{_ZN.*TABLE.*}}[4] = {1, 2, 3, 4};
+#[used]
+#[link_section = ".rodata.table"]
+static TABLE: [u16; 4] = [1, 2, 3, 4];
+
+// pointers are initialized with the addresses they point to
+// CHECK-NEXT: static const char [[HI:_g[0-9a-z]+_[0-9]+]][2] = "hi";
+// CHECK-NEXT: const struct {{str_[0-9]+}} GREETING = {(void*) [[HI]], 2};
+#[no_mangle]
+pub static GREETING: &str = "hi";
+
+// CHECK-NEXT: uint16_t *const SECOND =
+// CHECK-NEXT: (uint16_t*) ((char*) (&{{_ZN.*TABLE.*}}) + 2);
+#[no_mangle]
+pub static SECOND: &u16 = &TABLE[1];
+
+// enums initialize the member of their union for their variant, and the padding of the member
+// holds the tag
+// CHECK-NEXT: const struct [[OPTION:Option_[0-9]+]] SOME = {{\{\{}}.v1 = {{\{\{}}1, 0, 0, 0}, 7}}};
+#[no_mangle]
+pub static SOME: Option<u32> = Some(7);
+
+// CHECK-NEXT: const struct [[OPTION]] NONE = {{\{\{\{}}0, {0}}}};
+#[no_mangle]
+pub static NONE: Option<u32> = None;
+
+// CHECK-NEXT: void *const NO_REF = 0;
+#[no_mangle]
+pub static NO_REF: Option<&Config> = None;
+
+#[repr(C)]
+pub struct Node {
+    pub next: &'static Node,
+    pub value: u32,
+}
+
+// a static referring to itself is declared before its definition
+// CHECK-NEXT: extern const struct Node LOOP;
+// CHECK-NEXT: const struct Node LOOP = {(struct Node*) (&LOOP), 3, {0}};
+#[no_mangle]
+pub static LOOP: Node = Node { next: &LOOP, value: 3 };
+
+// constants pointing to a static hold its address in a global
+// CHECK: static const intptr_t [[COUNTER_ADDR:_g[0-9a-z]+_[0-9]+]] = (intptr_t) (&COUNTER);
+
+// statics of other crates are declared `extern`
+// CHECK: extern intptr_t environ;
+extern "C" {
+    static environ: *const *const u8;
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// CHECK-LABEL: uint64_t bump() {
+// CHECK-NEXT: (*(uint64_t*) [[COUNTER_ADDR]]) = (*(uint64_t*) [[COUNTER_ADDR]]) + 1;
+#[no_mangle]
+#[inline(never)]
+pub fn bump() -> u64 {
+    unsafe {
+        COUNTER += 1;
+        COUNTER
+    }
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    if bump() != 8 || CONFIG.limit != 100 || unsafe { environ.is_null() } {
+        return 1;
+    }
+    if *SECOND != 2 {
+        return 2;
+    }
+    if !matches!(SOME, Some(7)) || NONE.is_some() || NO_REF.is_some() {
+        return 3;
+    }
+    if LOOP.next.next.value != 3 {
+        return 4;
+    }
+    0
+}
@@ -0,0 +1,38 @@
+//@ ignore-O0: `bitcast` is not implemented
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {
+        pub fn write(fd: i32, buf: *const u8, count: usize) -> isize;
+    }
+}
+
+// CHECK-LABEL: rustc_codegen_c: interface
+
+// CHECK-DAG: static const char _g{{[0-9a-z]+}}_{{[0-9]+}}[13] = "say \"hi\" \?\?=\n";
+// CHECK-DAG: static const char _g{{[0-9a-z]+}}_{{[0-9]+}}[13] = "nul\000\377\0012 \\ end";
+// CHECK-DAG: static const char _g{{[0-9a-z]+}}_{{[0-9]+}}[14] = "line 1\n" "line 2\n";
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+#[no_mangle]
+pub fn main() -> i32 {
+    let quoted = "say \"hi\" ??=\n";
+    let bytes = b"nul\0\xff\x012 \\ end";
+    let lines = "line 1\nline 2\n";
+    unsafe {
+        libc::write(1, quoted.as_ptr(), quoted.len());
+        libc::write(1, lines.as_ptr(), lines.len());
+        libc::write(1, bytes.as_ptr(), bytes.len());
+    }
+    0
+}
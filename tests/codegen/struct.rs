@@ -0,0 +1,69 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: interface
+
+// fields are sorted by offset, with explicit padding
+// CHECK: struct [[POINT:Point_[0-9]+]] {
+// CHECK-NEXT: int64_t f1;
+// CHECK-NEXT: int32_t f0;
+// CHECK-NEXT: uint8_t f2;
+// CHECK-NEXT: uint8_t _pad0[3];
+// CHECK-NEXT: };
+pub struct Point {
+    pub x: i32,
+    pub y: i64,
+    pub z: u8,
+}
+
+// a struct containing other structs by value comes after them
+// CHECK: struct [[LINE:Line_[0-9]+]] {
+// CHECK-NEXT: struct [[POINT]] f0;
+// CHECK-NEXT: struct [[POINT]] f1;
+// CHECK-NEXT: };
+pub struct Line {
+    pub a: Point,
+    pub b: Point,
+}
+
+// recursive structs through pointers
+// CHECK: struct [[NODE:Node_[0-9]+]] {
+// CHECK-NEXT: struct [[NODE]] *f1;
+// CHECK-NEXT: int32_t f0;
+pub struct Node<'a> {
+    pub value: i32,
+    pub next: &'a Node<'a>,
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// CHECK-LABEL: get_line_y
+// CHECK: [16]
+#[no_mangle]
+pub fn get_line_y(l: &Line) -> i64 {
+    l.b.y
+}
+
+// CHECK-LABEL: next_value
+// CHECK: struct [[NODE]] *
+#[no_mangle]
+pub fn next_value(n: &Node) -> i32 {
+    n.next.value
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    0
+}
@@ -0,0 +1,75 @@
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// CHECK-LABEL: int32_t classify(int32_t x_0)
+// CHECK: switch (x_0) {
+// CHECK-DAG: case -1: goto [[NEG:[a-z_0-9]+]];
+// CHECK-DAG: case 0: goto [[ZERO:[a-z_0-9]+]];
+// CHECK-DAG: case 7: goto
+// CHECK: default: goto
+// CHECK: }
+#[no_mangle]
+pub fn classify(x: i32) -> i32 {
+    match x {
+        -1 => 10,
+        0 => 20,
+        7 => 30,
+        _ => 40,
+    }
+}
+
+// CHECK-LABEL: uint8_t byte_class(uint8_t x_0)
+// CHECK: switch (x_0) {
+// CHECK-DAG: case 200: goto
+// CHECK-DAG: case 255: goto
+#[no_mangle]
+pub fn byte_class(x: u8) -> u8 {
+    match x {
+        1 => 2,
+        200 => 3,
+        255 => 4,
+        _ => 5,
+    }
+}
+
+// CHECK-LABEL: wide_class
+// CHECK-NOT: switch
+// CHECK-NOT: goto
+// CHECK: if (__rust_cmp_u128(x_0, __rust_u128_const(0x0, 0x1)) == 0) {
+// CHECK: } else if (
+// CHECK: } else {
+// CHECK: return _1;
+#[no_mangle]
+pub fn wide_class(x: u128) -> u32 {
+    match x {
+        1 => 2,
+        u128::MAX => 3,
+        5 => 4,
+        _ => 5,
+    }
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    if classify(-1) + classify(0) + classify(7) + classify(3) != 100 {
+        return 1;
+    }
+    if byte_class(255) != 4 || byte_class(0) != 5 || wide_class(u128::MAX) != 3 {
+        return 1;
+    }
+    0
+}
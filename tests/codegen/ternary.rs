@@ -0,0 +1,61 @@
+//@ ignore-O0: `unchecked_ssub` is not implemented
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+use core::cmp::Ordering;
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// CHECK-LABEL: int8_t order(int32_t a_0, int32_t b_1)
+// CHECK: return (a_0 < b_1) ? -1 : (int8_t) ((a_0 != b_1) ? 1 : 0);
+#[no_mangle]
+pub fn order(a: i32, b: i32) -> Ordering {
+    a.cmp(&b)
+}
+
+// CHECK-LABEL: double smaller(double a_0, double b_1)
+// CHECK: = ((a_0 < b_1) || (b_1 != b_1)) ? a_0 : b_1;
+#[no_mangle]
+pub fn smaller(a: f64, b: f64) -> f64 {
+    a.min(b)
+}
+
+// CHECK-LABEL: float larger
+// CHECK: (a_0 > b_1) || (b_1 != b_1)
+#[no_mangle]
+pub fn larger(a: f32, b: f32) -> f32 {
+    a.max(b)
+}
+
+// CHECK-LABEL: int32_t count(bool a_0, bool b_1)
+// CHECK: return (int32_t) (a_0 ? 1 : 0) + (int32_t) ((!b_1) ? 1 : 0);
+#[no_mangle]
+pub fn count(a: bool, b: bool) -> i32 {
+    a as i32 + !b as i32
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    if order(1, 2).is_ge() || order(2, 2).is_ne() || order(3, 2).is_le() {
+        return 1;
+    }
+    if smaller(f64::NAN, 1.0) != 1.0 || smaller(2.0, 1.0) != 1.0 || larger(1.0, f32::NAN) != 1.0 {
+        return 2;
+    }
+    if count(true, false) != 2 || count(false, true) != 0 {
+        return 3;
+    }
+    0
+}
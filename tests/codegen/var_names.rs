@@ -0,0 +1,51 @@
+//@ compile-flags: -C debuginfo=2
+
+#![allow(internal_features)]
+#![feature(core_intrinsics)]
+#![no_std]
+#![no_main]
+
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    core::intrinsics::abort();
+}
+
+pub mod libc {
+    #[link(name = "c")]
+    extern "C" {}
+}
+
+// CHECK-LABEL: rustc_codegen_c: implementation
+
+// parameters are always named after the arguments, other variables are named with debug info
+// the names are suffixed with the index, so a keyword is a valid name too
+// CHECK-LABEL: uint32_t count_odd(uint32_t limit_0) {
+// CHECK: while (int_[[I:[0-9]+]] < limit_0) {
+// CHECK: count_[[C:[0-9]+]] = count_[[C]] + 1;
+// CHECK: int_[[I]] = int_[[I]] + 1;
+// CHECK: return count_[[C]];
+#[no_mangle]
+pub fn count_odd(limit: u32) -> u32 {
+    let mut count = 0;
+    let mut r#int = 0;
+    while r#int < limit {
+        if r#int & 1 == 1 {
+            count += 1;
+        }
+        r#int += 1;
+    }
+    count
+}
+
+// the halves of a scalar pair are named after the fields
+// CHECK-LABEL: uint64_t sum(uint32_t pair_0_0, uint64_t pair_1_1) {
+#[no_mangle]
+pub fn sum(pair: (u32, u64)) -> u64 {
+    let (a, b) = pair;
+    (a as u64).wrapping_add(b)
+}
+
+#[no_mangle]
+pub fn main() -> i32 {
+    if count_odd(5) == 2 && sum((1, 2)) == 3 { 0 } else { 1 }
+}